@@ -1,16 +1,17 @@
 #![allow(clippy::missing_errors_doc, clippy::must_use_candidate)]
 use std::str::FromStr;
 
+use base64::Engine;
 use itertools::Itertools;
 use serde_json::{json, Value};
 use stellar_xdr::curr::{
     AccountId, BytesM, ContractExecutable, Error as XdrError, Hash, Int128Parts, Int256Parts,
-    PublicKey, ScAddress, ScBytes, ScContractInstance, ScMap, ScMapEntry, ScNonceKey, ScSpecEntry,
-    ScSpecFunctionV0, ScSpecTypeDef as ScType, ScSpecTypeMap, ScSpecTypeOption, ScSpecTypeResult,
-    ScSpecTypeTuple, ScSpecTypeUdt, ScSpecTypeVec, ScSpecUdtEnumV0, ScSpecUdtErrorEnumCaseV0,
-    ScSpecUdtErrorEnumV0, ScSpecUdtStructV0, ScSpecUdtUnionCaseTupleV0, ScSpecUdtUnionCaseV0,
-    ScSpecUdtUnionCaseVoidV0, ScSpecUdtUnionV0, ScString, ScSymbol, ScVal, ScVec, StringM,
-    UInt128Parts, UInt256Parts, Uint256, VecM,
+    PublicKey, ScAddress, ScBytes, ScContractInstance, ScError, ScMap, ScMapEntry, ScNonceKey,
+    ScSpecEntry, ScSpecFunctionV0, ScSpecTypeDef as ScType, ScSpecTypeMap, ScSpecTypeOption,
+    ScSpecTypeResult, ScSpecTypeTuple, ScSpecTypeUdt, ScSpecTypeVec, ScSpecUdtEnumV0,
+    ScSpecUdtErrorEnumCaseV0, ScSpecUdtErrorEnumV0, ScSpecUdtStructV0, ScSpecUdtUnionCaseTupleV0,
+    ScSpecUdtUnionCaseV0, ScSpecUdtUnionCaseVoidV0, ScSpecUdtUnionV0, ScString, ScSymbol, ScVal,
+    ScVec, StringM, UInt128Parts, UInt256Parts, Uint256, VecM,
 };
 
 pub mod contract;
@@ -24,6 +25,10 @@ pub enum Error {
     InvalidPair(ScVal, ScType),
     #[error("value is not parseable to {0:#?}")]
     InvalidValue(Option<ScType>),
+    #[error("expected a value of type {expected:?}, got {got}")]
+    TypeMismatch { expected: ScType, got: String },
+    #[error("{value} is out of range for type {ty:?}")]
+    OutOfRange { ty: ScType, value: String },
     #[error("Unknown case {0} for {1}")]
     EnumCase(String, String),
     #[error("Enum {0} missing value for type {1}")]
@@ -250,7 +255,9 @@ impl Spec {
                     ScType::Udt(ScSpecTypeUdt { name })
                         if matches!(
                             self.find(&name.to_utf8_string_lossy())?,
-                            ScSpecEntry::UdtUnionV0(_) | ScSpecEntry::UdtStructV0(_)
+                            ScSpecEntry::UdtUnionV0(_)
+                                | ScSpecEntry::UdtStructV0(_)
+                                | ScSpecEntry::UdtEnumV0(_)
                         ) =>
                     {
                         Ok(Value::String(s.to_owned()))
@@ -312,6 +319,9 @@ impl Spec {
             // Tuple parsing
             (ScType::Tuple(elem), Value::Array(raw)) => self.parse_tuple(t, elem, raw)?,
 
+            // Result parsing
+            (ScType::Result(result), _) => self.parse_result(result, v)?,
+
             // User defined types parsing
             (ScType::Udt(ScSpecTypeUdt { name }), _) => self.parse_udt(name, v)?,
 
@@ -321,6 +331,41 @@ impl Spec {
         Ok(val)
     }
 
+    /// Parse `{"ok": ...}` against the ok type, or `{"err": ...}` against the error type, where
+    /// the error is either a case name of the contract's error enum or its numeric code.
+    fn parse_result(&self, result: &ScSpecTypeResult, value: &Value) -> Result<ScVal, Error> {
+        let invalid = || Error::InvalidValue(Some(ScType::Result(Box::new(result.clone()))));
+        let Value::Object(map) = value else {
+            return Err(invalid());
+        };
+        match (map.len(), map.get("ok"), map.get("err")) {
+            (1, Some(ok), None) => self.from_json(ok, &result.ok_type),
+            (1, None, Some(err)) => {
+                let code = match (&result.error_type, err) {
+                    (ScType::Udt(ScSpecTypeUdt { name }), Value::String(case)) => {
+                        let name = name.to_utf8_string_lossy();
+                        let ScSpecEntry::UdtErrorEnumV0(enum_) = self.find(&name)? else {
+                            return Err(invalid());
+                        };
+                        enum_
+                            .cases
+                            .iter()
+                            .find(|c| c.name.to_utf8_string_lossy() == *case)
+                            .ok_or_else(|| Error::EnumCase(case.clone(), name))?
+                            .value
+                    }
+                    (_, Value::Number(n)) => n
+                        .as_u64()
+                        .and_then(|n| u32::try_from(n).ok())
+                        .ok_or_else(invalid)?,
+                    _ => return Err(invalid()),
+                };
+                Ok(ScVal::Error(ScError::Contract(code)))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
     fn parse_udt(&self, name: &StringM<60>, value: &Value) -> Result<ScVal, Error> {
         let name = &name.to_utf8_string_lossy();
         match (self.find(name)?, value) {
@@ -330,12 +375,7 @@ impl Spec {
                     .iter()
                     .any(|f| f.name.to_utf8_string_lossy() == "0")
                 {
-                    self.parse_tuple_strukt(
-                        strukt,
-                        &(0..map.len())
-                            .map(|i| map.get(&i.to_string()).unwrap().clone())
-                            .collect::<Vec<_>>(),
-                    )
+                    self.parse_tuple_strukt(strukt, &indexed_values(map)?)
                 } else {
                     self.parse_strukt(strukt, map)
                 }
@@ -348,7 +388,15 @@ impl Spec {
                 val @ (Value::Array(_) | Value::String(_) | Value::Object(_)),
             ) => self.parse_union(union, val),
             (ScSpecEntry::UdtEnumV0(enum_), Value::Number(num)) => parse_const_enum(num, enum_),
-            (s, v) => todo!("Not implemented for {s:#?} {v:#?}"),
+            (ScSpecEntry::UdtEnumV0(enum_), Value::String(case)) => enum_
+                .cases
+                .iter()
+                .find(|c| c.name.to_utf8_string_lossy() == *case)
+                .map(|c| ScVal::U32(c.value))
+                .ok_or_else(|| Error::EnumCase(case.clone(), name.clone())),
+            _ => Err(Error::InvalidValue(Some(ScType::Udt(ScSpecTypeUdt {
+                name: StringM::from_str(name).map_err(Error::Xdr)?,
+            })))),
         }
     }
 
@@ -385,7 +433,7 @@ impl Spec {
                     .get(name)
                     .ok_or_else(|| Error::MissingKey(name.clone()))?;
                 let val = self.from_json(v, &f.type_)?;
-                let key = StringM::from_str(name).unwrap();
+                let key = StringM::from_str(name).map_err(Error::Xdr)?;
                 Ok(ScMapEntry {
                     key: ScVal::Symbol(key.into()),
                     val,
@@ -400,20 +448,20 @@ impl Spec {
         let (enum_case, rest) = match value {
             Value::String(s) => (s, None),
             Value::Object(o) if o.len() == 1 => {
-                let res = o.values().next().map(|v| match v {
+                let (key, v) = o.iter().next().ok_or(Error::Unknown)?;
+                let res = match v {
                     Value::Object(obj) if obj.contains_key("0") => {
-                        let len = obj.len();
-                        Value::Array(
-                            (0..len)
-                                .map(|i| obj.get(&i.to_string()).unwrap().clone())
-                                .collect::<Vec<_>>(),
-                        )
+                        Value::Array(indexed_values(obj)?)
                     }
                     _ => v.clone(),
-                });
-                (o.keys().next().unwrap(), res)
+                };
+                (key, Some(res))
+            }
+            _ => {
+                return Err(Error::InvalidValue(Some(ScType::Udt(ScSpecTypeUdt {
+                    name: union.name.clone(),
+                }))))
             }
-            _ => todo!(),
         };
         let case = union
             .cases
@@ -534,6 +582,7 @@ impl Spec {
             | (ScVal::Address(_), ScType::Address)
             | (ScVal::Bytes(_), ScType::Bytes | ScType::BytesN(_)) => to_json(val)?,
 
+            (val @ ScVal::Error(_), ScType::Result(inner)) => self.result_to_json(val, inner)?,
             (val, ScType::Result(inner)) => self.xdr_to_json(val, &inner.ok_type)?,
 
             (val, ScType::Option(inner)) => self.xdr_to_json(val, &inner.value_type)?,
@@ -546,6 +595,48 @@ impl Spec {
         })
     }
 
+    /// Render a value of a `Result` type as `{"ok": ...}` or `{"err": ...}`. Contract errors are
+    /// rendered as the case name of the contract's error enum when the spec has one.
+    ///
+    /// # Errors
+    ///
+    /// Might return an error
+    pub fn result_to_json(&self, val: &ScVal, result: &ScSpecTypeResult) -> Result<Value, Error> {
+        Ok(match val {
+            ScVal::Error(ScError::Contract(code)) => {
+                let case = match &result.error_type {
+                    ScType::Udt(ScSpecTypeUdt { name }) => {
+                        match self.find(&name.to_utf8_string_lossy()) {
+                            Ok(ScSpecEntry::UdtErrorEnumV0(enum_)) => {
+                                enum_.cases.iter().find(|c| c.value == *code)
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                let err = case.map_or_else(
+                    || Value::from(*code),
+                    |c| Value::String(c.name.to_utf8_string_lossy()),
+                );
+                json!({ "err": err })
+            }
+            ScVal::Error(e) => json!({ "err": serde_json::to_value(e)? }),
+            ok => json!({ "ok": self.xdr_to_json(ok, &result.ok_type)? }),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Might return an error
+    pub fn result_to_string(
+        &self,
+        val: &ScVal,
+        result: &ScSpecTypeResult,
+    ) -> Result<String, Error> {
+        Ok(serde_json::to_string(&self.result_to_json(val, result)?)?)
+    }
+
     /// # Errors
     ///
     /// Might return an error
@@ -593,10 +684,16 @@ impl Spec {
                 strukt
                     .fields
                     .iter()
-                    .zip(map.iter())
-                    .map(|(field, entry)| {
-                        let val = self.xdr_to_json(&entry.val, &field.type_)?;
-                        Ok((field.name.to_utf8_string_lossy(), val))
+                    .map(|field| {
+                        let name = field.name.to_utf8_string_lossy();
+                        let entry = map
+                            .iter()
+                            .find(|entry| match &entry.key {
+                                ScVal::Symbol(key) => key.as_vec() == field.name.as_vec(),
+                                _ => false,
+                            })
+                            .ok_or_else(|| Error::MissingKey(name.clone()))?;
+                        Ok((name, self.xdr_to_json(&entry.val, &field.type_)?))
                     })
                     .collect::<Result<serde_json::Map<String, _>, Error>>()?,
             ),
@@ -732,6 +829,16 @@ impl Spec {
     }
 }
 
+/// Collects the values of an object keyed by `"0"`, `"1"`, ... into a list, in index order.
+fn indexed_values(map: &serde_json::Map<String, Value>) -> Result<Vec<Value>, Error> {
+    (0..map.len())
+        .map(|i| {
+            let key = i.to_string();
+            map.get(&key).cloned().ok_or(Error::MissingKey(key))
+        })
+        .collect()
+}
+
 /// # Errors
 ///
 /// Might return an error
@@ -763,10 +870,11 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
         (ScType::Bool, Value::Bool(false)) => ScVal::Bool(false),
 
         // Number parsing
+        (ScType::U128 | ScType::I128 | ScType::U256 | ScType::I256, Value::Number(n)) => {
+            from_json_primitives(&Value::String(format!("{n}")), t)?
+        }
         (ScType::U128, Value::String(s)) => {
-            let val: u128 = u128::from_str(s)
-                .map(Into::into)
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?;
+            let val: u128 = u128::from_str(s).map_err(|e| parse_int_error(&e, s, t))?;
             let bytes = val.to_be_bytes();
             let (hi, lo) = bytes.split_at(8);
             ScVal::U128(UInt128Parts {
@@ -776,9 +884,7 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
         }
 
         (ScType::I128, Value::String(s)) => {
-            let val: i128 = i128::from_str(s)
-                .map(Into::into)
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?;
+            let val: i128 = i128::from_str(s).map_err(|e| parse_int_error(&e, s, t))?;
             let bytes = val.to_be_bytes();
             let (hi, lo) = bytes.split_at(8);
             ScVal::I128(Int128Parts {
@@ -789,7 +895,9 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
 
         // Number parsing
         (ScType::U256, Value::String(s)) => {
-            let (hi, lo) = ethnum::U256::from_str_prefixed(s)?.into_words();
+            let (hi, lo) = ethnum::U256::from_str_prefixed(s)
+                .map_err(|e| parse_int_error(&e, s, t))?
+                .into_words();
             let hi_bytes = hi.to_be_bytes();
             let (hi_hi, hi_lo) = hi_bytes.split_at(8);
             let lo_bytes = lo.to_be_bytes();
@@ -802,7 +910,9 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
             })
         }
         (ScType::I256, Value::String(s)) => {
-            let (hi, lo) = ethnum::I256::from_str_prefixed(s)?.into_words();
+            let (hi, lo) = ethnum::I256::from_str_prefixed(s)
+                .map_err(|e| parse_int_error(&e, s, t))?
+                .into_words();
             let hi_bytes = hi.to_be_bytes();
             let (hi_hi, hi_lo) = hi_bytes.split_at(8);
             let lo_bytes = lo.to_be_bytes();
@@ -815,26 +925,30 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
             })
         }
 
-        (ScType::I32, Value::Number(n)) => ScVal::I32(
-            n.as_i64()
-                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?
-                .try_into()
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?,
-        ),
-        (ScType::U32, Value::Number(n)) => ScVal::U32(
-            n.as_u64()
-                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?
-                .try_into()
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?,
-        ),
-        (ScType::I64, Value::Number(n)) => ScVal::I64(
-            n.as_i64()
-                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?,
-        ),
-        (ScType::U64 | ScType::Timepoint | ScType::Duration, Value::Number(n)) => ScVal::U64(
-            n.as_u64()
-                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?,
-        ),
+        (ScType::I32, Value::Number(n)) => ScVal::I32(json_integer(n, t)?),
+        (ScType::U32, Value::Number(n)) => ScVal::U32(json_integer(n, t)?),
+        (ScType::I64, Value::Number(n)) => ScVal::I64(json_integer(n, t)?),
+        (ScType::U64 | ScType::Timepoint | ScType::Duration, Value::Number(n)) => {
+            ScVal::U64(json_integer(n, t)?)
+        }
+        (
+            ScType::I32
+            | ScType::U32
+            | ScType::I64
+            | ScType::U64
+            | ScType::Timepoint
+            | ScType::Duration
+            | ScType::U128
+            | ScType::I128
+            | ScType::U256
+            | ScType::I256,
+            raw,
+        ) => {
+            return Err(Error::TypeMismatch {
+                expected: t.clone(),
+                got: json_type_name(raw).to_string(),
+            })
+        }
 
         // Symbol parsing
         (ScType::Symbol, Value::String(s)) => ScVal::Symbol(ScSymbol(
@@ -858,9 +972,9 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
                     return Ok(key);
                 }
             }
-            // Bytes are not an address, just parse as a hex string
-            utils::padded_hex_from_str(s, bytes.n as usize)
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?
+            // Bytes are not an address, parse as base64 or a hex string
+            bytes_n_from_str(s, bytes.n as usize)
+                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?
                 .try_into()
                 .map_err(|_| Error::InvalidValue(Some(t.clone())))?
         })),
@@ -868,8 +982,8 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
             from_json_primitives(&Value::String(format!("{n}")), &ScType::Bytes)?
         }
         (ScType::Bytes, Value::String(s)) => ScVal::Bytes(
-            hex::decode(s)
-                .map_err(|_| Error::InvalidValue(Some(t.clone())))?
+            bytes_from_str(s)
+                .ok_or_else(|| Error::InvalidValue(Some(t.clone())))?
                 .try_into()
                 .map_err(|_| Error::InvalidValue(Some(t.clone())))?,
         ),
@@ -897,6 +1011,50 @@ pub fn from_json_primitives(v: &Value, t: &ScType) -> Result<ScVal, Error> {
     Ok(val)
 }
 
+/// Converts a JSON number to an integer of the given type, telling apart numbers that are not
+/// integers from integers that don't fit in the type.
+fn json_integer<T: TryFrom<i128>>(n: &serde_json::Number, t: &ScType) -> Result<T, Error> {
+    let out_of_range = || Error::OutOfRange {
+        ty: t.clone(),
+        value: n.to_string(),
+    };
+    let int = match (n.as_i64(), n.as_u64(), n.as_f64()) {
+        (Some(i), _, _) => i128::from(i),
+        (_, Some(u), _) => i128::from(u),
+        (_, _, Some(f)) if f.fract() == 0.0 => return Err(out_of_range()),
+        _ => {
+            return Err(Error::TypeMismatch {
+                expected: t.clone(),
+                got: format!("non-integer number {n}"),
+            })
+        }
+    };
+    T::try_from(int).map_err(|_| out_of_range())
+}
+
+fn parse_int_error(e: &core::num::ParseIntError, s: &str, t: &ScType) -> Error {
+    match e.kind() {
+        core::num::IntErrorKind::PosOverflow | core::num::IntErrorKind::NegOverflow => {
+            Error::OutOfRange {
+                ty: t.clone(),
+                value: s.to_string(),
+            }
+        }
+        _ => Error::InvalidValue(Some(t.clone())),
+    }
+}
+
+fn json_type_name(v: &Value) -> &'static str {
+    match v {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 /// # Errors
 ///
 /// Might return an error
@@ -913,6 +1071,16 @@ pub fn to_string(v: &ScVal) -> Result<String, Error> {
     })
 }
 
+/// Like [`to_json`], but uses the contract spec to render `val` as `type_`, so struct fields
+/// and enum cases are labelled by name rather than by position.
+///
+/// # Errors
+///
+/// Might return an error
+pub fn to_json_with_spec(val: &ScVal, type_: &ScType, spec: &Spec) -> Result<Value, Error> {
+    spec.xdr_to_json(val, type_)
+}
+
 /// # Errors
 ///
 /// Might return an error
@@ -1063,6 +1231,25 @@ fn sc_address_from_json(s: &str) -> Result<ScVal, Error> {
         .ok_or(Error::InvalidValue(Some(ScType::Address)))
 }
 
+/// Decode binary given as `base64:`-prefixed base64, or as hex with an optional `0x` prefix.
+fn bytes_from_str(s: &str) -> Option<Vec<u8>> {
+    if let Some(b64) = s.strip_prefix("base64:") {
+        base64::engine::general_purpose::STANDARD.decode(b64).ok()
+    } else {
+        hex::decode(s.strip_prefix("0x").unwrap_or(s)).ok()
+    }
+}
+
+/// Like [`bytes_from_str`], but left pads hex input to `n` bytes and requires base64 input to
+/// be exactly `n` bytes.
+fn bytes_n_from_str(s: &str, n: usize) -> Option<Vec<u8>> {
+    if s.starts_with("base64:") {
+        bytes_from_str(s).filter(|b| b.len() == n)
+    } else {
+        utils::padded_hex_from_str(s.strip_prefix("0x").unwrap_or(s), n).ok()
+    }
+}
+
 fn to_lower_hex(bytes: &[u8]) -> String {
     let mut res = String::with_capacity(bytes.len());
     for b in bytes {
@@ -1357,12 +1544,430 @@ impl Spec {
     }
 }
 
+impl Spec {
+    /// JSON Schema (draft 07) of the arguments of the contract's functions, keyed by function name,
+    /// in the JSON form that [`Spec::from_json`] parses. User defined types are referenced from
+    /// `definitions`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the spec is missing
+    pub fn json_schema(&self) -> Result<Value, Error> {
+        let functions = self
+            .find_functions()?
+            .map(|f| {
+                let args = f
+                    .inputs
+                    .iter()
+                    .map(|arg| {
+                        let schema = with_doc(type_schema(&arg.type_), &arg.doc);
+                        (arg.name.to_utf8_string_lossy(), schema)
+                    })
+                    .collect();
+                (
+                    f.name.to_utf8_string_lossy(),
+                    with_doc(object_schema(args), &f.doc),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let definitions = self
+            .0
+            .iter()
+            .flatten()
+            .filter_map(udt_schema)
+            .collect::<serde_json::Map<_, _>>();
+        Ok(json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": functions,
+            "additionalProperties": false,
+            "definitions": definitions,
+        }))
+    }
+}
+
+fn type_schema(type_: &ScType) -> Value {
+    match type_ {
+        ScType::Bool => json!({ "type": "boolean" }),
+        ScType::U32 => integer_schema(u32::MIN, u32::MAX),
+        ScType::I32 => integer_schema(i32::MIN, i32::MAX),
+        ScType::U64 | ScType::Timepoint | ScType::Duration => integer_schema(u64::MIN, u64::MAX),
+        ScType::I64 => integer_schema(i64::MIN, i64::MAX),
+        // Too large for JSON numbers, so they are also accepted as strings
+        ScType::U128 => big_integer_schema("^[0-9]+$"),
+        ScType::I128 => big_integer_schema("^-?[0-9]+$"),
+        ScType::U256 => big_integer_schema("^(0x[0-9a-fA-F]+|[0-9]+)$"),
+        ScType::I256 => big_integer_schema("^-?(0x[0-9a-fA-F]+|[0-9]+)$"),
+        ScType::Symbol => {
+            json!({ "type": "string", "maxLength": 32, "pattern": "^[a-zA-Z0-9_]*$" })
+        }
+        ScType::String | ScType::Address => json!({ "type": "string" }),
+        ScType::Bytes => json!({ "type": "string", "pattern": "^([0-9a-fA-F]{2})*$" }),
+        ScType::BytesN(bytes) => {
+            json!({ "type": "string", "pattern": format!("^[0-9a-fA-F]{{{}}}$", bytes.n * 2) })
+        }
+        ScType::Void => json!({ "type": "null" }),
+        ScType::Option(option) => {
+            json!({ "anyOf": [{ "type": "null" }, type_schema(&option.value_type)] })
+        }
+        ScType::Vec(vec) => json!({ "type": "array", "items": type_schema(&vec.element_type) }),
+        ScType::Map(map) => {
+            json!({ "type": "object", "additionalProperties": type_schema(&map.value_type) })
+        }
+        ScType::Tuple(tuple) => tuple_schema(tuple.value_types.iter()),
+        ScType::Result(result) => json!({
+            "oneOf": [
+                single_key_schema("ok", type_schema(&result.ok_type)),
+                single_key_schema("err", type_schema(&result.error_type)),
+            ],
+        }),
+        ScType::Udt(ScSpecTypeUdt { name }) => {
+            json!({ "$ref": format!("#/definitions/{}", name.to_utf8_string_lossy()) })
+        }
+        ScType::Val | ScType::Error => json!({}),
+    }
+}
+
+fn udt_schema(entry: &ScSpecEntry) -> Option<(String, Value)> {
+    let (name, doc, schema) = match entry {
+        ScSpecEntry::FunctionV0(_) => return None,
+        ScSpecEntry::UdtStructV0(ScSpecUdtStructV0 {
+            name, doc, fields, ..
+        }) => {
+            let schema = if fields.iter().any(|f| f.name.to_utf8_string_lossy() == "0") {
+                tuple_schema(fields.iter().map(|f| &f.type_))
+            } else {
+                object_schema(
+                    fields
+                        .iter()
+                        .map(|f| {
+                            let schema = with_doc(type_schema(&f.type_), &f.doc);
+                            (f.name.to_utf8_string_lossy(), schema)
+                        })
+                        .collect(),
+                )
+            };
+            (name, doc, schema)
+        }
+        ScSpecEntry::UdtUnionV0(ScSpecUdtUnionV0 {
+            name, doc, cases, ..
+        }) => {
+            let cases = cases
+                .iter()
+                .map(|case| match case {
+                    ScSpecUdtUnionCaseV0::VoidV0(ScSpecUdtUnionCaseVoidV0 { name, doc }) => {
+                        with_doc(json!({ "const": name.to_utf8_string_lossy() }), doc)
+                    }
+                    ScSpecUdtUnionCaseV0::TupleV0(ScSpecUdtUnionCaseTupleV0 {
+                        name,
+                        doc,
+                        type_,
+                    }) => {
+                        let value = match type_.as_slice() {
+                            [single] => type_schema(single),
+                            types => tuple_schema(types.iter()),
+                        };
+                        with_doc(single_key_schema(&name.to_utf8_string_lossy(), value), doc)
+                    }
+                })
+                .collect::<Vec<_>>();
+            (name, doc, json!({ "oneOf": cases }))
+        }
+        ScSpecEntry::UdtEnumV0(ScSpecUdtEnumV0 {
+            name, doc, cases, ..
+        }) => {
+            let cases = cases
+                .iter()
+                .map(|c| (c.name.to_utf8_string_lossy(), c.value));
+            (name, doc, enum_schema(cases))
+        }
+        ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            name, doc, cases, ..
+        }) => {
+            let cases = cases
+                .iter()
+                .map(|c| (c.name.to_utf8_string_lossy(), c.value));
+            (name, doc, enum_schema(cases))
+        }
+    };
+    Some((name.to_utf8_string_lossy(), with_doc(schema, doc)))
+}
+
+fn integer_schema(minimum: impl Into<Value>, maximum: impl Into<Value>) -> Value {
+    let (minimum, maximum) = (minimum.into(), maximum.into());
+    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+}
+
+fn big_integer_schema(pattern: &str) -> Value {
+    json!({ "type": ["integer", "string"], "pattern": pattern })
+}
+
+fn tuple_schema<'a>(types: impl ExactSizeIterator<Item = &'a ScType>) -> Value {
+    let len = types.len();
+    let items = types.map(type_schema).collect::<Vec<_>>();
+    json!({ "type": "array", "items": items, "minItems": len, "maxItems": len })
+}
+
+/// Object with exactly the given properties, all of them required.
+fn object_schema(properties: serde_json::Map<String, Value>) -> Value {
+    let required = properties.keys().cloned().collect::<Vec<_>>();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn single_key_schema(key: &str, schema: Value) -> Value {
+    object_schema([(key.to_string(), schema)].into_iter().collect())
+}
+
+/// Enum cases can be given by name or by value.
+fn enum_schema(cases: impl Iterator<Item = (String, u32)>) -> Value {
+    let (names, values): (Vec<Value>, Vec<Value>) = cases
+        .map(|(name, value)| (name.into(), value.into()))
+        .unzip();
+    json!({ "enum": names.into_iter().chain(values).collect::<Vec<_>>() })
+}
+
+fn with_doc<const MAX: u32>(mut schema: Value, doc: &StringM<MAX>) -> Value {
+    let doc = doc.to_utf8_string_lossy();
+    if doc.is_empty() {
+        return schema;
+    }
+    if let Value::Object(map) = &mut schema {
+        map.insert("description".to_string(), Value::String(doc));
+    }
+    schema
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use stellar_xdr::curr::ScSpecTypeBytesN;
 
+    fn result_spec() -> (Spec, ScSpecTypeResult) {
+        let error_enum = ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: "Error".try_into().unwrap(),
+            cases: vec![ScSpecUdtErrorEnumCaseV0 {
+                doc: StringM::default(),
+                name: "NotFound".try_into().unwrap(),
+                value: 1,
+            }]
+            .try_into()
+            .unwrap(),
+        });
+        let result = ScSpecTypeResult {
+            ok_type: Box::new(ScType::U32),
+            error_type: Box::new(ScType::Udt(ScSpecTypeUdt {
+                name: "Error".try_into().unwrap(),
+            })),
+        };
+        (Spec::new(vec![error_enum]), result)
+    }
+
+    fn udt(name: &str) -> ScType {
+        ScType::Udt(ScSpecTypeUdt {
+            name: name.try_into().unwrap(),
+        })
+    }
+
+    fn udt_spec() -> Spec {
+        let color = ScSpecEntry::UdtEnumV0(ScSpecUdtEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: "Color".try_into().unwrap(),
+            cases: ["Red", "Green"]
+                .iter()
+                .zip(0..)
+                .map(|(name, value)| stellar_xdr::curr::ScSpecUdtEnumCaseV0 {
+                    doc: StringM::default(),
+                    name: (*name).try_into().unwrap(),
+                    value,
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        });
+        let strukt = |name: &str, fields: [&str; 2]| {
+            ScSpecEntry::UdtStructV0(ScSpecUdtStructV0 {
+                doc: StringM::default(),
+                lib: StringM::default(),
+                name: name.try_into().unwrap(),
+                fields: fields
+                    .iter()
+                    .map(|name| stellar_xdr::curr::ScSpecUdtStructFieldV0 {
+                        doc: StringM::default(),
+                        name: (*name).try_into().unwrap(),
+                        type_: ScType::I32,
+                    })
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap(),
+            })
+        };
+        let shape = ScSpecEntry::UdtUnionV0(ScSpecUdtUnionV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: "Shape".try_into().unwrap(),
+            cases: vec![ScSpecUdtUnionCaseV0::VoidV0(ScSpecUdtUnionCaseVoidV0 {
+                doc: StringM::default(),
+                name: "Empty".try_into().unwrap(),
+            })]
+            .try_into()
+            .unwrap(),
+        });
+        Spec::new(vec![
+            color,
+            strukt("Point", ["x", "y"]),
+            strukt("Pair", ["0", "1"]),
+            shape,
+        ])
+    }
+
+    #[test]
+    fn udt_enum_by_name() {
+        let spec = udt_spec();
+        assert_eq!(
+            spec.from_string("Green", &udt("Color")).unwrap(),
+            ScVal::U32(1)
+        );
+        assert_eq!(
+            spec.from_json(&json!(0), &udt("Color")).unwrap(),
+            ScVal::U32(0)
+        );
+        assert!(matches!(
+            spec.from_json(&json!("Blue"), &udt("Color")),
+            Err(Error::EnumCase(..))
+        ));
+    }
+
+    #[test]
+    fn udt_struct_by_field() {
+        let spec = udt_spec();
+        let val = spec
+            .from_json(&json!({ "x": 1, "y": -2 }), &udt("Point"))
+            .unwrap();
+        let entry = |key: &str, val| ScMapEntry {
+            key: ScVal::Symbol(ScSymbol(key.try_into().unwrap())),
+            val,
+        };
+        assert_eq!(
+            val,
+            ScVal::Map(Some(
+                vec![entry("x", ScVal::I32(1)), entry("y", ScVal::I32(-2))]
+                    .try_into()
+                    .unwrap()
+            ))
+        );
+        assert!(matches!(
+            spec.from_json(&json!({ "x": 1 }), &udt("Point")),
+            Err(Error::MissingKey(..))
+        ));
+        assert!(matches!(
+            spec.from_json(&json!(true), &udt("Point")),
+            Err(Error::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn udt_struct_to_json_with_spec() {
+        let spec = udt_spec();
+        let entry = |key: &str, val| ScMapEntry {
+            key: ScVal::Symbol(ScSymbol(key.try_into().unwrap())),
+            val,
+        };
+        let point = ScVal::Map(Some(
+            vec![entry("y", ScVal::I32(-2)), entry("x", ScVal::I32(1))]
+                .try_into()
+                .unwrap(),
+        ));
+        assert_eq!(
+            to_json_with_spec(&point, &udt("Point"), &spec).unwrap(),
+            json!({ "x": 1, "y": -2 })
+        );
+        let partial = ScVal::Map(Some(vec![entry("x", ScVal::I32(1))].try_into().unwrap()));
+        assert!(matches!(
+            to_json_with_spec(&partial, &udt("Point"), &spec),
+            Err(Error::MissingKey(key)) if key == "y"
+        ));
+    }
+
+    #[test]
+    fn udt_union_rejects_malformed_values() {
+        let spec = udt_spec();
+        let empty = ScVal::Vec(Some(
+            vec![ScVal::Symbol("Empty".try_into().unwrap())]
+                .try_into()
+                .unwrap(),
+        ));
+        assert_eq!(
+            spec.from_json(&json!("Empty"), &udt("Shape")).unwrap(),
+            empty
+        );
+        for value in [
+            json!(["Empty"]),
+            json!({ "Empty": 1, "Other": 2 }),
+            json!({}),
+        ] {
+            assert!(matches!(
+                spec.from_json(&value, &udt("Shape")),
+                Err(Error::InvalidValue(Some(ScType::Udt(ScSpecTypeUdt { name }))))
+                    if name.to_utf8_string_lossy() == "Shape"
+            ));
+        }
+    }
+
+    #[test]
+    fn udt_tuple_struct_by_index() {
+        let spec = udt_spec();
+        let expected = ScVal::Vec(Some(vec![ScVal::I32(1), ScVal::I32(2)].try_into().unwrap()));
+        assert_eq!(
+            spec.from_json(&json!({ "0": 1, "1": 2 }), &udt("Pair"))
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            spec.from_json(&json!([1, 2]), &udt("Pair")).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            spec.from_json(&json!({ "0": 1, "2": 2 }), &udt("Pair")),
+            Err(Error::MissingKey(key)) if key == "1"
+        ));
+    }
+
+    #[test]
+    fn result_ok() {
+        let (spec, result) = result_spec();
+        let t = ScType::Result(Box::new(result.clone()));
+        let val = spec.from_json(&json!({ "ok": 7 }), &t).unwrap();
+        assert_eq!(val, ScVal::U32(7));
+        assert_eq!(spec.result_to_string(&val, &result).unwrap(), r#"{"ok":7}"#);
+    }
+
+    #[test]
+    fn result_err() {
+        let (spec, result) = result_spec();
+        let t = ScType::Result(Box::new(result.clone()));
+        let expected = ScVal::Error(ScError::Contract(1));
+        assert_eq!(
+            spec.from_json(&json!({ "err": "NotFound" }), &t).unwrap(),
+            expected
+        );
+        assert_eq!(spec.from_json(&json!({ "err": 1 }), &t).unwrap(), expected);
+        assert_eq!(
+            spec.result_to_string(&expected, &result).unwrap(),
+            r#"{"err":"NotFound"}"#
+        );
+        assert!(spec.from_json(&json!({ "err": "Missing" }), &t).is_err());
+        assert!(spec.from_json(&json!(7), &t).is_err());
+    }
+
     #[test]
     fn from_json_primitives_bytesn() {
         // TODO: Add test for parsing addresses
@@ -1410,6 +2015,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_json_primitives_bytes_prefixed() {
+        let expected = ScVal::Bytes(ScBytes(vec![0xbe, 0xef, 0xfa, 0xce].try_into().unwrap()));
+        for s in ["beefface", "0xbeefface", "base64:vu/6zg=="] {
+            assert_eq!(
+                from_json_primitives(&Value::String(s.to_string()), &ScType::Bytes).unwrap(),
+                expected,
+                "{s}"
+            );
+            assert_eq!(
+                from_json_primitives(
+                    &Value::String(s.to_string()),
+                    &ScType::BytesN(ScSpecTypeBytesN { n: 4 })
+                )
+                .unwrap(),
+                expected,
+                "{s}"
+            );
+        }
+
+        // Base64 input must match the length of fixed size bytes exactly
+        assert!(from_json_primitives(
+            &Value::String("base64:vu8=".to_string()),
+            &ScType::BytesN(ScSpecTypeBytesN { n: 4 })
+        )
+        .is_err());
+        assert!(
+            from_json_primitives(&Value::String("base64:!".to_string()), &ScType::Bytes).is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_json_primitives_out_of_range() {
+        let err = from_json_primitives(&json!(4_294_967_296u64), &ScType::U32).unwrap_err();
+        assert!(
+            matches!(&err, Error::OutOfRange { ty: ScType::U32, value } if value == "4294967296")
+        );
+        assert_eq!(err.to_string(), "4294967296 is out of range for type U32");
+        assert!(matches!(
+            from_json_primitives(&json!(-1), &ScType::U64),
+            Err(Error::OutOfRange {
+                ty: ScType::U64,
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_json_primitives(&json!(1e20), &ScType::I64),
+            Err(Error::OutOfRange {
+                ty: ScType::I64,
+                ..
+            })
+        ));
+        let too_large = json!("340282366920938463463374607431768211456");
+        assert!(matches!(
+            from_json_primitives(&too_large, &ScType::U128),
+            Err(Error::OutOfRange {
+                ty: ScType::U128,
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_json_primitives(&json!("abc"), &ScType::U128),
+            Err(Error::InvalidValue(Some(ScType::U128)))
+        ));
+    }
+
+    #[test]
+    fn test_from_json_primitives_type_mismatch() {
+        let err = from_json_primitives(&json!("5"), &ScType::U32).unwrap_err();
+        assert!(
+            matches!(&err, Error::TypeMismatch { expected: ScType::U32, got } if got == "string")
+        );
+        assert_eq!(err.to_string(), "expected a value of type U32, got string");
+        assert!(matches!(
+            from_json_primitives(&json!(1.5), &ScType::I32),
+            Err(Error::TypeMismatch {
+                expected: ScType::I32,
+                ..
+            })
+        ));
+        assert!(matches!(
+            from_json_primitives(&json!(true), &ScType::I128),
+            Err(Error::TypeMismatch {
+                expected: ScType::I128,
+                ..
+            })
+        ));
+        assert_eq!(
+            from_json_primitives(&json!(7), &ScType::U128).unwrap(),
+            ScVal::U128(UInt128Parts { hi: 0, lo: 7 })
+        );
+    }
+
     #[test]
     fn test_sc_address_from_json_strkey() {
         // All zero contract address
@@ -1463,4 +2161,36 @@ mod tests {
             Err(e) => panic!("Unexpected error: {e}"),
         }
     }
+
+    #[test]
+    fn json_schema_of_function_args() {
+        let arg = |name: &str, type_| stellar_xdr::curr::ScSpecFunctionInputV0 {
+            doc: StringM::default(),
+            name: name.try_into().unwrap(),
+            type_,
+        };
+        let spec = Spec::new(vec![ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+            doc: "Adds a counter".try_into().unwrap(),
+            name: ScSymbol("add".try_into().unwrap()),
+            inputs: vec![arg("count", ScType::U32), arg("key", ScType::Symbol)]
+                .try_into()
+                .unwrap(),
+            outputs: VecM::default(),
+        })]);
+        let schema = spec.json_schema().unwrap();
+        assert_eq!(
+            schema["properties"]["add"],
+            json!({
+                "description": "Adds a counter",
+                "type": "object",
+                "properties": {
+                    "count": { "type": "integer", "minimum": 0, "maximum": 4_294_967_295_u32 },
+                    "key": { "type": "string", "maxLength": 32, "pattern": "^[a-zA-Z0-9_]*$" },
+                },
+                "required": ["count", "key"],
+                "additionalProperties": false,
+            })
+        );
+        assert_eq!(schema["definitions"], json!({}));
+    }
 }
@@ -70,6 +70,7 @@ impl Default for TestEnv {
                 rpc_url: "http://localhost:8889/soroban/rpc".to_string(),
                 network_passphrase: LOCAL_NETWORK_PASSPHRASE.to_string(),
                 rpc_headers: [].to_vec(),
+                rpc_timeout: None,
             },
         }
     }
@@ -258,9 +259,15 @@ impl TestEnv {
         config::Args {
             network: network::Args {
                 rpc_url: Some(self.network.rpc_url.clone()),
+                rpc_url_env: None,
                 rpc_headers: [].to_vec(),
                 network_passphrase: Some(LOCAL_NETWORK_PASSPHRASE.to_string()),
+                network_passphrase_env: None,
                 network: None,
+                rpc_timeout: None,
+                auto_passphrase: false,
+                fetched_passphrase: Default::default(),
+                network_url: None,
             },
             source_account: account.parse().unwrap(),
             locator: config::locator::Args {
@@ -276,17 +283,23 @@ impl TestEnv {
         &self,
         cmd: T,
         account: &str,
+    ) -> Result<T::Result, T::Error> {
+        self.run_cmd_with_global_args(cmd, account, global::Args::default())
+            .await
+    }
+
+    /// Like [`TestEnv::run_cmd_with`], with global arguments such as `--offline`
+    pub async fn run_cmd_with_global_args<T: NetworkRunnable>(
+        &self,
+        cmd: T,
+        account: &str,
+        global_args: global::Args,
     ) -> Result<T::Result, T::Error> {
         let config = self.clone_config(account);
         cmd.run_against_rpc_server(
             Some(&global::Args {
                 locator: config.locator.clone(),
-                filter_logs: Vec::default(),
-                quiet: false,
-                verbose: false,
-                very_verbose: false,
-                list: false,
-                no_cache: false,
+                ..global_args
             }),
             Some(&config),
         )
@@ -6,6 +6,7 @@ use std::{fs, path::Path};
 use crate::util::{add_key, add_test_id, SecretKind, DEFAULT_SEED_PHRASE};
 use soroban_cli::commands::network;
 use soroban_cli::config::network::passphrase::LOCAL as LOCAL_NETWORK_PASSPHRASE;
+use soroban_cli::config::{locator::KeyType, secret::Secret};
 
 fn ls(sandbox: &TestEnv) -> Vec<String> {
     sandbox
@@ -212,6 +213,78 @@ fn generate_key() {
     );
 }
 
+#[test]
+fn generate_key_prints_address_at_hd_path() {
+    let sandbox = TestEnv::default();
+    let generated = sandbox
+        .new_assert_cmd("keys")
+        .args([
+            "generate",
+            "--no-fund",
+            "--default-seed",
+            "--hd-path",
+            "1",
+            "--print-address",
+            "test_hd",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let address_at = |hd_path: usize| {
+        sandbox
+            .new_assert_cmd("keys")
+            .args(["address", "test_hd", "--hd-path", &hd_path.to_string()])
+            .assert()
+            .success()
+            .stdout_as_str()
+    };
+    let addresses = (0..3).map(address_at).collect::<Vec<_>>();
+    assert_eq!(generated, addresses[1]);
+    assert_ne!(addresses[0], addresses[1]);
+    assert_ne!(addresses[1], addresses[2]);
+    assert_ne!(addresses[0], addresses[2]);
+    for (hd_path, address) in addresses.iter().enumerate() {
+        assert_eq!(address, &address_at(hd_path));
+    }
+}
+
+#[test]
+fn generate_key_dry_run() {
+    let sandbox = TestEnv::default();
+    let printed = sandbox
+        .new_assert_cmd("keys")
+        .args(["generate", "--default-seed", "--dry-run", "test_dry"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert!(!sandbox
+        .dir()
+        .join(".soroban/identity/test_dry.toml")
+        .exists());
+    assert!(!sandbox
+        .dir()
+        .join(".stellar/identity/test_dry.toml")
+        .exists());
+    sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "test_dry"])
+        .assert()
+        .failure();
+
+    sandbox
+        .new_assert_cmd("keys")
+        .args(["generate", "--no-fund", "--default-seed", "test_dry"])
+        .assert()
+        .success();
+    let address = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "test_dry"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_eq!(printed, address);
+}
+
 #[test]
 fn generate_key_on_testnet() {
     if std::env::var("CI_TEST").is_err() {
@@ -437,3 +510,165 @@ fn cannot_create_key_with_alias() {
         ))
         .failure();
 }
+
+#[test]
+fn config_show_redacts_secrets() {
+    let sandbox = TestEnv::default();
+    add_key(
+        sandbox.dir(),
+        "alice",
+        SecretKind::Seed,
+        DEFAULT_SEED_PHRASE,
+    );
+    let secret_key = "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN";
+    add_key(sandbox.dir(), "bob", SecretKind::Key, secret_key);
+    add_network(&sandbox, "local");
+    add_network(&sandbox, "other");
+    sandbox
+        .new_assert_cmd("contract")
+        .arg("alias")
+        .arg("add")
+        .arg("hello")
+        .arg("--id=CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE")
+        .assert()
+        .success();
+
+    let output = sandbox
+        .new_assert_cmd("config")
+        .arg("show")
+        .assert()
+        .success()
+        .stdout_as_str();
+
+    assert!(
+        output.contains("  alice: seed phrase (redacted)"),
+        "{output}"
+    );
+    assert!(output.contains("  bob: secret key (redacted)"), "{output}");
+    assert!(output.contains(&format!(
+        "  local: https://127.0.0.1 ({LOCAL_NETWORK_PASSPHRASE})"
+    )));
+    assert!(output.contains(&format!(
+        "  other: https://127.0.0.1 ({LOCAL_NETWORK_PASSPHRASE})"
+    )));
+    assert!(output.contains("  hello: CA3D5KRYM6CB7OWQ6TWYRR3Z4T7GNZLKERYNZGGA5SOAOPIFY6YQGAXE ("));
+    assert!(!output.contains(DEFAULT_SEED_PHRASE));
+    assert!(!output.contains(secret_key));
+}
+
+#[test]
+fn config_migrate_reports_conflicts() {
+    let sandbox = TestEnv::default();
+    let secret_key = "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN";
+    add_key(
+        sandbox.dir(),
+        "alice",
+        SecretKind::Seed,
+        DEFAULT_SEED_PHRASE,
+    );
+    add_key(sandbox.dir(), "bob", SecretKind::Key, secret_key);
+    let stellar_dir = sandbox.dir().join(".stellar");
+    let stellar_alice = Secret::SecretKey {
+        secret_key: secret_key.to_string(),
+    };
+    KeyType::Identity
+        .write("alice", &stellar_alice, &stellar_dir)
+        .unwrap();
+
+    sandbox
+        .new_assert_cmd("config")
+        .args(["migrate", "--remove-old"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Conflicting identity \"alice\""))
+        .stderr(predicate::str::contains("Migrated identity \"bob\""))
+        .stderr(predicate::str::contains("identity alice"));
+
+    // The conflicting identity keeps its .stellar value and nothing is removed
+    let alice: Secret = KeyType::Identity.read("alice", &stellar_dir).unwrap();
+    assert!(matches!(alice, Secret::SecretKey { .. }));
+    let bob: Secret = KeyType::Identity.read("bob", &stellar_dir).unwrap();
+    assert!(matches!(bob, Secret::SecretKey { .. }));
+    assert!(sandbox.dir().join(".soroban").exists());
+}
+
+#[test]
+fn config_migrate_moves_everything_from_a_subdirectory() {
+    let sandbox = TestEnv::default();
+    add_key(
+        sandbox.dir(),
+        "alice",
+        SecretKind::Seed,
+        DEFAULT_SEED_PHRASE,
+    );
+    let alias = sandbox.dir().join(".soroban/contract-ids/hello.json");
+    fs::create_dir_all(alias.parent().unwrap()).unwrap();
+    fs::write(&alias, r#"{"ids":{}}"#).unwrap();
+    let sub_dir = sandbox.dir().join("sub");
+    fs::create_dir(&sub_dir).unwrap();
+
+    sandbox
+        .new_assert_cmd("config")
+        .current_dir(&sub_dir)
+        .args(["migrate", "--remove-old"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Migrated identity \"alice\""))
+        .stderr(predicate::str::contains("contract-ids"));
+
+    let stellar_dir = sandbox.dir().join(".stellar");
+    let alice: Secret = KeyType::Identity.read("alice", &stellar_dir).unwrap();
+    assert!(matches!(alice, Secret::SeedPhrase { .. }));
+    assert!(stellar_dir.join("contract-ids/hello.json").exists());
+    assert!(!sandbox.dir().join(".soroban").exists());
+    assert!(!sub_dir.join(".stellar").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn config_migrate_skips_symlinked_directories() {
+    let sandbox = TestEnv::default();
+    add_key(
+        sandbox.dir(),
+        "alice",
+        SecretKind::Seed,
+        DEFAULT_SEED_PHRASE,
+    );
+    let soroban_dir = sandbox.dir().join(".soroban");
+    std::os::unix::fs::symlink(&soroban_dir, soroban_dir.join("loop")).unwrap();
+
+    sandbox
+        .new_assert_cmd("config")
+        .args(["migrate"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Migrated identity \"alice\""));
+
+    let stellar_dir = sandbox.dir().join(".stellar");
+    assert!(stellar_dir.join("identity/alice.toml").exists());
+    assert!(!stellar_dir.join("loop").exists());
+}
+
+#[test]
+fn exit_codes() {
+    let sandbox = TestEnv::default();
+    sandbox
+        .new_assert_cmd("keys")
+        .args(["ls", "--no-such-flag"])
+        .assert()
+        .code(2);
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "bump-sequence",
+            "--bump-to",
+            "1",
+            "--source-account",
+            "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+            "--rpc-url",
+            "http://127.0.0.1:1",
+        ])
+        .assert()
+        .code(3);
+}
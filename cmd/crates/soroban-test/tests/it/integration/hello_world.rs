@@ -1,9 +1,12 @@
+use predicates::boolean::PredicateBooleanExt;
 use soroban_cli::{
     commands::{
         contract::{self, fetch},
+        global,
         txn_result::TxnResult,
     },
     config::{locator, secret},
+    xdr::{self, ReadXdr},
 };
 use soroban_rpc::GetLatestLedgerResponse;
 use soroban_test::{AssertExt, TestEnv, LOCAL_NETWORK_PASSPHRASE};
@@ -156,6 +159,87 @@ pub(crate) fn invoke_hello_world(sandbox: &TestEnv, id: &str) {
         .success();
 }
 
+#[test]
+fn info_interface_json_compact() {
+    let sandbox = &TestEnv::new();
+    let wasm = HELLO_WORLD.path();
+    let output = sandbox
+        .new_assert_cmd("contract")
+        .args(["info", "interface", "--output", "json-compact", "--wasm"])
+        .arg(&wasm)
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert!(!output.contains('\n'));
+    let spec: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(spec.is_array());
+}
+
+#[tokio::test]
+async fn info_interface_by_alias() {
+    let sandbox = &TestEnv::new();
+    let id = deploy_hello(sandbox).await;
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["alias", "add", "hello", "--id", &id])
+        .assert()
+        .success();
+    let by_id = sandbox
+        .new_assert_cmd("contract")
+        .args(["info", "interface", "--id", &id])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let by_alias = sandbox
+        .new_assert_cmd("contract")
+        .args(["info", "interface", "--id", "hello"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_eq!(by_id, by_alias);
+}
+
+#[tokio::test]
+async fn alias_add_ls_remove_and_invoke() {
+    let sandbox = &TestEnv::new();
+    let id = deploy_hello(sandbox).await;
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["alias", "add", "greeter", "--id", &id])
+        .assert()
+        .success();
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["alias", "ls"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(format!("greeter: {id}")));
+    let res = sandbox
+        .new_assert_cmd("contract")
+        .args(["invoke", "--id", "greeter", "--", "hello", "--world=alias"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_eq!(res.trim(), r#"["Hello","alias"]"#);
+
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["alias", "remove", "greeter"])
+        .assert()
+        .success();
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["alias", "ls"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("greeter").not());
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["invoke", "--id", "greeter", "--", "hello", "--world=alias"])
+        .assert()
+        .failure();
+}
+
 fn hello_world_cmd(id: &str, arg: &str) -> contract::invoke::Cmd {
     contract::invoke::Cmd {
         contract_id: id.parse().unwrap(),
@@ -310,6 +394,132 @@ async fn contract_data_read() {
         .stdout(predicates::str::starts_with("COUNTER,2"));
 }
 
+#[tokio::test]
+async fn contract_data_extend_ttl() {
+    const KEY: &str = "COUNTER";
+    let sandbox = &TestEnv::new();
+    let id = &deploy_hello(sandbox).await;
+    sandbox
+        .invoke_with_test(&["--id", id, "--", "inc"])
+        .await
+        .unwrap();
+    let live_until = || -> u32 {
+        let row = sandbox
+            .new_assert_cmd("contract")
+            .args(["read", "--id", id, "--key", KEY])
+            .assert()
+            .success()
+            .stdout_as_str();
+        row.trim().rsplit(',').next().unwrap().parse().unwrap()
+    };
+    let before = live_until();
+
+    let ttl_ledger: u32 = sandbox
+        .new_assert_cmd("contract")
+        .args(["extend", "--id", id, "--key", KEY])
+        .args(["--ledgers-to-extend", "100001", "--ttl-ledger-only"])
+        .assert()
+        .success()
+        .stdout_as_str()
+        .trim()
+        .parse()
+        .unwrap();
+    assert!(ttl_ledger > before);
+    assert_eq!(live_until(), ttl_ledger);
+}
+
+#[tokio::test]
+async fn contract_data_restore_footprint() {
+    const KEY: &str = "COUNTER";
+    let sandbox = &TestEnv::new();
+    let id = &deploy_hello(sandbox).await;
+    sandbox
+        .invoke_with_test(&["--id", id, "--", "inc"])
+        .await
+        .unwrap();
+
+    let tx = sandbox
+        .new_assert_cmd("contract")
+        .args(["restore", "--id", id, "--key", KEY, "--build-only"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let xdr::TransactionEnvelope::Tx(xdr::TransactionV1Envelope { tx, .. }) =
+        xdr::TransactionEnvelope::from_xdr_base64(tx, xdr::Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 transaction envelope");
+    };
+    assert!(matches!(
+        tx.operations[0].body,
+        xdr::OperationBody::RestoreFootprint(_)
+    ));
+    let xdr::TransactionExt::V1(data) = tx.ext else {
+        panic!("expected soroban transaction data");
+    };
+    let read_write = data.resources.footprint.read_write.to_vec();
+    assert!(matches!(
+        &read_write[..],
+        [xdr::LedgerKey::ContractData(xdr::LedgerKeyContractData {
+            key: xdr::ScVal::Symbol(key),
+            durability: xdr::ContractDataDurability::Persistent,
+            ..
+        })] if key.0.as_slice() == KEY.as_bytes()
+    ));
+
+    // Wait for the entry to be archived, so the restore has something to do.
+    let live_until = || -> u32 {
+        let row = sandbox
+            .new_assert_cmd("contract")
+            .args(["read", "--id", id, "--key", KEY])
+            .assert()
+            .success()
+            .stdout_as_str();
+        row.trim().rsplit(',').next().unwrap().parse().unwrap()
+    };
+    let archived_after = live_until();
+    let client = sandbox.client();
+    while client.get_latest_ledger().await.unwrap().sequence <= archived_after {
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+
+    let restored = sandbox
+        .new_assert_cmd("contract")
+        .args(["restore", "--id", id, "--key", KEY])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let ttl_ledger: u32 = restored
+        .trim()
+        .strip_prefix("New ttl ledger: ")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let latest_ledger = client.get_latest_ledger().await.unwrap().sequence;
+    assert!(ttl_ledger > latest_ledger);
+    assert_eq!(live_until(), ttl_ledger);
+
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["read", "--id", id, "--key", KEY])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with("COUNTER,1"));
+}
+
+#[tokio::test]
+async fn deploy_missing_wasm_hash() {
+    let sandbox = TestEnv::new();
+    let wasm_hash = "a".repeat(64);
+    sandbox
+        .new_assert_cmd("contract")
+        .args(["deploy", "--wasm-hash", &wasm_hash])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(format!(
+            "no wasm with hash {wasm_hash} is installed on the network, install the wasm first"
+        )));
+}
+
 #[tokio::test]
 #[ignore]
 async fn half_max_instructions() {
@@ -373,7 +583,7 @@ async fn fetch(sandbox: &TestEnv, id: &str) {
         "--out-file",
         f.to_str().unwrap(),
     ]);
-    cmd.run().await.unwrap();
+    cmd.run(&global::Args::default()).await.unwrap();
     assert!(f.exists());
 }
 
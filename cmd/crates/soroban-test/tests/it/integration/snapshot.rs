@@ -81,3 +81,46 @@ fn snapshot() {
         .assert(predicates::str::contains(&contract_b))
         .assert(predicates::str::contains(&contract_a).not());
 }
+
+#[test]
+fn snapshot_concurrency_does_not_change_output() {
+    let sandbox = &TestEnv::new();
+    let account = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "test"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    // Wait 8 ledgers for a checkpoint by submitting one tx per ledger.
+    for i in 1..=8 {
+        sandbox
+            .new_assert_cmd("keys")
+            .arg("generate")
+            .arg(format!("k{i}"))
+            .assert()
+            .success();
+    }
+    let create = |concurrency: &str, out: &str, ledger: Option<&str>| {
+        let mut cmd = sandbox.new_assert_cmd("snapshot");
+        cmd.args([
+            "create",
+            "--output=json",
+            "--address",
+            &account,
+            "--concurrency",
+            concurrency,
+            "--out",
+            out,
+        ]);
+        if let Some(ledger) = ledger {
+            cmd.args(["--ledger", ledger]);
+        }
+        cmd.assert().success();
+        std::fs::read_to_string(sandbox.dir().join(out)).unwrap()
+    };
+    let parallel = create("8", "parallel.json", None);
+    let snapshot: serde_json::Value = serde_json::from_str(&parallel).unwrap();
+    let ledger = snapshot["sequence_number"].to_string();
+    let serial = create("1", "serial.json", Some(&ledger));
+    assert_eq!(parallel, serial);
+}
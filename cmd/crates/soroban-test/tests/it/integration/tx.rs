@@ -1,11 +1,17 @@
 use soroban_cli::assembled::simulate_and_assemble_transaction;
-use soroban_cli::xdr::{Limits, ReadXdr, TransactionEnvelope, WriteXdr};
+use soroban_cli::commands::{contract, global};
+use soroban_cli::config::network;
+use soroban_cli::xdr::{
+    Limits, Preconditions, ReadXdr, TimeBounds, TimePoint, TransactionEnvelope, WriteXdr,
+};
 use soroban_test::{AssertExt, TestEnv};
 
 use crate::integration::util::{deploy_contract, DeployKind, HELLO_WORLD};
 
 pub mod operations;
 
+const SIGNED_TX: &str = include_str!("../../fixtures/signed_tx.txt");
+
 #[tokio::test]
 async fn simulate() {
     let sandbox = &TestEnv::new();
@@ -38,7 +44,7 @@ async fn simulate() {
 async fn txn_hash() {
     let sandbox = &TestEnv::new();
 
-    let xdr_base64 = "AAAAAgAAAACVk/0xt9tV/cUbF53iwQ3tkKLlq9zG2wV5qd9lRjZjlQAHt/sAFsKTAAAABAAAAAEAAAAAAAAAAAAAAABmOg6nAAAAAAAAAAEAAAAAAAAAGAAAAAAAAAABfcHs35M1GZ/JkY2+DHMs4dEUaqjynMnDYK/Gp0eulN8AAAAIdHJhbnNmZXIAAAADAAAAEgAAAAEFO1FR2Wg49QFY5KPOFAQ0bV5fN+7LD2GSQvOaHSH44QAAABIAAAAAAAAAAJWT/TG321X9xRsXneLBDe2QouWr3MbbBXmp32VGNmOVAAAACgAAAAAAAAAAAAAAADuaygAAAAABAAAAAQAAAAEFO1FR2Wg49QFY5KPOFAQ0bV5fN+7LD2GSQvOaHSH44QAAAY9SyLSVABbC/QAAABEAAAABAAAAAwAAAA8AAAASYXV0aGVudGljYXRvcl9kYXRhAAAAAAANAAAAJUmWDeWIDoxodDQXD2R2YFuP5K65ooYyx5lc87qDHZdjHQAAAAAAAAAAAAAPAAAAEGNsaWVudF9kYXRhX2pzb24AAAANAAAAcnsidHlwZSI6IndlYmF1dGhuLmdldCIsImNoYWxsZW5nZSI6ImhnMlRhOG8wWTliWFlyWlMyZjhzWk1kRFp6ektCSXhQNTZSd1FaNE90bTgiLCJvcmlnaW4iOiJodHRwOi8vbG9jYWxob3N0OjQ1MDcifQAAAAAADwAAAAlzaWduYXR1cmUAAAAAAAANAAAAQBcpuTFMxzkAdBs+5VIyJCBHaNuwEAva+kZVET4YuHVKF8gNII567RhxsnhBBSo5dDvssTN6vf2i42eEty66MtoAAAAAAAAAAX3B7N+TNRmfyZGNvgxzLOHRFGqo8pzJw2CvxqdHrpTfAAAACHRyYW5zZmVyAAAAAwAAABIAAAABBTtRUdloOPUBWOSjzhQENG1eXzfuyw9hkkLzmh0h+OEAAAASAAAAAAAAAACVk/0xt9tV/cUbF53iwQ3tkKLlq9zG2wV5qd9lRjZjlQAAAAoAAAAAAAAAAAAAAAA7msoAAAAAAAAAAAEAAAAAAAAAAwAAAAYAAAABfcHs35M1GZ/JkY2+DHMs4dEUaqjynMnDYK/Gp0eulN8AAAAUAAAAAQAAAAYAAAABBTtRUdloOPUBWOSjzhQENG1eXzfuyw9hkkLzmh0h+OEAAAAUAAAAAQAAAAeTiL4Gr2piUAmsXTev1ZzJ4kE2NUGZ0QMObd05iAMyzAAAAAMAAAAGAAAAAX3B7N+TNRmfyZGNvgxzLOHRFGqo8pzJw2CvxqdHrpTfAAAAEAAAAAEAAAACAAAADwAAAAdCYWxhbmNlAAAAABIAAAABBTtRUdloOPUBWOSjzhQENG1eXzfuyw9hkkLzmh0h+OEAAAABAAAAAAAAAACVk/0xt9tV/cUbF53iwQ3tkKLlq9zG2wV5qd9lRjZjlQAAAAYAAAABBTtRUdloOPUBWOSjzhQENG1eXzfuyw9hkkLzmh0h+OEAAAAVAAABj1LItJUAAAAAAEyTowAAGMgAAAG4AAAAAAADJBsAAAABRjZjlQAAAEASFnAIzNqpfdzv6yT0rSLMUDFgt7a/inCHurNCG55Jp8Imho04qRH+JNdkq0BgMC7yAJqH4N6Y2iGflFt3Lp4L";
+    let xdr_base64 = SIGNED_TX;
 
     let expected_hash = "bcc9fa60c8f6607c981d6e1c65d77ae07617720113f9080fe5883d8e4a331a68";
 
@@ -53,6 +59,85 @@ async fn txn_hash() {
     assert_eq!(hash.trim(), expected_hash);
 }
 
+#[tokio::test]
+async fn txn_hash_fee_bump() {
+    let sandbox = &TestEnv::new();
+    let output = sandbox
+        .new_assert_cmd("tx")
+        .args(["fee-bump", "--fee-source", "test", "--fee", "400"])
+        .write_stdin(SIGNED_TX.as_bytes())
+        .assert()
+        .success();
+    let fee_bumped = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let stderr = String::from_utf8(output.get_output().stderr.clone()).unwrap();
+
+    let hash = sandbox
+        .new_assert_cmd("tx")
+        .arg("hash")
+        .write_stdin(fee_bumped.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_ne!(
+        hash.trim(),
+        "bcc9fa60c8f6607c981d6e1c65d77ae07617720113f9080fe5883d8e4a331a68"
+    );
+    assert!(stderr.contains(&format!("Signing fee bump transaction: {}", hash.trim())));
+}
+
+#[tokio::test]
+async fn sign_output_json() {
+    let sandbox = &TestEnv::new();
+    let address = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "test"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let output = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key=test", "--output=json"])
+        .write_stdin(SIGNED_TX.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(
+        json["hash"],
+        "bcc9fa60c8f6607c981d6e1c65d77ae07617720113f9080fe5883d8e4a331a68"
+    );
+    assert_eq!(json["signer"], address.as_str());
+    let envelope =
+        TransactionEnvelope::from_xdr_base64(json["envelope"].as_str().unwrap(), Limits::none())
+            .unwrap();
+    let TransactionEnvelope::Tx(envelope) = envelope else {
+        panic!("expected a v1 transaction envelope");
+    };
+    assert_eq!(envelope.signatures.len(), 2);
+}
+
+#[tokio::test]
+async fn sign_verify_flags_wrong_passphrase() {
+    let sandbox = &TestEnv::new();
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key=test", "--verify"])
+        .write_stdin(SIGNED_TX.as_bytes())
+        .assert()
+        .success();
+    // The existing signature was made for the local network, so it is invalid for any other
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key=test", "--verify"])
+        .args(["--network-passphrase", "Test SDF Network ; September 2015"])
+        .write_stdin(SIGNED_TX.as_bytes())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "signature 0 is not a valid signature by",
+        ));
+}
+
 #[tokio::test]
 async fn build_simulate_sign_send() {
     let sandbox = &TestEnv::new();
@@ -84,3 +169,398 @@ async fn build_simulate_sign_send() {
         .success()
         .stdout(predicates::str::contains("SUCCESS"));
 }
+
+#[tokio::test]
+async fn check_auth_multisig() {
+    let sandbox = &TestEnv::new();
+    sandbox
+        .generate_account("multisig", None)
+        .assert()
+        .success();
+    sandbox
+        .generate_account("cosigner", None)
+        .assert()
+        .success();
+    let cosigner = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "cosigner"])
+        .assert()
+        .success()
+        .stdout_as_str();
+
+    // Make `multisig` a 2-of-2 account
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "set-options",
+            "--source",
+            "multisig",
+            "--signer",
+            cosigner.as_str(),
+            "--signer-weight",
+            "1",
+            "--master-weight",
+            "1",
+            "--low-threshold",
+            "2",
+            "--med-threshold",
+            "2",
+            "--high-threshold",
+            "2",
+        ])
+        .assert()
+        .success();
+
+    let tx = sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--source",
+            "multisig",
+            "--destination",
+            cosigner.as_str(),
+            "--amount",
+            "100",
+            "--build-only",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+
+    let signed_once = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "multisig"])
+        .write_stdin(tx.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    sandbox
+        .new_assert_cmd("tx")
+        .arg("check-auth")
+        .write_stdin(signed_once.as_bytes())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "signature weight 1 does not meet the required threshold of 2",
+        ));
+
+    let signed_twice = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "cosigner"])
+        .write_stdin(signed_once.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    sandbox
+        .new_assert_cmd("tx")
+        .arg("check-auth")
+        .write_stdin(signed_twice.as_bytes())
+        .assert()
+        .success();
+}
+
+#[tokio::test]
+async fn fee_bump() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    sandbox
+        .new_assert_cmd("keys")
+        .args(["generate", "--no-fund", "poor"])
+        .assert()
+        .success();
+    let poor = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "poor"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    // Funded with only the minimum balance, so `poor` cannot pay any fees itself
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["new", "create-account", "--destination", poor.as_str()])
+        .assert()
+        .success();
+    let before = client.get_account(&poor).await.unwrap();
+
+    let tx = sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "bump-sequence",
+            "--source",
+            "poor",
+            "--bump-to",
+            "1",
+            "--build-only",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let signed = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "poor"])
+        .write_stdin(tx.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    let fee_bumped = sandbox
+        .new_assert_cmd("tx")
+        .args(["fee-bump", "--fee-source", "test", "--fee", "200"])
+        .write_stdin(signed.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    sandbox
+        .new_assert_cmd("tx")
+        .arg("send")
+        .write_stdin(fee_bumped.as_bytes())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("SUCCESS"));
+
+    let after = client.get_account(&poor).await.unwrap();
+    assert_eq!(before.balance, after.balance);
+    assert_eq!(before.seq_num.0 + 1, after.seq_num.0);
+}
+
+#[tokio::test]
+async fn add_signature_from_external_signer() {
+    use ed25519_dalek::Signer;
+
+    let sandbox = &TestEnv::new();
+    let tx_base64 = sandbox
+        .new_assert_cmd("tx")
+        .args(["new", "bump-sequence", "--bump-to", "1", "--build-only"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let hash = sandbox
+        .new_assert_cmd("tx")
+        .arg("hash")
+        .write_stdin(tx_base64.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    let secret = sandbox
+        .new_assert_cmd("keys")
+        .args(["secret", "test"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let key = ed25519_dalek::SigningKey::from_bytes(
+        &stellar_strkey::ed25519::PrivateKey::from_string(secret.trim())
+            .unwrap()
+            .0,
+    );
+    let public_key = stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes()).to_string();
+    let add_signature = |signature: ed25519_dalek::Signature| {
+        let mut cmd = sandbox.new_assert_cmd("tx");
+        cmd.args(["add-signature", "--public-key", &public_key])
+            .args(["--signature", &hex::encode(signature.to_bytes())])
+            .write_stdin(tx_base64.as_bytes());
+        cmd
+    };
+
+    let signed = add_signature(key.sign(&hex::decode(hash.trim()).unwrap()))
+        .assert()
+        .success()
+        .stdout_as_str();
+    let expected = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "test"])
+        .write_stdin(tx_base64.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_eq!(signed, expected);
+
+    add_signature(key.sign(&[0; 32]))
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("is not a valid signature by"));
+}
+
+#[tokio::test]
+async fn sign_raw_xdr_input() {
+    let sandbox = &TestEnv::new();
+    let tx_base64 = sandbox
+        .new_assert_cmd("tx")
+        .args(["new", "bump-sequence", "--bump-to", "1", "--build-only"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let tx_raw = TransactionEnvelope::from_xdr_base64(&tx_base64, Limits::none())
+        .unwrap()
+        .to_xdr(Limits::none())
+        .unwrap();
+
+    let signed_from_base64 = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "test"])
+        .write_stdin(tx_base64.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    let signed_from_raw = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key", "test", "--input-format", "raw"])
+        .write_stdin(tx_raw)
+        .assert()
+        .success()
+        .stdout_as_str();
+    assert_eq!(signed_from_base64, signed_from_raw);
+}
+
+#[tokio::test]
+async fn simulate_output_table() {
+    let sandbox = &TestEnv::new();
+    let xdr_base64_build_only =
+        deploy_contract(sandbox, HELLO_WORLD, DeployKind::BuildOnly, None).await;
+    let table = sandbox
+        .new_assert_cmd("tx")
+        .args(["simulate", "--output", "table"])
+        .write_stdin(xdr_base64_build_only.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    for row in [
+        "Min resource fee",
+        "Instructions",
+        "Read bytes",
+        "Write bytes",
+        "Footprint",
+    ] {
+        assert!(table.contains(row), "missing {row} in {table}");
+    }
+}
+
+#[tokio::test]
+async fn simulate_payment_fails() {
+    let sandbox = &TestEnv::new();
+    sandbox.generate_account("test1", None).assert().success();
+    let test1 = sandbox
+        .new_assert_cmd("keys")
+        .args(["address", "test1"])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let tx = sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--destination",
+            test1.as_str(),
+            "--amount",
+            "100",
+            "--build-only",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+    // Only transactions invoking host functions can be simulated
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["simulate", "--output", "table"])
+        .write_stdin(tx.as_bytes())
+        .assert()
+        .failure();
+}
+
+#[tokio::test]
+async fn build_with_time_bounds() {
+    let sandbox = &TestEnv::new();
+    let tx_base64 = sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "bump-sequence",
+            "--bump-to",
+            "1",
+            "--min-time",
+            "1700000000",
+            "--max-time",
+            "1800000000",
+            "--build-only",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let TransactionEnvelope::Tx(env) =
+        TransactionEnvelope::from_xdr_base64(&tx_base64, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 transaction envelope");
+    };
+    let Preconditions::V2(cond) = env.tx.cond else {
+        panic!("expected v2 preconditions, got {:?}", env.tx.cond);
+    };
+    assert_eq!(
+        cond.time_bounds,
+        Some(TimeBounds {
+            min_time: TimePoint(1_700_000_000),
+            max_time: TimePoint(1_800_000_000),
+        })
+    );
+    assert_eq!(cond.ledger_bounds, None);
+}
+
+#[tokio::test]
+async fn offline_forbids_network_access() {
+    let sandbox = &TestEnv::new();
+    let bump = |args: &[&str]| {
+        let mut cmd = sandbox.new_assert_cmd("tx");
+        cmd.args(["new", "bump-sequence", "--bump-to", "100", "--offline"])
+            .args(args);
+        cmd.assert()
+    };
+
+    // With the sequence number given, building the transaction needs no network access
+    let tx_env = bump(&["--build-only", "--sequence-number", "42"])
+        .success()
+        .stdout_as_str();
+    let TransactionEnvelope::Tx(env) =
+        TransactionEnvelope::from_xdr_base64(tx_env, Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 transaction envelope");
+    };
+    assert_eq!(env.tx.seq_num.0, 42);
+
+    // Fetching the sequence number and submitting both need the network
+    bump(&["--build-only"])
+        .failure()
+        .stderr(predicates::str::contains("--offline"));
+    bump(&["--sequence-number", "42"])
+        .failure()
+        .stderr(predicates::str::contains("--offline"));
+    for args in [["fetch", "--id=hello"], ["read", "--id=hello"]] {
+        sandbox
+            .new_assert_cmd("contract")
+            .args(args)
+            .arg("--offline")
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains("--offline"));
+    }
+
+    // Library callers pass `--offline` in the global args
+    let invoke = sandbox.cmd_with_config::<_, contract::invoke::Cmd>(
+        &["--id=hello", "--", "hello", "--world=world"],
+        None,
+    );
+    let res = sandbox
+        .run_cmd_with_global_args(
+            invoke,
+            "test",
+            global::Args {
+                offline: true,
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(
+        res,
+        Err(contract::invoke::Error::Network(network::Error::Offline))
+    ));
+}
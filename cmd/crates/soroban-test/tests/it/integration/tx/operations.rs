@@ -122,6 +122,101 @@ async fn payment() {
     assert_eq!(before.balance - 10_000_100, after.balance);
 }
 
+#[tokio::test]
+async fn payment_over_balance() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let (test, test1) = setup_accounts(sandbox);
+    let before = client.get_account(&test).await.unwrap();
+
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--destination",
+            test1.as_str(),
+            "--amount",
+            &before.balance.to_string(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("insufficient balance"));
+    let after = client.get_account(&test).await.unwrap();
+    assert_eq!(before.seq_num, after.seq_num);
+}
+
+#[tokio::test]
+async fn payment_with_operation_source() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let (test, test1) = setup_accounts(sandbox);
+    let test_before = client.get_account(&test).await.unwrap();
+    let test1_before = client.get_account(&test1).await.unwrap();
+
+    // `test` is the transaction source and pays the fee, `test1` sends the payment
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--op-source",
+            "test1",
+            "--destination",
+            test.as_str(),
+            "--amount",
+            "10_000_000",
+        ])
+        .assert()
+        .success();
+
+    let test_after = client.get_account(&test).await.unwrap();
+    let test1_after = client.get_account(&test1).await.unwrap();
+    assert_eq!(test1_before.balance - ONE_XLM, test1_after.balance);
+    assert_eq!(test_before.balance + ONE_XLM - 100, test_after.balance);
+    assert_eq!(test1_before.seq_num, test1_after.seq_num);
+}
+
+#[tokio::test]
+async fn payment_to_muxed_id() {
+    let sandbox = &TestEnv::new();
+    let (_, test1) = setup_accounts(sandbox);
+    let tx_xdr = sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--destination",
+            test1.as_str(),
+            "--to-muxed-id",
+            "42",
+            "--amount",
+            "10_000_000",
+            "--build-only",
+        ])
+        .assert()
+        .success()
+        .stdout_as_str();
+    let xdr::TransactionEnvelope::Tx(xdr::TransactionV1Envelope { tx, .. }) =
+        xdr::TransactionEnvelope::from_xdr_base64(&tx_xdr, xdr::Limits::none()).unwrap()
+    else {
+        panic!("expected a v1 transaction envelope");
+    };
+    let xdr::OperationBody::Payment(xdr::PaymentOp { destination, .. }) = &tx.operations[0].body
+    else {
+        panic!("expected a payment operation");
+    };
+    let xdr::MuxedAccount::MuxedEd25519(xdr::MuxedAccountMed25519 { id, ed25519 }) = destination
+    else {
+        panic!("expected a muxed destination, got {destination:?}");
+    };
+    assert_eq!(*id, 42);
+    assert_eq!(
+        stellar_strkey::ed25519::PublicKey(ed25519.0).to_string(),
+        test1
+    );
+}
+
 #[tokio::test]
 async fn bump_sequence() {
     let sandbox = &TestEnv::new();
@@ -143,6 +238,81 @@ async fn bump_sequence() {
         .success();
     let after = client.get_account(&test).await.unwrap();
     assert_eq!(seq, after.seq_num);
+    // bump sequence relative to the current sequence number
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "bump-sequence",
+            "--bump-by",
+            amount.to_string().as_str(),
+        ])
+        .assert()
+        .success();
+    let after_bump_by = client.get_account(&test).await.unwrap();
+    assert_eq!(after.seq_num.0 + amount, after_bump_by.seq_num.0);
+    // --bump-to and --bump-by are mutually exclusive
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["new", "bump-sequence", "--bump-to", "1", "--bump-by", "1"])
+        .assert()
+        .failure();
+}
+
+#[tokio::test]
+async fn bump_sequence_quiet_success() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let test = test_address(sandbox);
+    let before = client.get_account(&test).await.unwrap();
+    let bump = |args: &[&str]| {
+        sandbox
+            .new_assert_cmd("tx")
+            .args(["new", "bump-sequence", "--bump-by", "1", "--quiet-success"])
+            .args(args)
+            .assert()
+            .success()
+            .stdout("");
+    };
+    bump(&[]);
+    let after = client.get_account(&test).await.unwrap();
+    assert_eq!(before.seq_num.0 + 1, after.seq_num.0);
+    // the transaction envelope that `--build-only` prints is suppressed too
+    bump(&["--build-only"]);
+}
+
+#[tokio::test]
+async fn bump_sequence_output_json() {
+    let sandbox = &TestEnv::new();
+    let build = |output: &str| {
+        sandbox
+            .new_assert_cmd("tx")
+            .args([
+                "new",
+                "bump-sequence",
+                "--bump-to",
+                "100",
+                "--build-only",
+                "--output",
+                output,
+            ])
+            .assert()
+            .success()
+            .stdout_as_str()
+    };
+    let from_xdr =
+        xdr::TransactionEnvelope::from_xdr_base64(build("xdr"), xdr::Limits::none()).unwrap();
+    let from_json: xdr::TransactionEnvelope = serde_json::from_str(&build("json")).unwrap();
+    assert_eq!(from_xdr, from_json);
+    let xdr::TransactionEnvelope::Tx(xdr::TransactionV1Envelope { tx, .. }) = from_json else {
+        panic!("expected a v1 transaction envelope");
+    };
+    assert_eq!(
+        tx.operations[0].body,
+        xdr::OperationBody::BumpSequence(xdr::BumpSequenceOp {
+            bump_to: SequenceNumber(100)
+        })
+    );
 }
 
 #[tokio::test]
@@ -525,6 +695,49 @@ async fn change_trust() {
         .stdout(format!("\"{bobs_limit}\"\n"));
 }
 
+#[tokio::test]
+async fn change_trust_with_asset_alias() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let (test, issuer) = setup_accounts(sandbox);
+    sandbox
+        .new_assert_cmd("contract")
+        .args([
+            "asset",
+            "alias",
+            "usdc",
+            "--asset",
+            &format!("usdc:{issuer}"),
+        ])
+        .assert()
+        .success();
+
+    let before = client.get_account(&test).await.unwrap();
+    sandbox
+        .new_assert_cmd("tx")
+        .args(["new", "change-trust", "--line", "usdc"])
+        .assert()
+        .success();
+    let after = client.get_account(&test).await.unwrap();
+    assert_eq!(before.num_sub_entries + 1, after.num_sub_entries);
+
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "payment",
+            "--source=test1",
+            "--destination",
+            &test,
+            "--asset",
+            "usdc",
+            "--amount",
+            "100",
+        ])
+        .assert()
+        .success();
+}
+
 #[tokio::test]
 async fn manage_data() {
     let sandbox = &TestEnv::new();
@@ -572,6 +785,142 @@ async fn manage_data() {
     assert_eq!(hex::encode(data_value.0.to_vec()), value);
 }
 
+/// Builds, signs and sends an offer operation, returning the offer it created
+async fn create_offer(sandbox: &TestEnv, args: &[&str]) -> xdr::OfferEntry {
+    let client = sandbox.network.rpc_client().unwrap();
+    let tx_xdr = sandbox
+        .new_assert_cmd("tx")
+        .arg("new")
+        .args(args)
+        .arg("--build-only")
+        .assert()
+        .success()
+        .stdout_as_str();
+    let signed = sandbox
+        .new_assert_cmd("tx")
+        .args(["sign", "--sign-with-key=test"])
+        .write_stdin(tx_xdr.as_bytes())
+        .assert()
+        .success()
+        .stdout_as_str();
+    let tx_env = xdr::TransactionEnvelope::from_xdr_base64(&signed, xdr::Limits::none()).unwrap();
+    let res = client.send_transaction_polling(&tx_env).await.unwrap();
+    let Some(xdr::TransactionResult {
+        result: xdr::TransactionResultResult::TxSuccess(results),
+        ..
+    }) = res.result
+    else {
+        panic!("Expected transaction to succeed");
+    };
+    let [xdr::OperationResult::OpInner(
+        xdr::OperationResultTr::ManageSellOffer(xdr::ManageSellOfferResult::Success(
+            xdr::ManageOfferSuccessResult {
+                offer: xdr::ManageOfferSuccessResultOffer::Created(offer),
+                ..
+            },
+        ))
+        | xdr::OperationResultTr::CreatePassiveSellOffer(xdr::ManageSellOfferResult::Success(
+            xdr::ManageOfferSuccessResult {
+                offer: xdr::ManageOfferSuccessResultOffer::Created(offer),
+                ..
+            },
+        )),
+    )] = results.as_slice()
+    else {
+        panic!("Expected an offer to be created");
+    };
+    offer.clone()
+}
+
+#[tokio::test]
+async fn manage_sell_offer() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let test = test_address(sandbox);
+    // An issuer can sell its own asset without a trustline
+    let asset = &format!("usdc:{test}");
+    let before = client.get_account(&test).await.unwrap();
+
+    let offer = create_offer(
+        sandbox,
+        &[
+            "manage-sell-offer",
+            "--selling",
+            asset,
+            "--buying",
+            "native",
+            "--amount",
+            "10_000_000",
+            "--price",
+            "5:4",
+        ],
+    )
+    .await;
+    assert_eq!(offer.price, xdr::Price { n: 5, d: 4 });
+    let after = client.get_account(&test).await.unwrap();
+    assert_eq!(before.num_sub_entries + 1, after.num_sub_entries);
+
+    sandbox
+        .new_assert_cmd("tx")
+        .args([
+            "new",
+            "manage-sell-offer",
+            "--selling",
+            asset,
+            "--buying",
+            "native",
+            "--amount",
+            "0",
+            "--price",
+            "5:4",
+            "--offer-id",
+            offer.offer_id.to_string().as_str(),
+        ])
+        .assert()
+        .success();
+    let after = client.get_account(&test).await.unwrap();
+    assert_eq!(before.num_sub_entries, after.num_sub_entries);
+}
+
+#[tokio::test]
+async fn create_passive_sell_offer() {
+    let sandbox = &TestEnv::new();
+    let client = sandbox.network.rpc_client().unwrap();
+    let test = test_address(sandbox);
+    let asset = &format!("usdc:{test}");
+
+    let offer = create_offer(
+        sandbox,
+        &[
+            "create-passive-sell-offer",
+            "--selling",
+            asset,
+            "--buying",
+            "native",
+            "--amount",
+            "10_000_000",
+            "--price",
+            "1.25",
+        ],
+    )
+    .await;
+    let res = client
+        .get_ledger_entries(&[xdr::LedgerKey::Offer(xdr::LedgerKeyOffer {
+            seller_id: test.parse().unwrap(),
+            offer_id: offer.offer_id,
+        })])
+        .await
+        .unwrap();
+    let entry = res.entries.as_ref().unwrap().first().unwrap();
+    let xdr::LedgerEntryData::Offer(offer_entry) =
+        xdr::LedgerEntryData::from_xdr_base64(&entry.xdr, xdr::Limits::none()).unwrap()
+    else {
+        panic!("Expected OfferEntry");
+    };
+    assert_eq!(offer_entry.amount, 10_000_000);
+    assert_eq!(offer_entry.price, xdr::Price { n: 5, d: 4 });
+}
+
 async fn issue_asset(sandbox: &TestEnv, test: &str, asset: &str, limit: u64, initial_balance: u64) {
     let client = sandbox.network.rpc_client().unwrap();
     let test_before = client.get_account(test).await.unwrap();
@@ -1,4 +1,13 @@
-use soroban_cli::{tx::builder, utils::contract_id_hash_from_asset};
+use soroban_cli::{
+    commands::{contract::deploy::asset, NetworkRunnable},
+    config::data,
+    tx::builder,
+    utils::contract_id_hash_from_asset,
+    xdr::{
+        ContractIdPreimage, CreateContractArgs, HostFunction, InvokeHostFunctionOp, Limits,
+        OperationBody, ReadXdr, TransactionEnvelope,
+    },
+};
 use soroban_test::{AssertExt, TestEnv, LOCAL_NETWORK_PASSPHRASE};
 
 #[tokio::test]
@@ -99,3 +108,73 @@ async fn burn() {
         .assert()
         .stdout("\"9223372036854775707\"\n");
 }
+
+#[tokio::test]
+async fn deploy_respects_no_cache() {
+    let sandbox = &TestEnv::new();
+    let address = sandbox
+        .new_assert_cmd("keys")
+        .arg("address")
+        .arg("test")
+        .assert()
+        .stdout_as_str();
+    let actions_dir = sandbox.dir().join("data").join("actions");
+    let cached = || std::fs::read_dir(&actions_dir).map_or(0, Iterator::count);
+    let deploy = |code: &str| {
+        let mut cmd = sandbox.new_assert_cmd("contract");
+        cmd.args(["asset", "deploy", "--source=test", "--asset"])
+            .arg(format!("{code}:{address}"));
+        cmd
+    };
+
+    deploy("NOCACHE").arg("--no-cache").assert().success();
+    assert_eq!(cached(), 0);
+
+    deploy("CACHE").assert().success();
+    assert_eq!(cached(), 1);
+}
+
+#[tokio::test]
+async fn deploy_without_global_args_respects_no_cache() {
+    let sandbox = &TestEnv::new();
+    let address = sandbox
+        .new_assert_cmd("keys")
+        .arg("address")
+        .arg("test")
+        .assert()
+        .stdout_as_str();
+    let asset = format!("LIBNOCACHE:{address}");
+    let mut cmd: asset::Cmd = sandbox.cmd_arr(&["--asset", &asset]);
+    cmd.no_cache = true;
+    cmd.run_against_rpc_server(None, Some(&sandbox.clone_config("test")))
+        .await
+        .unwrap();
+
+    let asset: builder::Asset = asset.parse().unwrap();
+    let cached = data::list_ulids().unwrap().iter().any(|id| {
+        let Ok((data::Action::Send { response }, _)) = data::read(id) else {
+            return false;
+        };
+        let Some(envelope) = response.envelope_xdr else {
+            return false;
+        };
+        let Ok(TransactionEnvelope::Tx(env)) =
+            TransactionEnvelope::from_xdr_base64(envelope, Limits::none())
+        else {
+            return false;
+        };
+        env.tx.operations.iter().any(|op| {
+            matches!(
+                &op.body,
+                OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                    host_function: HostFunction::CreateContract(CreateContractArgs {
+                        contract_id_preimage: ContractIdPreimage::Asset(a),
+                        ..
+                    }),
+                    ..
+                }) if *a == asset.0
+            )
+        })
+    });
+    assert!(!cached);
+}
@@ -35,7 +35,7 @@ impl From<&HdPath> for slip10::BIP32Path {
     }
 }
 
-fn hd_path_to_bytes(hd_path: &slip10::BIP32Path) -> Result<Vec<u8>, Error> {
+pub(crate) fn hd_path_to_bytes(hd_path: &slip10::BIP32Path) -> Result<Vec<u8>, Error> {
     let hd_path_indices = 0..hd_path.depth();
     let result = hd_path_indices
         .into_iter()
@@ -5,11 +5,12 @@ use ledger_transport_hid::{
     LedgerHIDError, TransportNativeHID,
 };
 
-use std::vec;
+use sha2::{Digest, Sha256};
+use std::{ops::Range, vec};
 use stellar_strkey::DecodeError;
 use stellar_xdr::curr::{
-    self as xdr, Hash, Limits, Transaction, TransactionSignaturePayload,
-    TransactionSignaturePayloadTaggedTransaction, WriteXdr,
+    self as xdr, Hash, HashIdPreimage, HashIdPreimageSorobanAuthorization, Limits, Transaction,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction, WriteXdr,
 };
 
 pub use crate::signer::Blob;
@@ -17,10 +18,9 @@ pub mod hd_path;
 mod signer;
 
 // this is from https://github.com/LedgerHQ/ledger-live/blob/36cfbf3fa3300fd99bcee2ab72e1fd8f280e6280/libs/ledgerjs/packages/hw-app-str/src/Str.ts#L181
-const APDU_MAX_SIZE: u8 = 150;
+pub const APDU_MAX_SIZE: u8 = 150;
 const HD_PATH_ELEMENTS_COUNT: u8 = 3;
 const BUFFER_SIZE: u8 = 1 + HD_PATH_ELEMENTS_COUNT * 4;
-const CHUNK_SIZE: u8 = APDU_MAX_SIZE - BUFFER_SIZE;
 
 // These constant values are from https://github.com/LedgerHQ/app-stellar/blob/develop/docs/COMMANDS.md
 const SIGN_TX_RESPONSE_SIZE: usize = 64;
@@ -47,6 +47,7 @@ const P1_SIGN_TX_HASH: u8 = 0x00;
 const P2_SIGN_TX_HASH: u8 = 0x00;
 
 const RETURN_CODE_OK: u16 = 36864; // APDUAnswer.retcode which means success from Ledger
+const RETURN_CODE_USER_REJECTED: u16 = 0x6985; // APDUAnswer.retcode when the user declines on the device
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -70,19 +71,25 @@ pub enum Error {
 
     #[error(transparent)]
     DecodeError(#[from] DecodeError),
+
+    #[error("APDU payload of {size} bytes exceeds the maximum of {max} bytes")]
+    PayloadTooLarge { size: usize, max: u8 },
+
+    #[error("Signing cancelled on device")]
+    UserRejected,
 }
 
 pub struct LedgerSigner<T: Exchange> {
     transport: T,
+    verbose_apdu: bool,
+    apdu_max_size: u8,
 }
 
 unsafe impl<T> Send for LedgerSigner<T> where T: Exchange {}
 unsafe impl<T> Sync for LedgerSigner<T> where T: Exchange {}
 
 pub fn native() -> Result<LedgerSigner<TransportNativeHID>, Error> {
-    Ok(LedgerSigner {
-        transport: get_transport()?,
-    })
+    Ok(LedgerSigner::new(get_transport()?))
 }
 
 impl<T> LedgerSigner<T>
@@ -90,12 +97,32 @@ where
     T: Exchange,
 {
     pub fn new(transport: T) -> Self {
-        Self { transport }
+        Self {
+            transport,
+            verbose_apdu: false,
+            apdu_max_size: APDU_MAX_SIZE,
+        }
     }
     pub fn native() -> Result<LedgerSigner<TransportNativeHID>, Error> {
-        Ok(LedgerSigner {
-            transport: get_transport()?,
-        })
+        Ok(LedgerSigner::new(get_transport()?))
+    }
+    /// Log raw APDU exchanges at `info` level instead of `trace`
+    #[must_use]
+    pub fn with_verbose_apdu(mut self, verbose_apdu: bool) -> Self {
+        self.verbose_apdu = verbose_apdu;
+        self
+    }
+    /// Maximum size of the data sent in a single APDU, defaults to [`APDU_MAX_SIZE`]. Newer
+    /// firmware accepts larger frames, which lets transactions be signed in fewer chunks.
+    #[must_use]
+    pub fn with_apdu_max_size(mut self, apdu_max_size: u8) -> Self {
+        self.apdu_max_size = apdu_max_size;
+        self
+    }
+    /// Size of the transaction chunks sent with `SIGN_TX`, leaving room for the hd path that is
+    /// sent along with the first one.
+    fn chunk_size(&self) -> usize {
+        usize::from(self.apdu_max_size.saturating_sub(BUFFER_SIZE)).max(1)
     }
     /// Get the device app's configuration
     /// # Errors
@@ -111,6 +138,35 @@ where
         self.send_command_to_ledger(command).await
     }
 
+    /// Get the public key from the device after the user has verified it on the device's
+    /// screen and approved sharing it
+    /// # Errors
+    /// Returns an error if there is an issue with connecting with the device, the user rejects
+    /// the request, or the public key cannot be decoded
+    pub async fn get_public_key_confirm(
+        &self,
+        index: u32,
+    ) -> Result<stellar_strkey::ed25519::PublicKey, Error> {
+        self.get_public_key_with_display_flag(index, true).await
+    }
+
+    /// Get the public keys for a range of account indexes from the device, without displaying
+    /// them, over the same transport
+    /// # Errors
+    /// Returns an error if there is an issue with connecting with the device or getting any of
+    /// the public keys from the device
+    pub async fn get_public_keys(
+        &self,
+        range: Range<u32>,
+    ) -> Result<Vec<(u32, stellar_strkey::ed25519::PublicKey)>, Error> {
+        let mut public_keys = Vec::with_capacity(range.len());
+        for index in range {
+            let public_key = self.get_public_key_with_display_flag(index, false).await?;
+            public_keys.push((index, public_key));
+        }
+        Ok(public_keys)
+    }
+
     /// Sign a Stellar transaction hash with the account on the Ledger device
     /// based on impl from [https://github.com/LedgerHQ/ledger-live/blob/develop/libs/ledgerjs/packages/hw-app-str/src/Str.ts#L166](https://github.com/LedgerHQ/ledger-live/blob/develop/libs/ledgerjs/packages/hw-app-str/src/Str.ts#L166)
     /// # Errors
@@ -123,6 +179,22 @@ where
         self.sign_blob(&hd_path.into(), transaction_hash).await
     }
 
+    /// Sign a Soroban authorization entry with the account on the Ledger device. The preimage is
+    /// hashed and the hash is signed, so the device must have hash signing enabled.
+    /// # Errors
+    /// Returns an error if there is an issue with connecting with the device or signing the given
+    /// auth entry on the device. Or, if the device has not enabled hash signing
+    pub async fn sign_auth_entry(
+        &self,
+        hd_path: impl Into<HdPath>,
+        preimage: HashIdPreimageSorobanAuthorization,
+    ) -> Result<Vec<u8>, Error> {
+        let payload = HashIdPreimage::SorobanAuthorization(preimage).to_xdr(Limits::none())?;
+        let hash: [u8; 32] = Sha256::digest(payload).into();
+        tracing::info!("Signing auth entry hash {} on Ledger", hex::encode(hash));
+        self.sign_blob(&hd_path.into(), &hash).await
+    }
+
     /// Sign a Stellar transaction with the account on the Ledger device
     /// # Errors
     /// Returns an error if there is an issue with connecting with the device or signing the given tx on the device
@@ -149,9 +221,10 @@ where
         data.append(&mut hd_path_to_bytes);
         data.append(&mut signature_payload_as_bytes);
 
-        let chunks = data.chunks(CHUNK_SIZE as usize);
+        let chunks = data.chunks(self.chunk_size());
         let chunks_count = chunks.len();
 
+        tracing::info!("Signing transaction on Ledger in {chunks_count} chunk(s)");
         let mut result = Vec::with_capacity(SIGN_TX_RESPONSE_SIZE);
         for (i, chunk) in chunks.enumerate() {
             let is_first_chunk = i == 0;
@@ -208,8 +281,7 @@ where
             data: hd_path_to_bytes,
         };
 
-        tracing::info!("APDU in: {}", hex::encode(command.serialize()));
-
+        tracing::info!("Requesting public key from Ledger");
         self.send_command_to_ledger(command)
             .await
             .and_then(|p| Ok(stellar_strkey::ed25519::PublicKey::from_payload(&p)?))
@@ -219,19 +291,26 @@ where
         &self,
         command: APDUCommand<Vec<u8>>,
     ) -> Result<Vec<u8>, Error> {
+        self.log_apdu(format_args!(
+            "APDU in: {}",
+            hex::encode(command.serialize())
+        ));
         match self.transport.exchange(&command).await {
             Ok(response) => {
-                tracing::info!(
+                self.log_apdu(format_args!(
                     "APDU out: {}\nAPDU ret code: {:x}",
                     hex::encode(response.apdu_data()),
                     response.retcode(),
-                );
+                ));
                 // Ok means we successfully connected with the Ledger but it doesn't mean our request succeeded. We still need to check the response.retcode
                 if response.retcode() == RETURN_CODE_OK {
                     return Ok(response.data().to_vec());
                 }
 
                 let retcode = response.retcode();
+                if retcode == RETURN_CODE_USER_REJECTED {
+                    return Err(Error::UserRejected);
+                }
                 let error_string = format!("Ledger APDU retcode: 0x{retcode:X}");
                 Err(Error::APDUExchangeError(error_string))
             }
@@ -240,6 +319,14 @@ where
             )),
         }
     }
+
+    fn log_apdu(&self, message: std::fmt::Arguments) {
+        if self.verbose_apdu {
+            tracing::info!("{message}");
+        } else {
+            tracing::trace!("{message}");
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -264,14 +351,7 @@ where
     /// # Errors
     /// Returns an error if there is an issue with connecting with the device or signing the given tx on the device. Or, if the device has not enabled hash signing
     async fn sign_blob(&self, index: &Self::Key, blob: &[u8]) -> Result<Vec<u8>, Error> {
-        let mut hd_path_to_bytes = index.to_vec()?;
-
-        let capacity = 1 + hd_path_to_bytes.len() + blob.len();
-        let mut data: Vec<u8> = Vec::with_capacity(capacity);
-
-        data.insert(0, HD_PATH_ELEMENTS_COUNT);
-        data.append(&mut hd_path_to_bytes);
-        data.extend_from_slice(blob);
+        let data = sign_hash_data(&index.into(), blob, self.apdu_max_size)?;
 
         let command = APDUCommand {
             cla: CLA,
@@ -281,10 +361,36 @@ where
             data,
         };
 
+        tracing::info!("Signing hash on Ledger");
         self.send_command_to_ledger(command).await
     }
 }
 
+/// Builds the data for a `SIGN_TX_HASH` command: the number of path elements, the path, then the
+/// hash. Unlike `SIGN_TX` the command cannot be chunked, so the whole payload has to fit in a
+/// single APDU.
+fn sign_hash_data(
+    hd_path: &slip10::BIP32Path,
+    blob: &[u8],
+    apdu_max_size: u8,
+) -> Result<Vec<u8>, Error> {
+    let mut hd_path_to_bytes = hd_path::hd_path_to_bytes(hd_path)?;
+
+    let capacity = 1 + hd_path_to_bytes.len() + blob.len();
+    if capacity > apdu_max_size as usize {
+        return Err(Error::PayloadTooLarge {
+            size: capacity,
+            max: apdu_max_size,
+        });
+    }
+    let mut data: Vec<u8> = Vec::with_capacity(capacity);
+
+    data.push(hd_path.depth());
+    data.append(&mut hd_path_to_bytes);
+    data.extend_from_slice(blob);
+    Ok(data)
+}
+
 fn get_transport() -> Result<TransportNativeHID, Error> {
     // instantiate the connection to Ledger, this will return an error if Ledger is not connected
     let hidapi = HidApi::new().map_err(Error::HidApiError)?;
@@ -294,8 +400,7 @@ fn get_transport() -> Result<TransportNativeHID, Error> {
 pub const TEST_NETWORK_PASSPHRASE: &[u8] = b"Test SDF Network ; September 2015";
 #[cfg(test)]
 pub fn test_network_hash() -> Hash {
-    use sha2::Digest;
-    Hash(sha2::Sha256::digest(TEST_NETWORK_PASSPHRASE).into())
+    Hash(Sha256::digest(TEST_NETWORK_PASSPHRASE).into())
 }
 
 #[cfg(test)]
@@ -368,6 +473,29 @@ mod test {
         mock_server.assert();
     }
 
+    fn payment_tx() -> Transaction {
+        let fake_source_acct = [0; 32];
+        let fake_dest_acct = [0; 32];
+        Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(fake_source_acct)),
+            fee: 100,
+            seq_num: SequenceNumber(1),
+            cond: Preconditions::None,
+            memo: Memo::Text("Stellar".as_bytes().try_into().unwrap()),
+            ext: TransactionExt::V0,
+            operations: [Operation {
+                source_account: Some(MuxedAccount::Ed25519(Uint256(fake_source_acct))),
+                body: OperationBody::Payment(PaymentOp {
+                    destination: MuxedAccount::Ed25519(Uint256(fake_dest_acct)),
+                    asset: xdr::Asset::Native,
+                    amount: 100,
+                }),
+            }]
+            .try_into()
+            .unwrap(),
+        }
+    }
+
     #[tokio::test]
     async fn test_sign_tx() {
         let server = MockServer::start();
@@ -395,29 +523,8 @@ mod test {
 
         let ledger = ledger(&server);
 
-        let fake_source_acct = [0; 32];
-        let fake_dest_acct = [0; 32];
-        let tx = Transaction {
-            source_account: MuxedAccount::Ed25519(Uint256(fake_source_acct)),
-            fee: 100,
-            seq_num: SequenceNumber(1),
-            cond: Preconditions::None,
-            memo: Memo::Text("Stellar".as_bytes().try_into().unwrap()),
-            ext: TransactionExt::V0,
-            operations: [Operation {
-                source_account: Some(MuxedAccount::Ed25519(Uint256(fake_source_acct))),
-                body: OperationBody::Payment(PaymentOp {
-                    destination: MuxedAccount::Ed25519(Uint256(fake_dest_acct)),
-                    asset: xdr::Asset::Native,
-                    amount: 100,
-                }),
-            }]
-            .try_into()
-            .unwrap(),
-        };
-
         let response = ledger
-            .sign_transaction(0, tx, test_network_hash())
+            .sign_transaction(0, payment_tx(), test_network_hash())
             .await
             .unwrap();
         assert_eq!(
@@ -429,6 +536,44 @@ mod test {
         mock_request_2.assert();
     }
 
+    #[tokio::test]
+    async fn test_sign_tx_with_apdu_max_size() {
+        let server = MockServer::start();
+        // The hd path and payment transaction are 217 bytes, which is 3 chunks of at most 100
+        let mock_server = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({"data": "9000"}));
+        });
+
+        let ledger = ledger(&server).with_apdu_max_size(crate::BUFFER_SIZE + 100);
+        ledger
+            .sign_transaction(0, payment_tx(), test_network_hash())
+            .await
+            .unwrap();
+
+        mock_server.assert_hits(3);
+    }
+
+    #[test]
+    fn test_sign_hash_data_with_deep_path() {
+        let path: slip10::BIP32Path = "m/44'/148'/0'/1'".parse().unwrap();
+        let hash = [0xabu8; 32];
+        let data = crate::sign_hash_data(&path, &hash, crate::APDU_MAX_SIZE).unwrap();
+        assert_eq!(data.len(), 1 + 4 * 4 + 32);
+        assert_eq!(data[0], 4);
+        assert_eq!(data[1..5], 0x8000_002cu32.to_be_bytes());
+        assert_eq!(data[13..17], 0x8000_0001u32.to_be_bytes());
+        assert_eq!(data[17..], hash);
+
+        let too_large = [0u8; 150];
+        assert!(matches!(
+            crate::sign_hash_data(&path, &too_large, crate::APDU_MAX_SIZE),
+            Err(Error::PayloadTooLarge { size: 167, .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_sign_tx_hash_when_hash_signing_is_not_enabled() {
         let server = MockServer::start();
@@ -457,6 +602,26 @@ mod test {
         mock_server.assert();
     }
 
+    #[tokio::test]
+    async fn test_sign_tx_hash_when_user_rejects() {
+        let server = MockServer::start();
+        let mock_server = server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({"data": "6985"}));
+        });
+
+        let ledger = ledger(&server);
+        let test_hash = [0u8; 32];
+
+        let err = ledger.sign_blob(&0.into(), &test_hash).await.unwrap_err();
+        assert!(matches!(err, Error::UserRejected), "{err:?}");
+        assert_eq!(err.to_string(), "Signing cancelled on device");
+
+        mock_server.assert();
+    }
+
     #[tokio::test]
     async fn test_sign_tx_hash_when_hash_signing_is_enabled() {
         let server = MockServer::start();
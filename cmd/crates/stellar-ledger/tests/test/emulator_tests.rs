@@ -13,8 +13,10 @@ use std::sync::Arc;
 use std::{collections::HashMap, time::Duration};
 
 use stellar_xdr::curr::{
-    self as xdr, Hash, Memo, MuxedAccount, Operation, OperationBody, PaymentOp, Preconditions,
-    SequenceNumber, Transaction, TransactionExt, Uint256,
+    self as xdr, Hash, HashIdPreimage, HashIdPreimageSorobanAuthorization, InvokeContractArgs,
+    Limits, Memo, MuxedAccount, Operation, OperationBody, PaymentOp, Preconditions, ScAddress,
+    SequenceNumber, SorobanAuthorizedFunction, SorobanAuthorizedInvocation, Transaction,
+    TransactionExt, Uint256, WriteXdr,
 };
 
 use testcontainers::{core::ContainerPort, runners::AsyncRunner, ContainerAsync, ImageExt};
@@ -68,6 +70,71 @@ async fn test_get_public_key(ledger_device_model: String) {
     }
 }
 
+#[test_case("nanos".to_string() ; "when the device is NanoS")]
+#[test_case("nanox".to_string() ; "when the device is NanoX")]
+#[test_case("nanosp".to_string() ; "when the device is NanoS Plus")]
+#[tokio::test]
+async fn test_get_public_keys(ledger_device_model: String) {
+    let container = get_container(ledger_device_model.clone()).await;
+    let host_port = container.get_host_port_ipv4(9998).await.unwrap();
+    let ui_host_port: u16 = container.get_host_port_ipv4(5000).await.unwrap();
+    wait_for_emulator_start_text(ui_host_port).await;
+
+    let ledger = ledger(host_port).await;
+
+    let public_keys = ledger.get_public_keys(0..3).await.unwrap();
+    assert_eq!(
+        public_keys
+            .iter()
+            .map(|(index, _)| *index)
+            .collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    for (index, public_key) in &public_keys {
+        assert_eq!(
+            *public_key,
+            ledger.get_public_key(&index.into()).await.unwrap()
+        );
+    }
+    // This is determined by the seed phrase used to start up the emulator
+    assert_eq!(
+        public_keys[0].1.to_string(),
+        "GDUTHCF37UX32EMANXIL2WOOVEDZ47GHBTT3DYKU6EKM37SOIZXM2FN7"
+    );
+}
+
+#[test_case("nanos".to_string() ; "when the device is NanoS")]
+#[test_case("nanox".to_string() ; "when the device is NanoX")]
+#[test_case("nanosp".to_string() ; "when the device is NanoS Plus")]
+#[tokio::test]
+async fn test_get_public_key_confirm(ledger_device_model: String) {
+    let container = get_container(ledger_device_model.clone()).await;
+    let host_port = container.get_host_port_ipv4(9998).await.unwrap();
+    let ui_host_port: u16 = container.get_host_port_ipv4(5000).await.unwrap();
+    wait_for_emulator_start_text(ui_host_port).await;
+
+    let ledger = Arc::new(ledger(host_port).await);
+
+    let get = tokio::task::spawn({
+        let ledger = Arc::clone(&ledger);
+        async move { ledger.get_public_key_confirm(0).await }
+    });
+    let approve = tokio::task::spawn(approve_public_key_display(ui_host_port));
+
+    let result = get.await.unwrap();
+    let _ = approve.await.unwrap();
+
+    match result {
+        Ok(public_key) => {
+            let expected_public_key = "GDUTHCF37UX32EMANXIL2WOOVEDZ47GHBTT3DYKU6EKM37SOIZXM2FN7";
+            assert_eq!(public_key.to_string(), expected_public_key);
+        }
+        Err(e) => {
+            panic!("Unexpected result: {e}");
+        }
+    }
+}
+
 #[test_case("nanos".to_string() ; "when the device is NanoS")]
 #[test_case("nanox".to_string() ; "when the device is NanoX")]
 #[test_case("nanosp".to_string() ; "when the device is NanoS Plus")]
@@ -245,6 +312,58 @@ async fn test_sign_tx_hash_when_hash_signing_is_enabled(ledger_device_model: Str
     }
 }
 
+#[test_case("nanos".to_string() ; "when the device is NanoS")]
+#[test_case("nanox".to_string() ; "when the device is NanoX")]
+#[test_case("nanosp".to_string() ; "when the device is NanoS Plus")]
+#[tokio::test]
+async fn test_sign_auth_entry(ledger_device_model: String) {
+    use sha2::Digest;
+
+    let container = get_container(ledger_device_model.clone()).await;
+    let host_port = container.get_host_port_ipv4(9998).await.unwrap();
+    let ui_host_port: u16 = container.get_host_port_ipv4(5000).await.unwrap();
+
+    wait_for_emulator_start_text(ui_host_port).await;
+    enable_hash_signing(ui_host_port).await;
+
+    let ledger = Arc::new(ledger(host_port).await);
+
+    let path = 0;
+    let preimage = HashIdPreimageSorobanAuthorization {
+        network_id: test_network_hash(),
+        nonce: 1,
+        signature_expiration_ledger: 100,
+        invocation: SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+                contract_address: ScAddress::Contract(Hash([1; 32])),
+                function_name: "hello".try_into().unwrap(),
+                args: vec![].try_into().unwrap(),
+            }),
+            sub_invocations: vec![].try_into().unwrap(),
+        },
+    };
+    let payload = HashIdPreimage::SorobanAuthorization(preimage.clone())
+        .to_xdr(Limits::none())
+        .unwrap();
+    let hash: [u8; 32] = sha2::Sha256::digest(payload).into();
+
+    let sign = tokio::task::spawn({
+        let ledger = Arc::clone(&ledger);
+        async move { ledger.sign_auth_entry(path, preimage).await }
+    });
+    let approve = tokio::task::spawn(approve_tx_hash_signature(ui_host_port, ledger_device_model));
+
+    let response = sign.await.unwrap();
+    let _ = approve.await.unwrap();
+
+    let signature: [u8; 64] = response.unwrap().try_into().unwrap();
+    let public_key = ledger.get_public_key(&path.into()).await.unwrap();
+    ed25519_dalek::VerifyingKey::from_bytes(&public_key.0)
+        .unwrap()
+        .verify_strict(&hash, &ed25519_dalek::Signature::from_bytes(&signature))
+        .unwrap();
+}
+
 async fn click(ui_host_port: u16, url: &str) {
     let previous_events = get_emulator_events(ui_host_port).await;
 
@@ -415,3 +534,16 @@ async fn approve_tx_signature(ui_host_port: u16, device_model: String) {
     }
     click(ui_host_port, "button/both").await;
 }
+
+async fn approve_public_key_display(ui_host_port: u16) {
+    // The address is split over a varying number of screens depending on the device, so
+    // scroll until the approval screen is shown.
+    while !get_emulator_events(ui_host_port)
+        .await
+        .iter()
+        .any(|event| event.text == "Approve")
+    {
+        click(ui_host_port, "button/right").await;
+    }
+    click(ui_host_port, "button/both").await;
+}
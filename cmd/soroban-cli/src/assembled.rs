@@ -1,9 +1,9 @@
 use sha2::{Digest, Sha256};
 use stellar_xdr::curr::{
-    self as xdr, ExtensionPoint, Hash, InvokeHostFunctionOp, LedgerFootprint, Limits, Memo,
-    Operation, OperationBody, Preconditions, ReadXdr, RestoreFootprintOp,
-    SorobanAuthorizationEntry, SorobanAuthorizedFunction, SorobanResources, SorobanTransactionData,
-    Transaction, TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    self as xdr, ExtensionPoint, InvokeHostFunctionOp, LedgerFootprint, Limits, Memo, Operation,
+    OperationBody, Preconditions, ReadXdr, RestoreFootprintOp, SorobanAuthorizationEntry,
+    SorobanAuthorizedFunction, SorobanResources, SorobanTransactionData, Transaction,
+    TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
     TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, VecM, WriteXdr,
 };
 
@@ -11,6 +11,8 @@ use soroban_rpc::{Error, RestorePreamble, SimulateTransactionResponse};
 
 use soroban_rpc::{LogEvents, LogResources};
 
+use crate::utils::NetworkId;
+
 pub(crate) const DEFAULT_TRANSACTION_FEES: u32 = 100;
 
 pub async fn simulate_and_assemble_transaction(
@@ -67,7 +69,7 @@ impl Assembled {
     /// Returns an error if generating the hash fails.
     pub fn hash(&self, network_passphrase: &str) -> Result<[u8; 32], xdr::Error> {
         let signature_payload = TransactionSignaturePayload {
-            network_id: Hash(Sha256::digest(network_passphrase).into()),
+            network_id: NetworkId::new(network_passphrase).into(),
             tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(self.txn.clone()),
         };
         Ok(Sha256::digest(signature_payload.to_xdr(Limits::none())?).into())
@@ -3,6 +3,7 @@ use dotenvy::dotenv;
 use tracing_subscriber::{fmt, EnvFilter};
 
 use crate::config::Config;
+use crate::exit_code::ExitCode;
 use crate::print::Print;
 use crate::upgrade_check::upgrade_check;
 use crate::{commands, Root};
@@ -43,7 +44,7 @@ pub async fn main() {
         }
         e => {
             eprintln!("{e}");
-            std::process::exit(1);
+            std::process::exit(e.exit_code());
         }
     });
 
@@ -79,14 +80,16 @@ pub async fn main() {
     // Spawn a thread to check if a new version exists.
     // It depends on logger, so we need to place it after
     // the code block that initializes the logger.
-    tokio::spawn(async move {
-        upgrade_check(root.global_args.quiet).await;
-    });
+    if !root.global_args.offline {
+        tokio::spawn(async move {
+            upgrade_check(root.global_args.quiet).await;
+        });
+    }
 
     let printer = Print::new(root.global_args.quiet);
     if let Err(e) = root.run().await {
         printer.errorln(format!("error: {e}"));
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
     }
 }
 
@@ -1,6 +1,10 @@
 use clap::command;
 
-use crate::config::{data, locator};
+use crate::{
+    commands::global,
+    config::{data, locator},
+    print::Print,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -21,9 +25,9 @@ pub struct Cmd {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let res = if self.long { self.ls_l() } else { self.ls() }?.join("\n");
-        println!("{res}");
+        Print::from(global_args).outln(res);
         Ok(())
     }
 
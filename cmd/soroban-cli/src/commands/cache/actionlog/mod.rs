@@ -1,5 +1,7 @@
 use clap::Parser;
 
+use crate::commands::global;
+
 pub mod ls;
 pub mod read;
 
@@ -20,10 +22,10 @@ pub enum Error {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match self {
-            Cmd::Ls(cmd) => cmd.run()?,
-            Cmd::Read(cmd) => cmd.run()?,
+            Cmd::Ls(cmd) => cmd.run(global_args)?,
+            Cmd::Read(cmd) => cmd.run(global_args)?,
         };
         Ok(())
     }
@@ -1,6 +1,10 @@
 use std::{fs, io, path::PathBuf};
 
-use crate::config::{data, locator};
+use crate::{
+    commands::global,
+    config::{data, locator},
+    print::Print,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -23,12 +27,11 @@ pub struct Cmd {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let file = self.file()?;
         tracing::debug!("reading file {}", file.display());
         let mut file = fs::File::open(file).map_err(|_| Error::NotFound(self.id.clone()))?;
-        let mut stdout = io::stdout();
-        let _ = io::copy(&mut file, &mut stdout);
+        let _ = io::copy(&mut file, &mut Print::from(global_args).stdout());
         Ok(())
     }
 
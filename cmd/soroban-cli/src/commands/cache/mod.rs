@@ -1,5 +1,7 @@
 use clap::Parser;
 
+use super::global;
+
 pub mod actionlog;
 pub mod clean;
 pub mod path;
@@ -27,11 +29,11 @@ pub enum Error {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match self {
             Cmd::Clean(cmd) => cmd.run()?,
-            Cmd::Path(cmd) => cmd.run()?,
-            Cmd::Actionlog(cmd) => cmd.run()?,
+            Cmd::Path(cmd) => cmd.run(global_args)?,
+            Cmd::Actionlog(cmd) => cmd.run(global_args)?,
         };
         Ok(())
     }
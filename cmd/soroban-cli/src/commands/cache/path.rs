@@ -1,4 +1,8 @@
-use crate::config::{data, locator};
+use crate::{
+    commands::global,
+    config::{data, locator},
+    print::Print,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -13,8 +17,9 @@ pub enum Error {
 pub struct Cmd {}
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
-        println!("{}", data::data_local_dir()?.to_string_lossy());
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let path = data::data_local_dir()?;
+        Print::from(global_args).outln(path.to_string_lossy());
         Ok(())
     }
 }
@@ -0,0 +1,158 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    commands::global,
+    config::locator::{self, KeyType},
+    print::Print,
+    utils::find_config_dir,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Locator(#[from] locator::Error),
+    #[error("No soroban config directory found at {path}, nothing to migrate")]
+    NothingToMigrate { path: PathBuf },
+    #[error("Failed to read {path}: {error}")]
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to write {path}: {error}")]
+    Write {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to remove {path}: {error}")]
+    Remove {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Not removing {path}, it has entries that differ from the stellar config directory: {names}")]
+    Conflicts { path: PathBuf, names: String },
+}
+
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    /// Remove the soroban config directory once everything has been migrated. Nothing is removed if an entry already exists in the stellar config directory with different contents
+    #[arg(long)]
+    pub remove_old: bool,
+    #[command(flatten)]
+    pub config_locator: locator::Args,
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let print = Print::from(global_args);
+        let (old, new) = self.dirs()?;
+        if !old.exists() {
+            return Err(Error::NothingToMigrate { path: old });
+        }
+
+        // Everything is copied, not only identities and networks, so that nothing is lost when
+        // the old directory is removed, e.g. contract aliases.
+        let mut conflicts = vec![];
+        for from in files(&old)? {
+            let relative = from.strip_prefix(&old).unwrap_or(&from);
+            let (kind, name) = entry(relative);
+            let to = new.join(relative);
+            let contents = fs::read(&from).map_err(|error| Error::Read {
+                path: from.clone(),
+                error,
+            })?;
+            if to.exists() {
+                let existing = fs::read(&to).map_err(|error| Error::Read {
+                    path: to.clone(),
+                    error,
+                })?;
+                if existing != contents {
+                    print.warnln(format!(
+                        "Conflicting {kind} {name:?}: keeping {}",
+                        to.display()
+                    ));
+                    conflicts.push(format!("{kind} {name}"));
+                }
+                continue;
+            }
+            let to = locator::ensure_directory(to)?;
+            fs::write(&to, contents).map_err(|error| Error::Write {
+                path: to.clone(),
+                error,
+            })?;
+            print.checkln(format!("Migrated {kind} {name:?}"));
+        }
+
+        if !self.remove_old {
+            return Ok(());
+        }
+        if !conflicts.is_empty() {
+            return Err(Error::Conflicts {
+                path: old,
+                names: conflicts.join(", "),
+            });
+        }
+        fs::remove_dir_all(&old).map_err(|error| Error::Remove {
+            path: old.clone(),
+            error,
+        })?;
+        print.checkln(format!("Removed {}", old.display()));
+        Ok(())
+    }
+
+    /// The soroban and stellar config directories, either global or in the closest directory
+    /// containing a config directory, starting from the current one.
+    fn dirs(&self) -> Result<(PathBuf, PathBuf), Error> {
+        if self.config_locator.global {
+            let global = locator::global_config_path()?;
+            let base = global.parent().unwrap_or(&global);
+            Ok((base.join("soroban"), base.join("stellar")))
+        } else {
+            let pwd = self.config_locator.current_dir()?;
+            let base = find_config_dir(pwd.clone())
+                .ok()
+                .and_then(|dir| dir.parent().map(Path::to_path_buf))
+                .unwrap_or(pwd);
+            Ok((base.join(".soroban"), base.join(".stellar")))
+        }
+    }
+}
+
+/// The files in `dir` and its subdirectories. Symlinked directories are skipped, so that nothing
+/// outside of `dir` is copied and a link back to it doesn't recurse forever.
+fn files(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let read_error = |error| Error::Read {
+        path: dir.to_path_buf(),
+        error,
+    };
+    let mut found = vec![];
+    for entry in fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        let metadata = fs::symlink_metadata(&path).map_err(|error| Error::Read {
+            path: path.clone(),
+            error,
+        })?;
+        if metadata.is_dir() {
+            found.extend(files(&path)?);
+        } else if !path.is_dir() {
+            found.push(path);
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// The kind and name of a file of a config directory, given relative to it, e.g. `identity` and
+/// `alice` for `identity/alice.toml`.
+fn entry(relative: &Path) -> (String, String) {
+    let kind = match relative.parent().and_then(Path::to_str) {
+        Some("identity") => KeyType::Identity,
+        Some("network") => KeyType::Network,
+        _ => return ("file".to_string(), relative.display().to_string()),
+    };
+    let name = relative.file_stem().unwrap_or_default();
+    (kind.to_string(), name.to_string_lossy().into_owned())
+}
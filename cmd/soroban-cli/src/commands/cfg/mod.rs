@@ -0,0 +1,33 @@
+use clap::Parser;
+
+use super::global;
+
+pub mod migrate;
+pub mod show;
+
+#[derive(Debug, Parser)]
+pub enum Cmd {
+    /// Merge the identities and networks of a soroban config directory into the stellar one,
+    /// reporting any conflicts
+    Migrate(migrate::Cmd),
+    /// Show the identities, networks and aliases in the config directory, with secrets redacted
+    Show(show::Cmd),
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Migrate(#[from] migrate::Error),
+    #[error(transparent)]
+    Show(#[from] show::Error),
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        match self {
+            Cmd::Migrate(cmd) => cmd.run(global_args)?,
+            Cmd::Show(cmd) => cmd.run(global_args)?,
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{
+    commands::global,
+    config::{
+        alias,
+        locator::{self, KeyType, Location},
+        network::Network,
+        secret::Secret,
+    },
+    print::Print,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Locator(#[from] locator::Error),
+    #[error("Failed to read {path}: {error}")]
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Failed to parse {path}: {error}")]
+    Parse {
+        path: PathBuf,
+        error: serde_json::Error,
+    },
+}
+
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    #[command(flatten)]
+    pub config_locator: locator::Args,
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        Print::from(global_args).outln(self.show()?.join("\n"));
+        Ok(())
+    }
+
+    /// Lines describing the contents of the config directory. Only the kind of each identity is
+    /// shown, never the secret itself.
+    pub fn show(&self) -> Result<Vec<String>, Error> {
+        let dir = self.config_locator.config_dir()?;
+        let location = if self.config_locator.global {
+            Location::Global(dir.clone())
+        } else {
+            Location::Local(dir.clone())
+        };
+        let mut lines = vec![format!("Config directory: {}", dir.display())];
+
+        lines.push("Identities:".to_string());
+        for (name, path) in KeyType::Identity.list(&location)? {
+            let kind = match KeyType::read_from_path::<Secret>(path.as_ref()) {
+                Ok(Secret::SecretKey { .. }) => "secret key",
+                Ok(Secret::SeedPhrase { .. }) => "seed phrase",
+                Ok(Secret::Encrypted { .. }) => "encrypted",
+                Err(_) => "unreadable",
+            };
+            lines.push(format!("  {name}: {kind} (redacted)"));
+        }
+
+        lines.push("Networks:".to_string());
+        for (name, path) in KeyType::Network.list(&location)? {
+            match KeyType::read_from_path::<Network>(path.as_ref()) {
+                Ok(network) => lines.push(format!(
+                    "  {name}: {} ({})",
+                    network.rpc_url, network.network_passphrase
+                )),
+                Err(_) => lines.push(format!("  {name}: unreadable")),
+            }
+        }
+
+        lines.push("Contract aliases:".to_string());
+        for (name, path) in json_files(&dir.join("contract-ids"))? {
+            let data: alias::Data = read_json(&path)?;
+            for (passphrase, id) in data.ids.iter().sorted() {
+                lines.push(format!("  {name}: {id} ({passphrase})"));
+            }
+        }
+
+        lines.push("Asset aliases:".to_string());
+        for (name, path) in json_files(&dir.join("asset-aliases"))? {
+            let data: alias::AssetData = read_json(&path)?;
+            lines.push(format!("  {name}: {}", data.asset));
+        }
+
+        Ok(lines)
+    }
+}
+
+fn json_files(dir: &Path) -> Result<Vec<(String, PathBuf)>, Error> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let read_error = |error| Error::Read {
+        path: dir.to_path_buf(),
+        error,
+    };
+    Ok(fs::read_dir(dir)
+        .map_err(read_error)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(OsStr::to_str) == Some("json"))
+        .filter_map(|path| Some((path.file_stem()?.to_string_lossy().to_string(), path)))
+        .sorted()
+        .collect())
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let content = fs::read_to_string(path).map_err(|error| Error::Read {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    serde_json::from_str(&content).map_err(|error| Error::Parse {
+        path: path.to_path_buf(),
+        error,
+    })
+}
@@ -1,8 +1,10 @@
 use clap::{arg, CommandFactory, Parser};
 use clap_complete::{generate, Shell};
-use std::io;
 
-use crate::commands::Root;
+use crate::{
+    commands::{global, Root},
+    print::Print,
+};
 
 pub const LONG_ABOUT: &str = "\
 Print shell completion code for the specified shell
@@ -23,8 +25,13 @@ pub struct Cmd {
 }
 
 impl Cmd {
-    pub fn run(&self) {
+    pub fn run(&self, global_args: &global::Args) {
         let cmd = &mut Root::command();
-        generate(self.shell, cmd, "stellar", &mut io::stdout());
+        generate(
+            self.shell,
+            cmd,
+            "stellar",
+            &mut Print::from(global_args).stdout(),
+        );
     }
 }
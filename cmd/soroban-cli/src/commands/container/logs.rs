@@ -12,6 +12,13 @@ pub enum Error {
     #[error(transparent)]
     ConnectionError(#[from] ConnectionError),
 
+    #[error("⛔ Container {container_name} not found")]
+    ContainerNotFound {
+        container_name: String,
+        #[source]
+        source: bollard::errors::Error,
+    },
+
     #[error("⛔ ️Failed to tail container: {0}")]
     TailContainerError(#[from] bollard::errors::Error),
 }
@@ -27,8 +34,9 @@ pub struct Cmd {
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let print = print::Print::new(global_args.quiet);
-        let container_name = Name(self.name.clone()).get_internal_container_name();
+        let print = print::Print::from(global_args);
+        let name = Name(self.name.clone());
+        let container_name = name.get_internal_container_name();
         let docker = self.container_args.connect_to_docker(&print).await?;
         let logs_stream = &mut docker.logs(
             &container_name,
@@ -41,9 +49,45 @@ impl Cmd {
             }),
         );
 
-        while let Some(log) = logs_stream.try_next().await? {
-            print!("{log}");
+        while let Some(log) = logs_stream
+            .try_next()
+            .await
+            .map_err(|e| logs_error(&name, e))?
+        {
+            print.out(log);
         }
         Ok(())
     }
 }
+
+fn logs_error(name: &Name, e: bollard::errors::Error) -> Error {
+    if e.to_string().contains("No such container") {
+        Error::ContainerNotFound {
+            container_name: name.get_external_container_name(),
+            source: e,
+        }
+    } else {
+        Error::TailContainerError(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_container_is_not_found() {
+        let err = logs_error(
+            &Name("local".to_string()),
+            bollard::errors::Error::DockerResponseServerError {
+                status_code: 404,
+                message: "No such container: stellar-local".to_string(),
+            },
+        );
+        assert!(matches!(
+            &err,
+            Error::ContainerNotFound { container_name, .. } if container_name == "local"
+        ));
+        assert_eq!(err.to_string(), "⛔ Container local not found");
+    }
+}
@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, future::Future};
 
 use bollard::{
     container::{Config, CreateContainerOptions, StartContainerOptions},
     image::CreateImageOptions,
-    service::{HostConfig, PortBinding},
+    service::{CreateImageInfo, HostConfig, PortBinding},
 };
-use futures_util::TryStreamExt;
+use futures_util::{Stream, TryStreamExt};
 
 use crate::{
     commands::{
@@ -27,6 +27,9 @@ pub enum Error {
 
     #[error("⛔ ️Failed to create container: {0}")]
     CreateContainerFailed(#[from] bollard::errors::Error),
+
+    #[error("⛔ ️Cancelled pulling image {0}")]
+    PullCancelled(String),
 }
 
 #[derive(Debug, clap::Parser, Clone)]
@@ -86,7 +89,7 @@ impl Runner {
             .await?;
 
         let image = self.get_image_name();
-        let mut stream = docker.create_image(
+        let stream = docker.create_image(
             Some(CreateImageOptions {
                 from_image: image.clone(),
                 ..Default::default()
@@ -94,26 +97,12 @@ impl Runner {
             None,
             None,
         );
-
-        while let Some(result) = stream.try_next().await.transpose() {
-            if let Ok(item) = result {
-                if let Some(status) = item.status {
-                    if status.contains("Pulling from")
-                        || status.contains("Digest")
-                        || status.contains("Status")
-                    {
-                        self.print.infoln(status);
-                    }
-                }
-            } else {
-                self.print
-                    .warnln(format!("Failed to fetch image: {image}."));
-                self.print.warnln(
-                    "Attempting to start local quickstart image. The image may be out-of-date.",
-                );
-                break;
+        let ctrl_c = async {
+            if tokio::signal::ctrl_c().await.is_err() {
+                std::future::pending::<()>().await;
             }
-        }
+        };
+        self.pull_image(&image, stream, ctrl_c).await?;
 
         let config = Config {
             image: Some(image),
@@ -149,6 +138,48 @@ impl Runner {
         Ok(())
     }
 
+    /// Print the progress of pulling `image` until the pull completes, fails or `cancel`
+    /// resolves. On cancellation the stream is dropped, which aborts the pull request.
+    async fn pull_image<S>(
+        &self,
+        image: &str,
+        mut stream: S,
+        cancel: impl Future<Output = ()>,
+    ) -> Result<(), Error>
+    where
+        S: Stream<Item = Result<CreateImageInfo, bollard::errors::Error>> + Unpin,
+    {
+        tokio::pin!(cancel);
+        loop {
+            let result = tokio::select! {
+                () = &mut cancel => return Err(Error::PullCancelled(image.to_string())),
+                result = stream.try_next() => result,
+            };
+            match result {
+                Ok(Some(item)) => {
+                    if let Some(status) = item.status {
+                        if status.contains("Pulling from")
+                            || status.contains("Digest")
+                            || status.contains("Status")
+                        {
+                            self.print.infoln(status);
+                        }
+                    }
+                }
+                Ok(None) => break,
+                Err(_) => {
+                    self.print
+                        .warnln(format!("Failed to fetch image: {image}."));
+                    self.print.warnln(
+                        "Attempting to start local quickstart image. The image may be out-of-date.",
+                    );
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn get_image_name(&self) -> String {
         // this can be overriden with the `-t` flag
         let mut image_tag = match &self.args.network {
@@ -243,3 +274,57 @@ impl Runner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn runner(args: &[&str]) -> Runner {
+        Runner {
+            args: Cmd::parse_from(std::iter::once("start").chain(args.iter().copied())),
+            print: print::Print::new(true),
+        }
+    }
+
+    #[test]
+    fn default_container_name_includes_network() {
+        for network in ["local", "testnet", "futurenet", "pubnet"] {
+            let name = runner(&[network]).container_name();
+            assert_eq!(name.get_external_container_name(), network);
+            assert_eq!(
+                name.get_internal_container_name(),
+                format!("stellar-{network}")
+            );
+        }
+    }
+
+    #[test]
+    fn container_name_can_be_overridden() {
+        let name = runner(&["testnet", "--name", "second"]).container_name();
+        assert_eq!(name.get_external_container_name(), "second");
+        assert_eq!(name.get_internal_container_name(), "stellar-second");
+    }
+
+    #[tokio::test]
+    async fn pull_image_is_cancelled() {
+        let stream = futures_util::stream::pending();
+        let result = runner(&["local"])
+            .pull_image("image", stream, std::future::ready(()))
+            .await;
+        assert!(matches!(result, Err(Error::PullCancelled(image)) if image == "image"));
+    }
+
+    #[tokio::test]
+    async fn pull_image_runs_to_completion() {
+        let stream = futures_util::stream::iter([Ok(CreateImageInfo {
+            status: Some("Pulling from stellar/quickstart".to_string()),
+            ..Default::default()
+        })]);
+        runner(&["local"])
+            .pull_image("image", stream, std::future::pending())
+            .await
+            .unwrap();
+    }
+}
@@ -4,8 +4,9 @@ use std::{fs, process};
 
 use clap::{command, Parser};
 
-use crate::commands::config::network;
+use crate::commands::{config::network, global};
 use crate::config::{alias, locator};
+use crate::print::Print;
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -39,7 +40,8 @@ struct AliasEntry {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let print = Print::from(global_args);
         let config_dir = self.config_locator.config_dir()?;
         let pattern = config_dir
             .join("contract-ids")
@@ -80,16 +82,18 @@ impl Cmd {
 
         for network_passphrase in map.keys() {
             if let Some(list) = map.clone().get_mut(network_passphrase) {
-                println!("ℹ️ Aliases available for network '{network_passphrase}'");
+                print.outln(format!(
+                    "ℹ️ Aliases available for network '{network_passphrase}'"
+                ));
 
                 list.sort_by(|a, b| a.alias.cmp(&b.alias));
 
                 for entry in list {
                     found = true;
-                    println!("{}: {}", entry.alias, entry.contract);
+                    print.outln(format!("{}: {}", entry.alias, entry.contract));
                 }
 
-                println!();
+                print.outln("");
             }
         }
 
@@ -36,7 +36,7 @@ pub enum Error {
 
 impl Cmd {
     pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let print = Print::new(global_args.quiet);
+        let print = Print::from(global_args);
         let alias = &self.alias;
         let network = self.network.get(&self.config_locator)?;
         let network_passphrase = &network.network_passphrase;
@@ -49,7 +49,7 @@ impl Cmd {
                 "Contract alias '{alias}' references {contract} on network '{network_passphrase}'"
             ));
 
-            println!("{contract}");
+            print.outln(contract);
 
             Ok(())
         } else {
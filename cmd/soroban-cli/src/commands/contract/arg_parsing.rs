@@ -49,6 +49,51 @@ pub enum Error {
     ScAddress(#[from] sc_address::Error),
     #[error(transparent)]
     Config(#[from] config::Error),
+    #[error("environment variable {0} is referenced in the function arguments but is not set")]
+    UnsetEnvVar(String),
+    #[error("unterminated `${{` in function argument {0:?}")]
+    UnterminatedEnvVar(String),
+}
+
+/// Substitute `$VAR` and `${VAR}` in each argument with the value of the environment variable.
+pub fn expand_env(slop: &[OsString]) -> Result<Vec<OsString>, Error> {
+    slop.iter()
+        .map(|arg| {
+            expand_vars(&arg.to_string_lossy(), |name| std::env::var(name).ok()).map(Into::into)
+        })
+        .collect()
+}
+
+fn expand_vars(arg: &str, lookup: impl Fn(&str) -> Option<String>) -> Result<String, Error> {
+    let is_name_start = |c: char| c.is_ascii_alphabetic() || c == '_';
+    let mut expanded = String::with_capacity(arg.len());
+    let mut rest = arg;
+    while let Some(i) = rest.find('$') {
+        expanded.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        let name = if let Some(braced) = rest.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| Error::UnterminatedEnvVar(arg.to_string()))?;
+            rest = &braced[end + 1..];
+            &braced[..end]
+        } else if rest.starts_with(is_name_start) {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let name = &rest[..end];
+            rest = &rest[end..];
+            name
+        } else {
+            // Not a variable reference, e.g. a trailing `$` or `$5`
+            expanded.push('$');
+            continue;
+        };
+        let value = lookup(name).ok_or_else(|| Error::UnsetEnvVar(name.to_string()))?;
+        expanded.push_str(&value);
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
 }
 
 pub fn build_host_function_parameters(
@@ -235,20 +280,33 @@ Note: The only types which aren't JSON are Bytes and BytesN, which are raw bytes
     )
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum Output {
+    /// JSON encoding of the return value, with strings quoted
+    #[default]
+    Json,
+    /// Like `json`, but a return value that is a string is written without quotes
+    Text,
+}
+
 pub fn output_to_string(
     spec: &Spec,
     res: &ScVal,
     function: &str,
+    format: Output,
 ) -> Result<TxnResult<String>, Error> {
     let mut res_str = String::new();
     if let Some(output) = spec.find_function(function)?.outputs.first() {
-        res_str = spec
+        let value = spec
             .xdr_to_json(res, output)
             .map_err(|e| Error::CannotPrintResult {
                 result: res.clone(),
                 error: e,
-            })?
-            .to_string();
+            })?;
+        res_str = match (format, value) {
+            (Output::Text, serde_json::Value::String(s)) => s,
+            (_, value) => value.to_string(),
+        };
     }
     Ok(TxnResult::Res(res_str))
 }
@@ -276,3 +334,96 @@ fn resolve_signer(addr_or_alias: &str, config: &config::Args) -> Option<SigningK
     };
     cmd.private_key().ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xdr::{ScSpecTypeVec, ScString, ScSymbol, VecM};
+
+    fn spec(output: ScSpecTypeDef) -> Spec {
+        Spec::new(vec![ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+            doc: "".try_into().unwrap(),
+            name: ScSymbol("f".try_into().unwrap()),
+            inputs: VecM::default(),
+            outputs: vec![output].try_into().unwrap(),
+        })])
+    }
+
+    fn output(output: ScSpecTypeDef, res: &ScVal, format: Output) -> String {
+        output_to_string(&spec(output), res, "f", format)
+            .unwrap()
+            .into_result()
+            .unwrap()
+    }
+
+    #[test]
+    fn string_output() {
+        let res = ScVal::String(ScString("Hello \"world\"".try_into().unwrap()));
+        assert_eq!(
+            output(ScSpecTypeDef::String, &res, Output::Json),
+            r#""Hello \"world\"""#
+        );
+        assert_eq!(
+            output(ScSpecTypeDef::String, &res, Output::Text),
+            r#"Hello "world""#
+        );
+    }
+
+    #[test]
+    fn vec_output() {
+        let type_ = ScSpecTypeDef::Vec(Box::new(ScSpecTypeVec {
+            element_type: Box::new(ScSpecTypeDef::Symbol),
+        }));
+        let res = ScVal::Vec(Some(
+            vec![
+                ScVal::Symbol(ScSymbol("Hello".try_into().unwrap())),
+                ScVal::Symbol(ScSymbol("world".try_into().unwrap())),
+            ]
+            .try_into()
+            .unwrap(),
+        ));
+        for format in [Output::Json, Output::Text] {
+            assert_eq!(output(type_.clone(), &res, format), r#"["Hello","world"]"#);
+        }
+    }
+
+    fn expand(arg: &str) -> Result<String, Error> {
+        let vars = HashMap::from([("WORLD", "world"), ("EMPTY", "")]);
+        expand_vars(arg, |name| vars.get(name).map(ToString::to_string))
+    }
+
+    #[test]
+    fn expands_set_env_vars() {
+        assert_eq!(expand("--world=$WORLD").unwrap(), "--world=world");
+        assert_eq!(expand("--world=${WORLD}!").unwrap(), "--world=world!");
+        assert_eq!(expand("$WORLD-$WORLD$EMPTY").unwrap(), "world-world");
+        assert_eq!(expand("costs $5 or $").unwrap(), "costs $5 or $");
+    }
+
+    #[test]
+    fn rejects_unset_env_vars() {
+        assert!(matches!(
+            expand("--world=$UNSET"),
+            Err(Error::UnsetEnvVar(name)) if name == "UNSET"
+        ));
+        assert!(matches!(
+            expand("--world=${UNSET}"),
+            Err(Error::UnsetEnvVar(name)) if name == "UNSET"
+        ));
+        assert!(matches!(
+            expand("--world=${WORLD"),
+            Err(Error::UnterminatedEnvVar(_))
+        ));
+    }
+
+    #[test]
+    fn u64_output() {
+        let res = ScVal::U64(u64::MAX);
+        for format in [Output::Json, Output::Text] {
+            assert_eq!(
+                output(ScSpecTypeDef::U64, &res, format),
+                "18446744073709551615"
+            );
+        }
+    }
+}
@@ -1,11 +1,17 @@
+use crate::commands::global;
+
 use super::{deploy, id};
 
+pub mod alias;
+
 #[derive(Debug, clap::Subcommand)]
 pub enum Cmd {
     /// Get Id of builtin Soroban Asset Contract. Deprecated, use `stellar contract id asset` instead
     Id(id::asset::Cmd),
     /// Deploy builtin Soroban Asset Contract
     Deploy(deploy::asset::Cmd),
+    /// Save an alias for an asset, usable anywhere an asset of the form `code:issuer` is accepted
+    Alias(alias::Cmd),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -14,13 +20,16 @@ pub enum Error {
     Id(#[from] id::asset::Error),
     #[error(transparent)]
     Deploy(#[from] deploy::asset::Error),
+    #[error(transparent)]
+    Alias(#[from] alias::Error),
 }
 
 impl Cmd {
-    pub async fn run(&self) -> Result<(), Error> {
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match &self {
-            Cmd::Id(id) => id.run()?,
-            Cmd::Deploy(asset) => asset.run().await?,
+            Cmd::Id(id) => id.run(global_args)?,
+            Cmd::Deploy(asset) => asset.run(global_args).await?,
+            Cmd::Alias(alias) => alias.run(global_args)?,
         }
         Ok(())
     }
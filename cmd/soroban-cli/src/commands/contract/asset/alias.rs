@@ -0,0 +1,69 @@
+use std::fmt::Debug;
+
+use clap::{command, Parser};
+
+use crate::commands::global;
+use crate::config::locator;
+use crate::print::Print;
+use crate::tx::builder;
+
+#[derive(Parser, Debug, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    #[command(flatten)]
+    pub config_locator: locator::Args,
+
+    /// The asset alias that will be used, e.g. `usdc`.
+    pub alias: String,
+
+    /// The asset that will be associated with the alias, e.g. `USDC:G...` or `native`.
+    #[arg(long)]
+    pub asset: String,
+
+    /// Overwrite the asset alias if it already exists.
+    #[arg(long)]
+    pub overwrite: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Locator(#[from] locator::Error),
+
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
+
+    #[error("asset must be 'native' or 'code:issuer', got '{0}'")]
+    NotAnAsset(String),
+
+    #[error("alias '{alias}' is already referencing asset '{asset}'")]
+    AlreadyExist { alias: String, asset: String },
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let print = Print::new(global_args.quiet);
+        let alias = &self.alias;
+        let asset = &self.asset;
+
+        if asset != "native" && !asset.contains(':') {
+            return Err(Error::NotAnAsset(asset.clone()));
+        }
+        asset.parse::<builder::Asset>()?;
+
+        if let Some(existing) = self.config_locator.get_asset_alias(alias)? {
+            if existing != *asset && !self.overwrite {
+                return Err(Error::AlreadyExist {
+                    alias: alias.clone(),
+                    asset: existing,
+                });
+            }
+        }
+
+        print.infoln(format!("Saving asset alias '{alias}' for {asset}"));
+        self.config_locator.save_asset_alias(alias, asset)?;
+        print.checkln(format!("Asset alias '{alias}' has been saved"));
+
+        Ok(())
+    }
+}
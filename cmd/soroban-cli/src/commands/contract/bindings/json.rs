@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use clap::{command, Parser};
 use soroban_spec_json;
 
-use crate::wasm;
+use crate::{commands::global, print::Print, wasm};
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -19,11 +19,11 @@ pub enum Error {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let wasm_path_str = self.wasm.wasm.to_string_lossy();
         let json = soroban_spec_json::generate_from_file(&wasm_path_str, None)
             .map_err(Error::GenerateJsonFromFile)?;
-        println!("{json}");
+        Print::from(global_args).outln(json);
         Ok(())
     }
 }
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use clap::{command, Parser};
 use soroban_spec_rust::{self, ToFormattedString};
 
-use crate::wasm;
+use crate::{commands::global, print::Print, wasm};
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -21,17 +21,18 @@ pub enum Error {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let wasm_path_str = self.wasm.wasm.to_string_lossy();
         let code = soroban_spec_rust::generate_from_file(&wasm_path_str, None)
             .map_err(Error::GenerateRustFromFile)?;
+        let print = Print::from(global_args);
         match code.to_formatted_string() {
             Ok(formatted) => {
-                println!("{formatted}");
+                print.outln(formatted);
                 Ok(())
             }
             Err(e) => {
-                println!("{code}");
+                print.outln(code);
                 Err(Error::FormatRust(e.to_string()))
             }
         }
@@ -61,7 +61,7 @@ impl NetworkRunnable for Cmd {
         let print = Print::new(global_args.is_some_and(|a| a.quiet));
 
         let contract_spec::Fetched { contract, source } =
-            contract_spec::fetch(&self.wasm_or_hash_or_contract_id, &print).await?;
+            contract_spec::fetch(&self.wasm_or_hash_or_contract_id, global_args, &print).await?;
 
         let spec = match contract {
             contract_spec::Contract::Wasm { wasm_bytes } => Spec::new(&wasm_bytes)?.spec,
@@ -117,7 +117,7 @@ impl NetworkRunnable for Cmd {
 }
 
 impl Cmd {
-    pub async fn run(&self) -> Result<(), Error> {
-        self.run_against_rpc_server(None, None).await
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        self.run_against_rpc_server(Some(global_args), None).await
     }
 }
@@ -114,7 +114,7 @@ const META_CUSTOM_SECTION_NAME: &str = "contractmetav0";
 
 impl Cmd {
     pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let print = Print::new(global_args.quiet);
+        let print = Print::from(global_args);
 
         let working_dir = env::current_dir().map_err(Error::GettingCurrentDir)?;
 
@@ -183,7 +183,7 @@ impl Cmd {
             let cmd_str = cmd_str_parts.join(" ");
 
             if self.print_commands_only {
-                println!("{cmd_str}");
+                print.outln(cmd_str);
             } else {
                 print.infoln(cmd_str);
                 let status = cmd.status().map_err(Error::CargoCmd)?;
@@ -22,7 +22,7 @@ pub enum Error {
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match &self {
-            Cmd::Asset(asset) => asset.run().await?,
+            Cmd::Asset(asset) => asset.run(global_args).await?,
             Cmd::Wasm(wasm) => wasm.run(global_args).await?,
         }
         Ok(())
@@ -1,23 +1,26 @@
 use crate::xdr::{
-    Asset, ContractDataDurability, ContractExecutable, ContractIdPreimage, CreateContractArgs,
-    Error as XdrError, Hash, HostFunction, InvokeHostFunctionOp, LedgerKey::ContractData,
-    LedgerKeyContractData, Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions,
-    ScAddress, ScVal, SequenceNumber, Transaction, TransactionExt, VecM, WriteXdr,
+    AccountId, Asset, ContractDataDurability, ContractExecutable, ContractIdPreimage,
+    CreateContractArgs, Error as XdrError, Hash, HostFunction, InvokeHostFunctionOp,
+    LedgerKey::ContractData, LedgerKeyContractData, Limits, Memo, Operation, OperationBody,
+    Preconditions, ScAddress, ScVal, SequenceNumber, Transaction, TransactionExt, VecM, WriteXdr,
 };
 use clap::{arg, command, Parser};
 use std::convert::Infallible;
 use std::{array::TryFromSliceError, fmt::Debug, num::ParseIntError};
 
 use crate::{
-    assembled::simulate_and_assemble_transaction,
     commands::{
         global,
         txn_result::{TxnEnvelopeResult, TxnResult},
         NetworkRunnable,
     },
     config::{self, data, network},
+    print::Print,
     rpc::Error as SorobanRpcError,
-    tx::builder,
+    tx::{
+        builder,
+        submit::{self, assemble_sign_submit},
+    },
     utils::contract_id_hash_from_asset,
 };
 
@@ -39,6 +42,10 @@ pub enum Error {
     Network(#[from] network::Error),
     #[error(transparent)]
     Builder(#[from] builder::Error),
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
 }
 
 impl From<Infallible> for Error {
@@ -52,21 +59,29 @@ impl From<Infallible> for Error {
 pub struct Cmd {
     /// ID of the Stellar classic asset to wrap, e.g. "USDC:G...5"
     #[arg(long)]
-    pub asset: builder::Asset,
+    pub asset: builder::UnresolvedAsset,
 
     #[command(flatten)]
     pub config: config::Args,
     #[command(flatten)]
     pub fee: crate::fee::Args,
+
+    /// Don't cache the transaction, even when run without global arguments
+    #[arg(skip)]
+    pub no_cache: bool,
 }
 
 impl Cmd {
-    pub async fn run(&self) -> Result<(), Error> {
-        let res = self.run_against_rpc_server(None, None).await?.to_envelope();
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let res = self
+            .run_against_rpc_server(Some(global_args), None)
+            .await?
+            .to_envelope();
+        let print = Print::from(global_args);
         match res {
-            TxnEnvelopeResult::TxnEnvelope(tx) => println!("{}", tx.to_xdr_base64(Limits::none())?),
+            TxnEnvelopeResult::TxnEnvelope(tx) => print.outln(tx.to_xdr_base64(Limits::none())?),
             TxnEnvelopeResult::Res(contract) => {
-                println!("{contract}");
+                print.outln(contract);
             }
         }
         Ok(())
@@ -83,46 +98,43 @@ impl NetworkRunnable for Cmd {
         args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<Self::Result, Error> {
+        network::ensure_online(args)?;
         let config = config.unwrap_or(&self.config);
-        // Parse asset
-        let asset = &self.asset;
+        let asset = &self.asset.resolve(&config.locator)?;
 
-        let network = config.get_network()?;
+        let network = config.resolve_network(args).await?;
         let client = network.rpc_client()?;
-        client
-            .verify_network_passphrase(Some(&network.network_passphrase))
-            .await?;
-        let source_account = config.source_account()?;
+        network
+            .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+            .await??;
+        let source_account = config.unmuxed_source_account()?;
         // Get the account sequence number
         // TODO: use symbols for the method names (both here and in serve)
-        let account_details = client
-            .get_account(&source_account.clone().to_string())
-            .await?;
+        let account_details = network
+            .timeout(client.get_account(&source_account.to_string()))
+            .await??;
         let sequence: i64 = account_details.seq_num.into();
         let network_passphrase = &network.network_passphrase;
         let contract_id = contract_id_hash_from_asset(asset, network_passphrase);
+        let fee = self.fee.inclusion_fee(&network).await?;
         let tx = build_wrap_token_tx(
             asset,
             &contract_id,
             sequence + 1,
-            self.fee.fee,
+            fee,
             network_passphrase,
             source_account,
         )?;
         if self.fee.build_only {
             return Ok(TxnResult::Txn(Box::new(tx)));
         }
-        let txn = simulate_and_assemble_transaction(&client, &tx).await?;
-        let txn = self.fee.apply_to_assembled_txn(txn).transaction().clone();
-        if self.fee.sim_only {
-            return Ok(TxnResult::Txn(Box::new(txn)));
-        }
-        let get_txn_resp = client
-            .send_transaction_polling(&self.config.sign_with_local_key(txn).await?)
-            .await?
-            .try_into()?;
-        if args.map_or(true, |a| !a.no_cache) {
-            data::write(get_txn_resp, &network.rpc_uri()?)?;
+        let signer = config.signer(Print::new(args.map_or(false, |a| a.quiet)))?;
+        let res = match assemble_sign_submit(&client, &tx, &signer, &self.fee, &network).await? {
+            TxnResult::Txn(txn) => return Ok(TxnResult::Txn(txn)),
+            TxnResult::Res(res) => res,
+        };
+        if !self.no_cache && args.map_or(true, |a| !a.no_cache) {
+            data::write(res.try_into()?, &network.rpc_uri()?)?;
         }
 
         Ok(TxnResult::Res(stellar_strkey::Contract(contract_id.0)))
@@ -135,7 +147,7 @@ fn build_wrap_token_tx(
     sequence: i64,
     fee: u32,
     _network_passphrase: &str,
-    source_account: MuxedAccount,
+    source_account: AccountId,
 ) -> Result<Transaction, Error> {
     let contract = ScAddress::Contract(Hash(contract_id.0));
     let mut read_write = vec![
@@ -175,7 +187,7 @@ fn build_wrap_token_tx(
     };
 
     Ok(Transaction {
-        source_account,
+        source_account: source_account.into(),
         fee,
         seq_num: SequenceNumber(sequence),
         cond: Preconditions::None,
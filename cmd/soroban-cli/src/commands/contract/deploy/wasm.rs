@@ -6,9 +6,8 @@ use std::num::ParseIntError;
 use crate::xdr::{
     AccountId, ContractExecutable, ContractIdPreimage, ContractIdPreimageFromAddress,
     CreateContractArgs, CreateContractArgsV2, Error as XdrError, Hash, HostFunction,
-    InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation, OperationBody,
-    Preconditions, PublicKey, ScAddress, SequenceNumber, Transaction, TransactionExt, Uint256,
-    VecM, WriteXdr,
+    InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, Operation, OperationBody,
+    Preconditions, ScAddress, SequenceNumber, Transaction, TransactionExt, Uint256, VecM, WriteXdr,
 };
 use clap::{arg, command, Parser};
 use rand::Rng;
@@ -17,7 +16,6 @@ use regex::Regex;
 use soroban_spec_tools::contract as contract_spec;
 
 use crate::{
-    assembled::simulate_and_assemble_transaction,
     commands::{
         contract::{self, arg_parsing, id::wasm::get_contract_id, install},
         global,
@@ -27,6 +25,7 @@ use crate::{
     config::{self, data, locator, network},
     print::Print,
     rpc,
+    tx::submit::{self, assemble_sign_submit},
     utils::{self, rpc::get_remote_wasm_from_hash},
     wasm,
 };
@@ -99,6 +98,8 @@ pub enum Error {
     #[error(transparent)]
     Rpc(#[from] rpc::Error),
     #[error(transparent)]
+    RemoteWasm(#[from] utils::rpc::Error),
+    #[error(transparent)]
     Config(#[from] config::Error),
     #[error(transparent)]
     StrKey(#[from] stellar_strkey::DecodeError),
@@ -122,8 +123,10 @@ pub enum Error {
     ContractSpec(#[from] contract_spec::Error),
     #[error(transparent)]
     ArgParse(#[from] arg_parsing::Error),
-    #[error("Only ed25519 accounts are allowed")]
-    OnlyEd25519AccountsAllowed,
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
+    #[error("no wasm with hash {wasm_hash} is installed on the network, install the wasm first with `stellar contract install --wasm <WASM>` or deploy with `--wasm`")]
+    WasmNotInstalled { wasm_hash: String },
 }
 
 impl Cmd {
@@ -132,8 +135,9 @@ impl Cmd {
             .run_against_rpc_server(Some(global_args), None)
             .await?
             .to_envelope();
+        let print = Print::from(global_args);
         match res {
-            TxnEnvelopeResult::TxnEnvelope(tx) => println!("{}", tx.to_xdr_base64(Limits::none())?),
+            TxnEnvelopeResult::TxnEnvelope(tx) => print.outln(tx.to_xdr_base64(Limits::none())?),
             TxnEnvelopeResult::Res(contract) => {
                 let network = self.config.get_network()?;
 
@@ -145,7 +149,7 @@ impl Cmd {
                     )?;
                 }
 
-                println!("{contract}");
+                print.outln(contract);
             }
         }
         Ok(())
@@ -175,6 +179,7 @@ impl NetworkRunnable for Cmd {
         global_args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<TxnResult<stellar_strkey::Contract>, Error> {
+        network::ensure_online(global_args)?;
         let print = Print::new(global_args.map_or(false, |a| a.quiet));
         let config = config.unwrap_or(&self.config);
         let wasm_hash = if let Some(wasm) = &self.wasm {
@@ -211,7 +216,7 @@ impl NetworkRunnable for Cmd {
 
         print.infoln(format!("Using wasm hash {wasm_hash}").as_str());
 
-        let network = config.get_network()?;
+        let network = config.resolve_network(global_args).await?;
         let salt: [u8; 32] = match &self.salt {
             Some(h) => soroban_spec_tools::utils::padded_hex_from_str(h, 32)
                 .map_err(|_| Error::CannotParseSalt { salt: h.clone() })?
@@ -221,14 +226,11 @@ impl NetworkRunnable for Cmd {
         };
 
         let client = network.rpc_client()?;
-        client
-            .verify_network_passphrase(Some(&network.network_passphrase))
-            .await?;
+        network
+            .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+            .await??;
 
-        let MuxedAccount::Ed25519(bytes) = config.source_account()? else {
-            return Err(Error::OnlyEd25519AccountsAllowed);
-        };
-        let source_account = AccountId(PublicKey::PublicKeyTypeEd25519(bytes));
+        let source_account = config.unmuxed_source_account()?;
         let contract_id_preimage = ContractIdPreimage::Address(ContractIdPreimageFromAddress {
             address: ScAddress::Account(source_account.clone()),
             salt: Uint256(salt),
@@ -238,7 +240,15 @@ impl NetworkRunnable for Cmd {
         let raw_wasm = if let Some(wasm) = self.wasm.as_ref() {
             wasm::Args { wasm: wasm.clone() }.read()?
         } else {
-            get_remote_wasm_from_hash(&client, &wasm_hash).await?
+            network
+                .timeout(get_remote_wasm_from_hash(&client, &wasm_hash))
+                .await?
+                .map_err(|e| match e {
+                    utils::rpc::Error::Rpc(rpc::Error::NotFound(..)) => Error::WasmNotInstalled {
+                        wasm_hash: hex::encode(wasm_hash.0),
+                    },
+                    e => e.into(),
+                })?
         };
         let entries = soroban_spec_tools::contract::Spec::new(&raw_wasm)?.spec;
         let res = soroban_spec_tools::Spec::new(entries.clone());
@@ -263,12 +273,15 @@ impl NetworkRunnable for Cmd {
         };
 
         // Get the account sequence number
-        let account_details = client.get_account(&source_account.to_string()).await?;
+        let account_details = network
+            .timeout(client.get_account(&source_account.to_string()))
+            .await??;
         let sequence: i64 = account_details.seq_num.into();
+        let fee = self.fee.inclusion_fee(&network).await?;
         let txn = Box::new(build_create_contract_tx(
             wasm_hash,
             sequence + 1,
-            self.fee.fee,
+            fee,
             source_account,
             contract_id_preimage,
             constructor_params.as_ref(),
@@ -279,26 +292,19 @@ impl NetworkRunnable for Cmd {
             return Ok(TxnResult::Txn(txn));
         }
 
-        print.infoln("Simulating deploy transaction…");
-
-        let txn = simulate_and_assemble_transaction(&client, &txn).await?;
-        let txn = Box::new(self.fee.apply_to_assembled_txn(txn).transaction().clone());
+        print.infoln("Simulating and submitting deploy transaction…");
 
-        if self.fee.sim_only {
-            print.checkln("Done!");
-            return Ok(TxnResult::Txn(txn));
-        }
-
-        print.globeln("Submitting deploy transaction…");
-        print.log_transaction(&txn, &network, true)?;
-
-        let get_txn_resp = client
-            .send_transaction_polling(&config.sign_with_local_key(*txn).await?)
-            .await?
-            .try_into()?;
+        let signer = config.signer(print.clone())?;
+        let res = match assemble_sign_submit(&client, &txn, &signer, &self.fee, &network).await? {
+            TxnResult::Txn(txn) => {
+                print.checkln("Done!");
+                return Ok(TxnResult::Txn(txn));
+            }
+            TxnResult::Res(res) => res,
+        };
 
         if global_args.map_or(true, |a| !a.no_cache) {
-            data::write(get_txn_resp, &network.rpc_uri()?)?;
+            data::write(res.try_into()?, &network.rpc_uri()?)?;
         }
 
         if let Some(url) = utils::explorer_url_for_contract(&network, &contract_id) {
@@ -359,6 +365,7 @@ fn build_create_contract_tx(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::xdr::PublicKey;
 
     #[test]
     fn test_build_create_contract() {
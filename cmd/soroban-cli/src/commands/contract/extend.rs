@@ -3,20 +3,23 @@ use std::{fmt::Debug, path::Path, str::FromStr};
 use crate::xdr::{
     Error as XdrError, ExtendFootprintTtlOp, ExtensionPoint, LedgerEntry, LedgerEntryChange,
     LedgerEntryData, LedgerFootprint, Limits, Memo, Operation, OperationBody, Preconditions,
-    SequenceNumber, SorobanResources, SorobanTransactionData, Transaction, TransactionExt,
-    TransactionMeta, TransactionMetaV3, TtlEntry, WriteXdr,
+    SequenceNumber, Transaction, TransactionExt, TransactionMeta, TransactionMetaV3, TtlEntry,
+    WriteXdr,
 };
 use clap::{command, Parser};
 
 use crate::{
-    assembled::simulate_and_assemble_transaction,
     commands::{
         global,
         txn_result::{TxnEnvelopeResult, TxnResult},
         NetworkRunnable,
     },
     config::{self, data, locator, network},
-    key, rpc, wasm, Pwd,
+    key,
+    print::Print,
+    rpc,
+    tx::submit::{self, assemble_sign_submit, sign_submit},
+    utils, wasm, Pwd,
 };
 
 const MAX_LEDGERS_TO_EXTEND: u32 = 535_679;
@@ -85,19 +88,27 @@ pub enum Error {
     Network(#[from] network::Error),
     #[error(transparent)]
     Locator(#[from] locator::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
 }
 
 impl Cmd {
     #[allow(clippy::too_many_lines)]
-    pub async fn run(&self) -> Result<(), Error> {
-        let res = self.run_against_rpc_server(None, None).await?.to_envelope();
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let res = self
+            .run_against_rpc_server(Some(global_args), None)
+            .await?
+            .to_envelope();
+        let print = Print::from(global_args);
         match res {
-            TxnEnvelopeResult::TxnEnvelope(tx) => println!("{}", tx.to_xdr_base64(Limits::none())?),
+            TxnEnvelopeResult::TxnEnvelope(tx) => print.outln(tx.to_xdr_base64(Limits::none())?),
             TxnEnvelopeResult::Res(ttl_ledger) => {
                 if self.ttl_ledger_only {
-                    println!("{ttl_ledger}");
+                    print.outln(ttl_ledger);
                 } else {
-                    println!("New ttl ledger: {ttl_ledger}");
+                    print.outln(format!("New ttl ledger: {ttl_ledger}"));
                 }
             }
         }
@@ -126,8 +137,9 @@ impl NetworkRunnable for Cmd {
         args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<TxnResult<u32>, Self::Error> {
+        network::ensure_online(args)?;
         let config = config.unwrap_or(&self.config);
-        let network = config.get_network()?;
+        let network = config.resolve_network(args).await?;
         tracing::trace!(?network);
         let keys = self.key.parse_keys(&config.locator, &network)?;
         let client = network.rpc_client()?;
@@ -135,14 +147,18 @@ impl NetworkRunnable for Cmd {
         let extend_to = self.ledgers_to_extend();
 
         // Get the account sequence number
-        let account_details = client
-            .get_account(&source_account.clone().to_string())
-            .await?;
+        let account_details = network
+            .timeout(client.get_account(&source_account.clone().to_string()))
+            .await??;
         let sequence: i64 = account_details.seq_num.into();
 
+        let (fee, transaction_data) = self.fee.soroban_transaction_data(LedgerFootprint {
+            read_only: keys.clone().try_into()?,
+            read_write: vec![].try_into()?,
+        });
         let tx = Box::new(Transaction {
             source_account,
-            fee: self.fee.fee,
+            fee,
             seq_num: SequenceNumber(sequence + 1),
             cond: Preconditions::None,
             memo: Memo::None,
@@ -154,30 +170,20 @@ impl NetworkRunnable for Cmd {
                 }),
             }]
             .try_into()?,
-            ext: TransactionExt::V1(SorobanTransactionData {
-                ext: ExtensionPoint::V0,
-                resources: SorobanResources {
-                    footprint: LedgerFootprint {
-                        read_only: keys.clone().try_into()?,
-                        read_write: vec![].try_into()?,
-                    },
-                    instructions: self.fee.instructions.unwrap_or_default(),
-                    read_bytes: 0,
-                    write_bytes: 0,
-                },
-                resource_fee: 0,
-            }),
+            ext: TransactionExt::V1(transaction_data),
         });
         if self.fee.build_only {
             return Ok(TxnResult::Txn(tx));
         }
-        let tx = simulate_and_assemble_transaction(&client, &tx)
-            .await?
-            .transaction()
-            .clone();
-        let res = client
-            .send_transaction_polling(&config.sign_with_local_key(tx).await?)
-            .await?;
+        let signer = config.signer(Print::new(args.map_or(false, |a| a.quiet)))?;
+        let res = if self.fee.has_manual_resources() {
+            sign_submit(&client, *tx, &signer, &self.fee, &network).await?
+        } else {
+            match assemble_sign_submit(&client, &tx, &signer, &self.fee, &network).await? {
+                TxnResult::Txn(tx) => return Ok(TxnResult::Txn(tx)),
+                TxnResult::Res(res) => res,
+            }
+        };
         if args.map_or(true, |a| !a.no_cache) {
             data::write(res.clone().try_into()?, &network.rpc_uri()?)?;
         }
@@ -201,7 +207,9 @@ impl NetworkRunnable for Cmd {
         }
 
         if operations[0].changes.is_empty() {
-            let entry = client.get_full_ledger_entries(&keys).await?;
+            let entry = network
+                .timeout(client.get_full_ledger_entries(&keys))
+                .await??;
             let extension = entry.entries[0].live_until_ledger_seq;
             if entry.latest_ledger + i64::from(extend_to) < i64::from(extension) {
                 return Ok(TxnResult::Res(extension));
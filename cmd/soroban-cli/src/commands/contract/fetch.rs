@@ -1,9 +1,12 @@
 use std::convert::Infallible;
 
-use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::{fmt::Debug, fs, io};
+use std::{
+    fmt::Debug,
+    fs,
+    io::{self, Write},
+};
 
 use clap::{arg, command, Parser};
 
@@ -13,6 +16,7 @@ use crate::{
         self, locator,
         network::{self, Network},
     },
+    print::Print,
     wasm, Pwd,
 };
 
@@ -72,8 +76,8 @@ impl From<Infallible> for Error {
 }
 
 impl Cmd {
-    pub async fn run(&self) -> Result<(), Error> {
-        let bytes = self.get_bytes().await?;
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let bytes = self.get_bytes(global_args).await?;
         if let Some(out_file) = &self.out_file {
             if let Some(parent) = out_file.parent() {
                 if !parent.exists() {
@@ -84,16 +88,15 @@ impl Cmd {
             fs::write(out_file, bytes)
                 .map_err(|io| Error::CannotWriteContractFile(out_file.clone(), io))
         } else {
-            let stdout = std::io::stdout();
-            let mut handle = stdout.lock();
-            handle.write_all(&bytes)?;
-            handle.flush()?;
+            let mut stdout = Print::from(global_args).stdout();
+            stdout.write_all(&bytes)?;
+            stdout.flush()?;
             Ok(())
         }
     }
 
-    pub async fn get_bytes(&self) -> Result<Vec<u8>, Error> {
-        self.run_against_rpc_server(None, None).await
+    pub async fn get_bytes(&self, global_args: &global::Args) -> Result<Vec<u8>, Error> {
+        self.run_against_rpc_server(Some(global_args), None).await
     }
 
     pub fn network(&self) -> Result<Network, Error> {
@@ -107,10 +110,14 @@ impl NetworkRunnable for Cmd {
     type Result = Vec<u8>;
     async fn run_against_rpc_server(
         &self,
-        _args: Option<&global::Args>,
+        global_args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<Vec<u8>, Error> {
-        let network = config.map_or_else(|| self.network(), |c| Ok(c.get_network()?))?;
+        network::ensure_online(global_args)?;
+        let network = match config {
+            Some(config) => config.resolve_network(global_args).await?,
+            None => self.network.resolve(&self.locator, global_args).await?,
+        };
         Ok(wasm::fetch_from_contract(
             &self
                 .contract_id
@@ -1,6 +1,8 @@
 use clap::{arg, command, Parser};
 
+use crate::commands::global;
 use crate::config;
+use crate::print::Print;
 
 use crate::tx::builder;
 use crate::utils::contract_id_hash_from_asset;
@@ -10,7 +12,7 @@ use crate::utils::contract_id_hash_from_asset;
 pub struct Cmd {
     /// ID of the Stellar classic asset to wrap, e.g. "USDC:G...5"
     #[arg(long)]
-    pub asset: builder::Asset,
+    pub asset: builder::UnresolvedAsset,
 
     #[command(flatten)]
     pub config: config::ArgsLocatorAndNetwork,
@@ -21,16 +23,19 @@ pub enum Error {
     ConfigError(#[from] config::Error),
     #[error(transparent)]
     Xdr(#[from] crate::xdr::Error),
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
 }
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
-        println!("{}", self.contract_address()?);
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        Print::from(global_args).outln(self.contract_address()?);
         Ok(())
     }
 
     pub fn contract_address(&self) -> Result<stellar_strkey::Contract, Error> {
         let network = self.config.get_network()?;
-        let contract_id = contract_id_hash_from_asset(&self.asset, &network.network_passphrase);
+        let asset = self.asset.resolve(&self.config.locator)?;
+        let contract_id = contract_id_hash_from_asset(&asset, &network.network_passphrase);
         Ok(stellar_strkey::Contract(contract_id.0))
     }
 }
@@ -1,11 +1,11 @@
 use crate::xdr::{
-    self, AccountId, ContractIdPreimage, ContractIdPreimageFromAddress, Hash, HashIdPreimage,
-    HashIdPreimageContractId, Limits, PublicKey, ScAddress, Uint256, WriteXdr,
+    self, AccountId, ContractIdPreimage, ContractIdPreimageFromAddress, HashIdPreimage,
+    HashIdPreimageContractId, Limits, ScAddress, Uint256, WriteXdr,
 };
 use clap::{arg, command, Parser};
 use sha2::{Digest, Sha256};
 
-use crate::config;
+use crate::{commands::global, config, print::Print, utils::NetworkId};
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -25,36 +25,29 @@ pub enum Error {
     Xdr(#[from] xdr::Error),
     #[error("cannot parse salt {0}")]
     CannotParseSalt(String),
-    #[error("only Ed25519 accounts are allowed")]
-    OnlyEd25519AccountsAllowed,
 }
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let salt: [u8; 32] = soroban_spec_tools::utils::padded_hex_from_str(&self.salt, 32)
             .map_err(|_| Error::CannotParseSalt(self.salt.clone()))?
             .try_into()
             .map_err(|_| Error::CannotParseSalt(self.salt.clone()))?;
-        let source_account = match self.config.source_account()? {
-            xdr::MuxedAccount::Ed25519(uint256) => stellar_strkey::ed25519::PublicKey(uint256.0),
-            xdr::MuxedAccount::MuxedEd25519(_) => return Err(Error::OnlyEd25519AccountsAllowed),
-        };
+        // Muxed accounts share the contract ids of their underlying account, as only the
+        // account id is part of the preimage.
+        let (_, source_account) = self.config.source_account_and_id()?;
         let contract_id_preimage = contract_preimage(&source_account, salt);
         let contract_id = get_contract_id(
             contract_id_preimage.clone(),
             &self.config.get_network()?.network_passphrase,
         )?;
-        println!("{contract_id}");
+        Print::from(global_args).outln(contract_id);
         Ok(())
     }
 }
 
-pub fn contract_preimage(
-    key: &stellar_strkey::ed25519::PublicKey,
-    salt: [u8; 32],
-) -> ContractIdPreimage {
-    let source_account = AccountId(PublicKey::PublicKeyTypeEd25519(key.0.into()));
+pub fn contract_preimage(source_account: &AccountId, salt: [u8; 32]) -> ContractIdPreimage {
     ContractIdPreimage::Address(ContractIdPreimageFromAddress {
-        address: ScAddress::Account(source_account),
+        address: ScAddress::Account(source_account.clone()),
         salt: Uint256(salt),
     })
 }
@@ -63,7 +56,7 @@ pub fn get_contract_id(
     contract_id_preimage: ContractIdPreimage,
     network_passphrase: &str,
 ) -> Result<stellar_strkey::Contract, Error> {
-    let network_id = Hash(Sha256::digest(network_passphrase.as_bytes()).into());
+    let network_id = NetworkId::new(network_passphrase).into();
     let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
         network_id,
         contract_id_preimage,
@@ -73,3 +66,24 @@ pub fn get_contract_id(
         Sha256::digest(preimage_xdr).into(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn muxed_account_has_same_contract_id() {
+        let key = [7; 32];
+        let account = xdr::MuxedAccount::Ed25519(Uint256(key));
+        let muxed = xdr::MuxedAccount::MuxedEd25519(xdr::MuxedAccountMed25519 {
+            id: 42,
+            ed25519: Uint256(key),
+        });
+        let passphrase = "Test SDF Network ; September 2015";
+        let id = |account: &xdr::MuxedAccount| {
+            let account_id = account.clone().account_id();
+            get_contract_id(contract_preimage(&account_id, [0; 32]), passphrase).unwrap()
+        };
+        assert_eq!(id(&account), id(&muxed));
+    }
+}
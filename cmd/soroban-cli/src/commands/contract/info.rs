@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::commands::global;
+use crate::{commands::global, print::Print};
 
 pub mod env_meta;
 pub mod interface;
@@ -68,7 +68,7 @@ impl Cmd {
             Cmd::Meta(meta) => meta.run(global_args).await?,
             Cmd::EnvMeta(env_meta) => env_meta.run(global_args).await?,
         };
-        println!("{result}");
+        Print::from(global_args).outln(result);
         Ok(())
     }
 }
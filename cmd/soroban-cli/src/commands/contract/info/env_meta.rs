@@ -43,7 +43,7 @@ pub enum Error {
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<String, Error> {
         let print = Print::new(global_args.quiet);
-        let Fetched { contract, .. } = fetch(&self.common, &print).await?;
+        let Fetched { contract, .. } = fetch(&self.common, Some(global_args), &print).await?;
 
         let spec = match contract {
             shared::Contract::Wasm { wasm_bytes } => Spec::new(&wasm_bytes)?,
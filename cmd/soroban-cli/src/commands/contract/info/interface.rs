@@ -26,9 +26,12 @@ pub enum InfoOutput {
     /// XDR output of the info entry
     XdrBase64,
     /// JSON output of the info entry (one line, not formatted)
+    #[value(alias = "json-compact")]
     Json,
     /// Formatted (multiline) JSON output of the info entry
     JsonFormatted,
+    /// JSON Schema of the arguments of each function, to validate inputs with
+    JsonSchema,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -41,12 +44,14 @@ pub enum Error {
     NoInterfacePresent(),
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    JsonSchema(#[from] soroban_spec_tools::Error),
 }
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<String, Error> {
         let print = Print::new(global_args.quiet);
-        let Fetched { contract, .. } = fetch(&self.common, &print).await?;
+        let Fetched { contract, .. } = fetch(&self.common, Some(global_args), &print).await?;
 
         let (base64, spec) = match contract {
             shared::Contract::Wasm { wasm_bytes } => {
@@ -67,6 +72,10 @@ impl Cmd {
             InfoOutput::XdrBase64 => base64,
             InfoOutput::Json => serde_json::to_string(&spec)?,
             InfoOutput::JsonFormatted => serde_json::to_string_pretty(&spec)?,
+            InfoOutput::JsonSchema => {
+                let schema = soroban_spec_tools::Spec::new(spec).json_schema()?;
+                serde_json::to_string_pretty(&schema)?
+            }
             InfoOutput::Rust => soroban_spec_rust::generate_without_file(&spec)
                 .to_formatted_string()
                 .expect("Unexpected spec format error"),
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use clap::arg;
 
 use crate::{
-    commands::contract::info::shared::Error::InvalidWasmHash,
+    commands::{contract::info::shared::Error::InvalidWasmHash, global},
     config::{
         self, locator,
         network::{self, Network},
@@ -62,6 +62,7 @@ pub enum MetasInfoOutput {
     /// XDR output of the info entry
     XdrBase64,
     /// JSON output of the info entry (one line, not formatted)
+    #[value(alias = "json-compact")]
     Json,
     /// Formatted (multiline) JSON output of the info entry
     JsonFormatted,
@@ -80,6 +81,8 @@ pub enum Error {
     #[error(transparent)]
     Rpc(#[from] soroban_rpc::Error),
     #[error(transparent)]
+    RemoteWasm(#[from] crate::utils::rpc::Error),
+    #[error(transparent)]
     Locator(#[from] locator::Error),
 }
 
@@ -118,7 +121,11 @@ impl Source {
     }
 }
 
-pub async fn fetch(args: &Args, print: &Print) -> Result<Fetched, Error> {
+pub async fn fetch(
+    args: &Args,
+    global_args: Option<&global::Args>,
+    print: &Print,
+) -> Result<Fetched, Error> {
     // Check if a local WASM file path is provided
     if let Some(path) = &args.wasm {
         // Read the WASM file and return its contents
@@ -131,7 +138,8 @@ pub async fn fetch(args: &Args, print: &Print) -> Result<Fetched, Error> {
     }
 
     // If no local wasm, then check for wasm_hash and fetch from the network
-    let network = &args.network.get(&args.locator)?;
+    network::ensure_online(global_args)?;
+    let network = &args.network.resolve(&args.locator, global_args).await?;
     print.infoln(format!("Network: {}", network.network_passphrase));
 
     if let Some(wasm_hash) = &args.wasm_hash {
@@ -144,14 +152,16 @@ pub async fn fetch(args: &Args, print: &Print) -> Result<Fetched, Error> {
 
         let client = network.rpc_client()?;
 
-        client
-            .verify_network_passphrase(Some(&network.network_passphrase))
-            .await?;
+        network
+            .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+            .await??;
 
         print.globeln(format!(
             "Downloading contract spec for wasm hash: {wasm_hash}"
         ));
-        let wasm_bytes = get_remote_wasm_from_hash(&client, &hash).await?;
+        let wasm_bytes = network
+            .timeout(get_remote_wasm_from_hash(&client, &hash))
+            .await??;
         Ok(Fetched {
             contract: Contract::Wasm { wasm_bytes },
             source: Source::Wasm {
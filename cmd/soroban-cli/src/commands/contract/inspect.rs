@@ -36,7 +36,8 @@ pub enum Error {
 
 impl Cmd {
     pub fn run(&self, global_args: &Args) -> Result<(), Error> {
-        Print::new(global_args.quiet).warnln(
+        let print = Print::from(global_args);
+        print.warnln(
             "`contract inspect` has been deprecated in favor of `contract info`. \
             Please use `contract info` instead.",
         );
@@ -50,7 +51,7 @@ impl Cmd {
             SpecOutput::XdrBase64Array => wasm.spec_as_json_array()?,
             SpecOutput::Docs => wasm.to_string(),
         };
-        println!("{output}");
+        print.outln(output);
         Ok(())
     }
 }
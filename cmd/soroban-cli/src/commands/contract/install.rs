@@ -11,7 +11,6 @@ use clap::{command, Parser};
 
 use super::restore;
 use crate::{
-    assembled::simulate_and_assemble_transaction,
     commands::{
         global,
         txn_result::{TxnEnvelopeResult, TxnResult},
@@ -21,7 +20,10 @@ use crate::{
     key,
     print::Print,
     rpc,
-    tx::builder::{self, TxExt},
+    tx::{
+        builder::{self, TxExt},
+        submit::{self, assemble_sign_submit},
+    },
     utils, wasm,
 };
 
@@ -78,6 +80,10 @@ pub enum Error {
     Data(#[from] data::Error),
     #[error(transparent)]
     Builder(#[from] builder::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
 }
 
 impl Cmd {
@@ -86,9 +92,10 @@ impl Cmd {
             .run_against_rpc_server(Some(global_args), None)
             .await?
             .to_envelope();
+        let print = Print::from(global_args);
         match res {
-            TxnEnvelopeResult::TxnEnvelope(tx) => println!("{}", tx.to_xdr_base64(Limits::none())?),
-            TxnEnvelopeResult::Res(hash) => println!("{}", hex::encode(hash)),
+            TxnEnvelopeResult::TxnEnvelope(tx) => print.outln(tx.to_xdr_base64(Limits::none())?),
+            TxnEnvelopeResult::Res(hash) => print.outln(hex::encode(hash)),
         };
         Ok(())
     }
@@ -104,14 +111,15 @@ impl NetworkRunnable for Cmd {
         args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<TxnResult<Hash>, Error> {
+        network::ensure_online(args)?;
         let print = Print::new(args.map_or(false, |a| a.quiet));
         let config = config.unwrap_or(&self.config);
         let contract = self.wasm.read()?;
-        let network = config.get_network()?;
+        let network = config.resolve_network(args).await?;
         let client = network.rpc_client()?;
-        client
-            .verify_network_passphrase(Some(&network.network_passphrase))
-            .await?;
+        network
+            .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+            .await??;
         let wasm_spec = &self.wasm.parse().map_err(|e| Error::CannotParseWasm {
             wasm: self.wasm.wasm.clone(),
             error: e,
@@ -137,13 +145,14 @@ impl NetworkRunnable for Cmd {
         // Get the account sequence number
         let source_account = config.source_account()?;
 
-        let account_details = client
-            .get_account(&source_account.clone().to_string())
-            .await?;
+        let account_details = network
+            .timeout(client.get_account(&source_account.clone().to_string()))
+            .await??;
         let sequence: i64 = account_details.seq_num.into();
+        let fee = self.fee.inclusion_fee(&network).await?;
 
         let (tx_without_preflight, hash) =
-            build_install_contract_code_tx(&contract, sequence + 1, self.fee.fee, &source_account)?;
+            build_install_contract_code_tx(&contract, sequence + 1, fee, &source_account)?;
 
         if self.fee.build_only {
             return Ok(TxnResult::Txn(Box::new(tx_without_preflight)));
@@ -155,7 +164,9 @@ impl NetworkRunnable for Cmd {
         if !self.fee.sim_only {
             let code_key =
                 xdr::LedgerKey::ContractCode(xdr::LedgerKeyContractCode { hash: hash.clone() });
-            let contract_data = client.get_ledger_entries(&[code_key]).await?;
+            let contract_data = network
+                .timeout(client.get_ledger_entries(&[code_key]))
+                .await??;
 
             // Skip install if the contract is already installed, and the contract has an extension version that isn't V0.
             // In protocol 21 extension V1 was added that stores additional information about a contract making execution
@@ -183,20 +194,21 @@ impl NetworkRunnable for Cmd {
             }
         }
 
-        print.infoln("Simulating install transaction…");
-
-        let txn = simulate_and_assemble_transaction(&client, &tx_without_preflight).await?;
-        let txn = Box::new(self.fee.apply_to_assembled_txn(txn).transaction().clone());
-
-        if self.fee.sim_only {
-            return Ok(TxnResult::Txn(txn));
-        }
-
-        print.globeln("Submitting install transaction…");
-
-        let txn_resp = client
-            .send_transaction_polling(&self.config.sign_with_local_key(*txn).await?)
-            .await?;
+        print.infoln("Simulating and submitting install transaction…");
+
+        let signer = config.signer(print.clone())?;
+        let txn_resp = match assemble_sign_submit(
+            &client,
+            &tx_without_preflight,
+            &signer,
+            &self.fee,
+            &network,
+        )
+        .await?
+        {
+            TxnResult::Txn(txn) => return Ok(TxnResult::Txn(txn)),
+            TxnResult::Res(res) => res,
+        };
 
         if args.map_or(true, |a| !a.no_cache) {
             data::write(txn_resp.clone().try_into().unwrap(), &network.rpc_uri()?)?;
@@ -23,6 +23,8 @@ use crate::{
     config::{self, data, locator, network},
     get_spec::{self, get_remote_contract_spec},
     print, rpc,
+    tx::submit::{self, sign_submit},
+    utils,
     xdr::{
         self, AccountEntry, AccountEntryExt, AccountId, ContractEvent, ContractEventType,
         DiagnosticEvent, HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo,
@@ -50,6 +52,9 @@ pub struct Cmd {
     /// Function name as subcommand, then arguments for that function as `--arg-name value`
     #[arg(last = true, id = "CONTRACT_FN_AND_ARGS")]
     pub slop: Vec<OsString>,
+    /// Expand `$VAR` and `${VAR}` environment variable references in the function arguments before parsing them. Fails if a referenced variable is not set
+    #[arg(long)]
+    pub expand_env: bool,
     #[command(flatten)]
     pub config: config::Args,
     #[command(flatten)]
@@ -57,6 +62,9 @@ pub struct Cmd {
     /// Whether or not to send a transaction
     #[arg(long, value_enum, default_value_t, env = "STELLAR_SEND")]
     pub send: Send,
+    /// Format of the return value written to stdout
+    #[arg(long, value_enum, default_value_t)]
+    pub output: arg_parsing::Output,
 }
 
 impl FromStr for Cmd {
@@ -121,6 +129,10 @@ pub enum Error {
     GetSpecError(#[from] get_spec::Error),
     #[error(transparent)]
     ArgParsing(#[from] arg_parsing::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
 }
 
 impl From<Infallible> for Error {
@@ -132,10 +144,11 @@ impl From<Infallible> for Error {
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let res = self.invoke(global_args).await?.to_envelope();
+        let print = print::Print::from(global_args);
         match res {
-            TxnEnvelopeResult::TxnEnvelope(tx) => println!("{}", tx.to_xdr_base64(Limits::none())?),
+            TxnEnvelopeResult::TxnEnvelope(tx) => print.outln(tx.to_xdr_base64(Limits::none())?),
             TxnEnvelopeResult::Res(output) => {
-                println!("{output}");
+                print.outln(output);
             }
         }
         Ok(())
@@ -183,6 +196,7 @@ impl Cmd {
         &self,
         host_function_params: InvokeContractArgs,
         rpc_client: Client,
+        network: &network::Network,
     ) -> Result<ShouldSend, Error> {
         let account_details = default_account_entry();
         let sequence: i64 = account_details.seq_num.into();
@@ -194,7 +208,9 @@ impl Cmd {
             self.fee.fee,
             account_id,
         )?;
-        let txn = simulate_and_assemble_transaction(&rpc_client, &tx).await?;
+        let txn = network
+            .timeout(simulate_and_assemble_transaction(&rpc_client, &tx))
+            .await??;
         let txn = self.fee.apply_to_assembled_txn(txn); // do we need this part?
         let sim_res = txn.sim_response();
         self.should_send_tx(sim_res)
@@ -211,17 +227,24 @@ impl NetworkRunnable for Cmd {
         global_args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<TxnResult<String>, Error> {
+        network::ensure_online(global_args)?;
         let config = config.unwrap_or(&self.config);
-        let network = config.get_network()?;
+        let network = config.resolve_network(global_args).await?;
         tracing::trace!(?network);
         let contract_id = self
             .contract_id
             .resolve_contract_id(&config.locator, &network.network_passphrase)?;
 
+        let slop = if self.expand_env {
+            arg_parsing::expand_env(&self.slop)?
+        } else {
+            self.slop.clone()
+        };
+
         let spec_entries = self.spec_entries()?;
         if let Some(spec_entries) = &spec_entries {
             // For testing wasm arg parsing
-            let _ = build_host_function_parameters(&contract_id, &self.slop, spec_entries, config)?;
+            let _ = build_host_function_parameters(&contract_id, &slop, spec_entries, config)?;
         }
         let client = network.rpc_client()?;
 
@@ -236,36 +259,39 @@ impl NetworkRunnable for Cmd {
         .map_err(Error::from)?;
 
         let (function, spec, host_function_params, signers) =
-            build_host_function_parameters(&contract_id, &self.slop, &spec_entries, config)?;
+            build_host_function_parameters(&contract_id, &slop, &spec_entries, config)?;
 
         let should_send_tx = self
-            .should_send_after_sim(host_function_params.clone(), client.clone())
+            .should_send_after_sim(host_function_params.clone(), client.clone(), &network)
             .await?;
 
         let account_details = if should_send_tx == ShouldSend::Yes {
-            client
-                .verify_network_passphrase(Some(&network.network_passphrase))
-                .await?;
+            network
+                .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+                .await??;
 
-            client
-                .get_account(&config.source_account()?.to_string())
-                .await?
+            network
+                .timeout(client.get_account(&config.source_account()?.to_string()))
+                .await??
         } else {
             default_account_entry()
         };
         let sequence: i64 = account_details.seq_num.into();
         let AccountId(PublicKey::PublicKeyTypeEd25519(account_id)) = account_details.account_id;
+        let fee = self.fee.inclusion_fee(&network).await?;
 
         let tx = Box::new(build_invoke_contract_tx(
             host_function_params.clone(),
             sequence + 1,
-            self.fee.fee,
+            fee,
             account_id,
         )?);
         if self.fee.build_only {
             return Ok(TxnResult::Txn(tx));
         }
-        let txn = simulate_and_assemble_transaction(&client, &tx).await?;
+        let txn = network
+            .timeout(simulate_and_assemble_transaction(&client, &tx))
+            .await??;
         let assembled = self.fee.apply_to_assembled_txn(txn);
         let mut txn = Box::new(assembled.transaction().clone());
         if self.fee.sim_only {
@@ -283,9 +309,9 @@ impl NetworkRunnable for Cmd {
                 if let Some(tx) = config.sign_soroban_authorizations(&txn, &signers).await? {
                     txn = Box::new(tx);
                 }
-                let res = client
-                    .send_transaction_polling(&config.sign_with_local_key(*txn).await?)
-                    .await?;
+                let print = print::Print::new(global_args.map_or(false, |g| g.quiet));
+                let signer = config.signer(print)?;
+                let res = sign_submit(&client, *txn, &signer, &self.fee, &network).await?;
                 if !no_cache {
                     data::write(res.clone().try_into()?, &network.rpc_uri()?)?;
                 }
@@ -304,7 +330,12 @@ impl NetworkRunnable for Cmd {
             }
         };
         crate::log::events(&events);
-        Ok(output_to_string(&spec, &return_value, &function)?)
+        Ok(output_to_string(
+            &spec,
+            &return_value,
+            &function,
+            self.output,
+        )?)
     }
 }
 
@@ -15,6 +15,7 @@ pub mod invoke;
 pub mod optimize;
 pub mod read;
 pub mod restore;
+pub mod verify;
 
 use crate::commands::global;
 
@@ -88,6 +89,9 @@ pub enum Cmd {
     ///
     /// If no keys are specificed the contract itself is restored.
     Restore(restore::Cmd),
+
+    /// Verify that a local WASM file matches the code deployed for a contract
+    Verify(verify::Cmd),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -139,27 +143,31 @@ pub enum Error {
 
     #[error(transparent)]
     Restore(#[from] restore::Error),
+
+    #[error(transparent)]
+    Verify(#[from] verify::Error),
 }
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match &self {
-            Cmd::Asset(asset) => asset.run().await?,
-            Cmd::Bindings(bindings) => bindings.run().await?,
+            Cmd::Asset(asset) => asset.run(global_args).await?,
+            Cmd::Bindings(bindings) => bindings.run(global_args).await?,
             Cmd::Build(build) => build.run(global_args)?,
-            Cmd::Extend(extend) => extend.run().await?,
+            Cmd::Extend(extend) => extend.run(global_args).await?,
             Cmd::Alias(alias) => alias.run(global_args)?,
             Cmd::Deploy(deploy) => deploy.run(global_args).await?,
-            Cmd::Id(id) => id.run()?,
+            Cmd::Id(id) => id.run(global_args)?,
             Cmd::Info(info) => info.run(global_args).await?,
             Cmd::Init(init) => init.run(global_args)?,
             Cmd::Inspect(inspect) => inspect.run(global_args)?,
             Cmd::Install(install) => install.run(global_args).await?,
             Cmd::Invoke(invoke) => invoke.run(global_args).await?,
-            Cmd::Optimize(optimize) => optimize.run()?,
-            Cmd::Fetch(fetch) => fetch.run().await?,
-            Cmd::Read(read) => read.run().await?,
-            Cmd::Restore(restore) => restore.run().await?,
+            Cmd::Optimize(optimize) => optimize.run(global_args)?,
+            Cmd::Fetch(fetch) => fetch.run(global_args).await?,
+            Cmd::Read(read) => read.run(global_args).await?,
+            Cmd::Restore(restore) => restore.run(global_args).await?,
+            Cmd::Verify(verify) => verify.run(global_args).await?,
         }
         Ok(())
     }
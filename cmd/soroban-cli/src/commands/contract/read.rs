@@ -1,18 +1,13 @@
-use std::{
-    fmt::Debug,
-    io::{self, stdout},
-};
+use std::{fmt::Debug, io};
 
-use crate::xdr::{
-    ContractDataEntry, Error as XdrError, LedgerEntryData, LedgerKey, LedgerKeyContractData,
-    Limits, ScVal, WriteXdr,
-};
+use crate::xdr::{ContractDataEntry, Error as XdrError, LedgerEntryData, Limits, ScVal, WriteXdr};
 use clap::{command, Parser, ValueEnum};
 
 use crate::{
     commands::{global, NetworkRunnable},
     config::{self, locator},
     key,
+    print::Print,
     rpc::{self, FullLedgerEntries, FullLedgerEntry},
 };
 
@@ -24,6 +19,12 @@ pub struct Cmd {
     pub output: Output,
     #[command(flatten)]
     pub key: key::Args,
+    /// Maximum number of entries to read, the position to continue from with `--cursor` is printed if there are more
+    #[arg(long)]
+    pub limit: Option<usize>,
+    /// Position to continue reading from, as printed by a previous read with `--limit`
+    #[arg(long)]
+    pub cursor: Option<usize>,
     #[command(flatten)]
     config: config::ArgsLocatorAndNetwork,
 }
@@ -36,6 +37,10 @@ pub enum Output {
     Json,
     /// XDR
     Xdr,
+    /// Comma separated values with a header row, values containing commas are quoted
+    Csv,
+    /// Table with a header row and aligned columns
+    Table,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -87,83 +92,142 @@ pub enum Error {
 }
 
 impl Cmd {
-    pub async fn run(&self) -> Result<(), Error> {
-        let entries = self.run_against_rpc_server(None, None).await?;
-        self.output_entries(&entries)
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        config::network::ensure_online(Some(global_args))?;
+        self.config.resolve_network(Some(global_args)).await?;
+        let print = Print::from(global_args);
+        let (entries, next_cursor) = self.read_page().await?;
+        self.output_entries(&entries, &print)?;
+        if let Some(cursor) = next_cursor {
+            print.infoln(format!(
+                "More entries are available, continue with `--cursor {cursor}`"
+            ));
+        }
+        Ok(())
     }
 
-    fn output_entries(&self, entries: &FullLedgerEntries) -> Result<(), Error> {
+    fn output_entries(&self, entries: &FullLedgerEntries, print: &Print) -> Result<(), Error> {
         if entries.entries.is_empty() {
             return Err(Error::NoContractDataEntryFoundForContractID);
         }
         tracing::trace!("{entries:#?}");
-        let mut out = csv::Writer::from_writer(stdout());
-        for FullLedgerEntry {
-            key,
+        let rows = entries
+            .entries
+            .iter()
+            .map(|entry| self.row(entry))
+            .collect::<Result<Vec<_>, _>>()?;
+        match self.output {
+            Output::Table => {
+                print_table(&rows, print);
+                Ok(())
+            }
+            Output::Csv => write_csv(print.stdout(), Some(HEADER), &rows),
+            Output::String | Output::Json | Output::Xdr => write_csv(print.stdout(), None, &rows),
+        }
+    }
+
+    /// The key, value, last modified ledger and live until ledger of a contract data entry,
+    /// formatted for `--output`.
+    fn row(&self, entry: &FullLedgerEntry) -> Result<[String; 4], Error> {
+        let FullLedgerEntry {
             val,
             live_until_ledger_seq,
             last_modified_ledger,
-        } in &entries.entries
-        {
-            let (
-                LedgerKey::ContractData(LedgerKeyContractData { key, .. }),
-                LedgerEntryData::ContractData(ContractDataEntry { val, .. }),
-            ) = &(key, val)
-            else {
-                return Err(Error::OnlyDataAllowed);
-            };
-            let output = match self.output {
-                Output::String => [
-                    soroban_spec_tools::to_string(key).map_err(|e| Error::CannotPrintResult {
+            ..
+        } = entry;
+        let LedgerEntryData::ContractData(ContractDataEntry { key, val, .. }) = val else {
+            return Err(Error::OnlyDataAllowed);
+        };
+        Ok(match self.output {
+            Output::String | Output::Csv | Output::Table => [
+                soroban_spec_tools::to_string(key).map_err(|e| Error::CannotPrintResult {
+                    result: key.clone(),
+                    error: e,
+                })?,
+                soroban_spec_tools::to_string(val).map_err(|e| Error::CannotPrintResult {
+                    result: val.clone(),
+                    error: e,
+                })?,
+                last_modified_ledger.to_string(),
+                live_until_ledger_seq.to_string(),
+            ],
+            Output::Json => [
+                serde_json::to_string_pretty(&key).map_err(|error| {
+                    Error::CannotPrintJsonResult {
                         result: key.clone(),
-                        error: e,
-                    })?,
-                    soroban_spec_tools::to_string(val).map_err(|e| Error::CannotPrintResult {
+                        error,
+                    }
+                })?,
+                serde_json::to_string_pretty(&val).map_err(|error| {
+                    Error::CannotPrintJsonResult {
+                        result: val.clone(),
+                        error,
+                    }
+                })?,
+                serde_json::to_string_pretty(&last_modified_ledger).map_err(|error| {
+                    Error::CannotPrintJsonResult {
                         result: val.clone(),
-                        error: e,
-                    })?,
-                    last_modified_ledger.to_string(),
-                    live_until_ledger_seq.to_string(),
-                ],
-                Output::Json => [
-                    serde_json::to_string_pretty(&key).map_err(|error| {
-                        Error::CannotPrintJsonResult {
-                            result: key.clone(),
-                            error,
-                        }
-                    })?,
-                    serde_json::to_string_pretty(&val).map_err(|error| {
-                        Error::CannotPrintJsonResult {
-                            result: val.clone(),
-                            error,
-                        }
-                    })?,
-                    serde_json::to_string_pretty(&last_modified_ledger).map_err(|error| {
-                        Error::CannotPrintJsonResult {
-                            result: val.clone(),
-                            error,
-                        }
-                    })?,
-                    serde_json::to_string_pretty(&live_until_ledger_seq).map_err(|error| {
-                        Error::CannotPrintJsonResult {
-                            result: val.clone(),
-                            error,
-                        }
-                    })?,
-                ],
-                Output::Xdr => [
-                    key.to_xdr_base64(Limits::none())?,
-                    val.to_xdr_base64(Limits::none())?,
-                    last_modified_ledger.to_xdr_base64(Limits::none())?,
-                    live_until_ledger_seq.to_xdr_base64(Limits::none())?,
-                ],
-            };
-            out.write_record(output)
-                .map_err(|e| Error::CannotPrintAsCsv { error: e })?;
+                        error,
+                    }
+                })?,
+                serde_json::to_string_pretty(&live_until_ledger_seq).map_err(|error| {
+                    Error::CannotPrintJsonResult {
+                        result: val.clone(),
+                        error,
+                    }
+                })?,
+            ],
+            Output::Xdr => [
+                key.to_xdr_base64(Limits::none())?,
+                val.to_xdr_base64(Limits::none())?,
+                last_modified_ledger.to_xdr_base64(Limits::none())?,
+                live_until_ledger_seq.to_xdr_base64(Limits::none())?,
+            ],
+        })
+    }
+}
+
+const HEADER: [&str; 4] = [
+    "key",
+    "value",
+    "last_modified_ledger",
+    "live_until_ledger_seq",
+];
+
+/// Writes the rows as CSV records, quoting values that contain commas, quotes or newlines.
+fn write_csv(
+    writer: impl io::Write,
+    header: Option<[&str; 4]>,
+    rows: &[[String; 4]],
+) -> Result<(), Error> {
+    let mut out = csv::Writer::from_writer(writer);
+    if let Some(header) = header {
+        out.write_record(header)
+            .map_err(|e| Error::CannotPrintAsCsv { error: e })?;
+    }
+    for row in rows {
+        out.write_record(row)
+            .map_err(|e| Error::CannotPrintAsCsv { error: e })?;
+    }
+    out.flush()
+        .map_err(|e| Error::CannotPrintFlush { error: e })?;
+    Ok(())
+}
+
+fn print_table(rows: &[[String; 4]], print: &Print) {
+    let mut widths = HEADER.map(str::len);
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.len());
         }
-        out.flush()
-            .map_err(|e| Error::CannotPrintFlush { error: e })?;
-        Ok(())
+    }
+    let header = HEADER.map(String::from);
+    for row in std::iter::once(&header).chain(rows) {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{value:<width$}"));
+        print.outln(cells.collect::<Vec<_>>().join("  ").trim_end());
     }
 }
 
@@ -174,15 +238,82 @@ impl NetworkRunnable for Cmd {
 
     async fn run_against_rpc_server(
         &self,
-        _global_args: Option<&global::Args>,
+        global_args: Option<&global::Args>,
         _config: Option<&config::Args>,
     ) -> Result<FullLedgerEntries, Error> {
+        config::network::ensure_online(global_args)?;
+        self.config.resolve_network(global_args).await?;
+        Ok(self.read_page().await?.0)
+    }
+}
+
+impl Cmd {
+    /// Reads the page of entries selected by `--cursor` and `--limit`, and returns it with the
+    /// cursor of the next page, if there is one. Keys are paged before they are fetched, so that
+    /// each page is a single `getLedgerEntries` request.
+    async fn read_page(&self) -> Result<(FullLedgerEntries, Option<usize>), Error> {
         let locator = self.config.locator.clone();
         let network = self.config.network.get(&locator)?;
 
         tracing::trace!(?network);
         let client = network.rpc_client()?;
         let keys = self.key.parse_keys(&locator, &network)?;
-        Ok(client.get_full_ledger_entries(&keys).await?)
+        let (keys, next_cursor) = page(keys, self.cursor, self.limit);
+        let entries = network
+            .timeout(client.get_full_ledger_entries(&keys))
+            .await??;
+        Ok((entries, next_cursor))
+    }
+}
+
+/// Returns the items from `cursor` on, at most `limit` of them, and the cursor of the next page if
+/// there are items left after it.
+fn page<T>(items: Vec<T>, cursor: Option<usize>, limit: Option<usize>) -> (Vec<T>, Option<usize>) {
+    let start = cursor.unwrap_or_default();
+    let end = limit.map_or(items.len(), |limit| {
+        start.saturating_add(limit).min(items.len())
+    });
+    let next_cursor = (end < items.len()).then_some(end);
+    let page = items
+        .into_iter()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect();
+    (page, next_cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_advances_across_pages() {
+        let items = (0..5).collect::<Vec<_>>();
+        let (first, cursor) = page(items.clone(), None, Some(2));
+        assert_eq!(first, [0, 1]);
+        assert_eq!(cursor, Some(2));
+        let (second, cursor) = page(items.clone(), cursor, Some(2));
+        assert_eq!(second, [2, 3]);
+        assert_eq!(cursor, Some(4));
+        let (last, cursor) = page(items.clone(), cursor, Some(2));
+        assert_eq!(last, [4]);
+        assert_eq!(cursor, None);
+        assert_eq!(page(items.clone(), None, None), (items, None));
+    }
+
+    #[test]
+    fn csv_quotes_values_with_commas() {
+        let rows = [[
+            "COUNTER".to_string(),
+            "a,b".to_string(),
+            "1".to_string(),
+            "4096".to_string(),
+        ]];
+        let mut out = Vec::new();
+        write_csv(&mut out, Some(HEADER), &rows).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "key,value,last_modified_ledger,live_until_ledger_seq\nCOUNTER,\"a,b\",1,4096\n"
+        );
     }
 }
@@ -3,8 +3,8 @@ use std::{fmt::Debug, path::Path, str::FromStr};
 use crate::xdr::{
     Error as XdrError, ExtensionPoint, LedgerEntry, LedgerEntryChange, LedgerEntryData,
     LedgerFootprint, Limits, Memo, Operation, OperationBody, OperationMeta, Preconditions,
-    RestoreFootprintOp, SequenceNumber, SorobanResources, SorobanTransactionData, Transaction,
-    TransactionExt, TransactionMeta, TransactionMetaV3, TtlEntry, WriteXdr,
+    RestoreFootprintOp, SequenceNumber, Transaction, TransactionExt, TransactionMeta,
+    TransactionMetaV3, TtlEntry, WriteXdr,
 };
 use clap::{command, Parser};
 use stellar_strkey::DecodeError;
@@ -17,7 +17,11 @@ use crate::{
         NetworkRunnable,
     },
     config::{self, data, locator, network},
-    key, rpc, wasm, Pwd,
+    key,
+    print::Print,
+    rpc,
+    tx::submit::{self, assemble_sign_submit, sign_submit},
+    utils, wasm, Pwd,
 };
 
 #[derive(Parser, Debug, Clone)]
@@ -87,15 +91,23 @@ pub enum Error {
     Data(#[from] data::Error),
     #[error(transparent)]
     Network(#[from] network::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Submit(#[from] submit::Error),
 }
 
 impl Cmd {
     #[allow(clippy::too_many_lines)]
-    pub async fn run(&self) -> Result<(), Error> {
-        let res = self.run_against_rpc_server(None, None).await?.to_envelope();
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let res = self
+            .run_against_rpc_server(Some(global_args), None)
+            .await?
+            .to_envelope();
+        let print = Print::from(global_args);
         let expiration_ledger_seq = match res {
             TxnEnvelopeResult::TxnEnvelope(tx) => {
-                println!("{}", tx.to_xdr_base64(Limits::none())?);
+                print.outln(tx.to_xdr_base64(Limits::none())?);
                 return Ok(());
             }
             TxnEnvelopeResult::Res(res) => res,
@@ -108,10 +120,10 @@ impl Cmd {
                 fee: self.fee.clone(),
                 ttl_ledger_only: false,
             }
-            .run()
+            .run(global_args)
             .await?;
         } else {
-            println!("New ttl ledger: {expiration_ledger_seq}");
+            print.outln(format!("New ttl ledger: {expiration_ledger_seq}"));
         }
 
         Ok(())
@@ -128,22 +140,27 @@ impl NetworkRunnable for Cmd {
         args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<TxnResult<u32>, Error> {
+        network::ensure_online(args)?;
         let config = config.unwrap_or(&self.config);
-        let network = config.get_network()?;
+        let network = config.resolve_network(args).await?;
         tracing::trace!(?network);
         let entry_keys = self.key.parse_keys(&config.locator, &network)?;
         let client = network.rpc_client()?;
         let source_account = config.source_account()?;
 
         // Get the account sequence number
-        let account_details = client
-            .get_account(&source_account.clone().to_string())
-            .await?;
+        let account_details = network
+            .timeout(client.get_account(&source_account.clone().to_string()))
+            .await??;
         let sequence: i64 = account_details.seq_num.into();
 
+        let (fee, transaction_data) = self.fee.soroban_transaction_data(LedgerFootprint {
+            read_only: vec![].try_into()?,
+            read_write: entry_keys.try_into()?,
+        });
         let tx = Box::new(Transaction {
             source_account,
-            fee: self.fee.fee,
+            fee,
             seq_num: SequenceNumber(sequence + 1),
             cond: Preconditions::None,
             memo: Memo::None,
@@ -154,26 +171,20 @@ impl NetworkRunnable for Cmd {
                 }),
             }]
             .try_into()?,
-            ext: TransactionExt::V1(SorobanTransactionData {
-                ext: ExtensionPoint::V0,
-                resources: SorobanResources {
-                    footprint: LedgerFootprint {
-                        read_only: vec![].try_into()?,
-                        read_write: entry_keys.try_into()?,
-                    },
-                    instructions: self.fee.instructions.unwrap_or_default(),
-                    read_bytes: 0,
-                    write_bytes: 0,
-                },
-                resource_fee: 0,
-            }),
+            ext: TransactionExt::V1(transaction_data),
         });
         if self.fee.build_only {
             return Ok(TxnResult::Txn(tx));
         }
-        let res = client
-            .send_transaction_polling(&config.sign_with_local_key(*tx).await?)
-            .await?;
+        let signer = config.signer(Print::new(args.map_or(false, |a| a.quiet)))?;
+        let res = if self.fee.has_manual_resources() {
+            sign_submit(&client, *tx, &signer, &self.fee, &network).await?
+        } else {
+            match assemble_sign_submit(&client, &tx, &signer, &self.fee, &network).await? {
+                TxnResult::Txn(tx) => return Ok(TxnResult::Txn(tx)),
+                TxnResult::Res(res) => res,
+            }
+        };
         if args.map_or(true, |a| !a.no_cache) {
             data::write(res.clone().try_into()?, &network.rpc_uri()?)?;
         }
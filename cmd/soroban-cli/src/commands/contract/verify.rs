@@ -0,0 +1,55 @@
+use clap::{arg, command, Parser};
+
+use crate::{
+    commands::global,
+    config::{self, locator, network},
+    print::Print,
+    wasm,
+};
+
+#[derive(Parser, Debug, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    /// Contract ID/alias whose deployed code is compared against the local wasm
+    #[arg(long = "id", env = "STELLAR_CONTRACT_ID")]
+    pub contract_id: config::UnresolvedContract,
+    #[command(flatten)]
+    pub wasm: wasm::Args,
+    #[command(flatten)]
+    pub locator: locator::Args,
+    #[command(flatten)]
+    pub network: network::Args,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Locator(#[from] locator::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+    #[error(transparent)]
+    Wasm(#[from] wasm::Error),
+}
+
+impl Cmd {
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        network::ensure_online(Some(global_args))?;
+        let print = Print::from(global_args);
+        let network = self
+            .network
+            .resolve(&self.locator, Some(global_args))
+            .await?;
+        let contract_id = self
+            .contract_id
+            .resolve_contract_id(&self.locator, &network.network_passphrase)?;
+        let local = self.wasm.read()?;
+        let remote = wasm::fetch_from_contract(&contract_id, &network).await?;
+        let hash = wasm::verify(&local, &remote)?;
+        print.checkln(format!(
+            "{} matches the code deployed for {contract_id}",
+            self.wasm.wasm.display()
+        ));
+        print.outln(hex::encode(hash.0));
+        Ok(())
+    }
+}
@@ -19,7 +19,7 @@ pub enum Error {
 
 impl Cmd {
     pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let print = Print::new(global_args.quiet);
+        let print = Print::from(global_args);
         let mut lines: Vec<(String, String)> = Vec::new();
 
         if let Some(data) = get("STELLAR_NETWORK") {
@@ -40,7 +40,7 @@ impl Cmd {
         lines.sort();
 
         for (value, source) in lines {
-            println!("{value:max_len$} # {source}");
+            print.outln(format!("{value:max_len$} # {source}"));
         }
 
         Ok(())
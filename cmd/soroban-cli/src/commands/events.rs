@@ -6,6 +6,7 @@ use crate::xdr::{self, Limits, ReadXdr};
 use super::{global, NetworkRunnable};
 use crate::{
     config::{self, locator, network},
+    print::Print,
     rpc,
 };
 
@@ -134,7 +135,7 @@ pub enum OutputFormat {
 }
 
 impl Cmd {
-    pub async fn run(&mut self) -> Result<(), Error> {
+    pub async fn run(&mut self, global_args: &global::Args) -> Result<(), Error> {
         // Validate that topics are made up of segments.
         for topic in &self.topic_filters {
             for (i, segment) in topic.split(',').enumerate() {
@@ -156,7 +157,8 @@ impl Cmd {
             }
         }
 
-        let response = self.run_against_rpc_server(None, None).await?;
+        let response = self.run_against_rpc_server(Some(global_args), None).await?;
+        let print = Print::from(global_args);
 
         for event in &response.events {
             match self.output {
@@ -164,17 +166,15 @@ impl Cmd {
                 // dump an event in raw JSON on each line? The latter is easier
                 // to consume programmatically.
                 OutputFormat::Json => {
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&event).map_err(|e| {
-                            Error::InvalidJson {
-                                debug: format!("{event:#?}"),
-                                error: e,
-                            }
-                        })?,
-                    );
+                    print.outln(serde_json::to_string_pretty(&event).map_err(|e| {
+                        Error::InvalidJson {
+                            debug: format!("{event:#?}"),
+                            error: e,
+                        }
+                    })?);
                 }
-                OutputFormat::Plain => println!("{event}"),
+                OutputFormat::Plain => print.outln(event),
+                OutputFormat::Pretty if print.quiet_success => {}
                 OutputFormat::Pretty => event.pretty_print()?,
             }
         }
@@ -199,20 +199,21 @@ impl NetworkRunnable for Cmd {
 
     async fn run_against_rpc_server(
         &self,
-        _args: Option<&global::Args>,
+        global_args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<rpc::GetEventsResponse, Error> {
+        network::ensure_online(global_args)?;
         let start = self.start()?;
         let network = if let Some(config) = config {
-            Ok(config.get_network()?)
+            config.resolve_network(global_args).await?
         } else {
-            self.network.get(&self.locator)
-        }?;
+            self.network.resolve(&self.locator, global_args).await?
+        };
 
         let client = network.rpc_client()?;
-        client
-            .verify_network_passphrase(Some(&network.network_passphrase))
-            .await?;
+        network
+            .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+            .await??;
 
         let contract_ids: Vec<String> = self
             .contract_ids
@@ -224,15 +225,15 @@ impl NetworkRunnable for Cmd {
             })
             .collect::<Result<Vec<_>, Error>>()?;
 
-        Ok(client
-            .get_events(
+        Ok(network
+            .timeout(client.get_events(
                 start,
                 Some(self.event_type),
                 &contract_ids,
                 &self.topic_filters,
                 Some(self.count),
-            )
-            .await
+            ))
+            .await?
             .map_err(Error::Rpc)?)
     }
 }
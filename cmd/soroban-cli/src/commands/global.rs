@@ -31,6 +31,10 @@ pub struct Args {
     #[arg(long, short = 'q', global = true, help_heading = HEADING_GLOBAL)]
     pub quiet: bool,
 
+    /// Do not write anything to stdout. Errors are still written to stderr, so that only the exit code tells whether the command succeeded
+    #[arg(long, global = true, help_heading = HEADING_GLOBAL)]
+    pub quiet_success: bool,
+
     /// Log DEBUG events
     #[arg(long, short = 'v', global = true, help_heading = HEADING_GLOBAL)]
     pub verbose: bool,
@@ -46,6 +50,10 @@ pub struct Args {
     /// Do not cache your simulations and transactions
     #[arg(long, env = "STELLAR_NO_CACHE", global = true, help_heading = HEADING_GLOBAL)]
     pub no_cache: bool,
+
+    /// Forbid all network requests. Commands that need to reach the RPC server or another network service fail instead
+    #[arg(long, env = "STELLAR_OFFLINE", global = true, help_heading = HEADING_GLOBAL)]
+    pub offline: bool,
 }
 
 #[derive(thiserror::Error, Debug)]
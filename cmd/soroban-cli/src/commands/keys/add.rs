@@ -24,6 +24,10 @@ pub struct Cmd {
     #[command(flatten)]
     pub secrets: secret::Args,
 
+    /// Encrypt the secret with a passphrase before saving it. The passphrase is read from `STELLAR_SECRET_PASSPHRASE` or prompted for, and is needed whenever the identity is used
+    #[arg(long)]
+    pub encrypt: bool,
+
     #[command(flatten)]
     pub config_locator: locator::Args,
 }
@@ -31,7 +35,10 @@ pub struct Cmd {
 impl Cmd {
     pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let print = Print::new(global_args.quiet);
-        let secret = self.secrets.read_secret()?;
+        let mut secret = self.secrets.read_secret()?;
+        if self.encrypt {
+            secret = secret.encrypt(&secret::read_new_passphrase()?)?;
+        }
         let path = self.config_locator.write_identity(&self.name, &secret)?;
         print.checkln(format!("Key saved with alias {:?} in {path:?}", self.name));
         Ok(())
@@ -1,4 +1,5 @@
-use crate::commands::config::secret;
+use crate::commands::{config::secret, global};
+use crate::print::Print;
 
 use super::super::config::locator;
 use clap::arg;
@@ -30,8 +31,8 @@ pub struct Cmd {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
-        println!("{}", self.public_key()?);
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        Print::from(global_args).outln(self.public_key()?);
         Ok(())
     }
 
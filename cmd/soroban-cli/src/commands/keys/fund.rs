@@ -24,9 +24,13 @@ pub struct Cmd {
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        network::ensure_online(Some(global_args))?;
         let print = Print::new(global_args.quiet);
         let addr = self.address.public_key()?;
-        let network = self.network.get(&self.address.locator)?;
+        let network = self
+            .network
+            .resolve(&self.address.locator, Some(global_args))
+            .await?;
         network.fund_address(&addr).await?;
         print.checkln(format!(
             "Account {:?} funded on {:?}",
@@ -61,11 +61,40 @@ pub struct Cmd {
     /// Overwrite existing identity if it already exists.
     #[arg(long)]
     pub overwrite: bool,
+
+    /// Print the address (public key) of the generated identity at `--hd-path` to stdout.
+    #[arg(long)]
+    pub print_address: bool,
+
+    /// Only print the address of the identity that would be generated, and its secret key when
+    /// used with `--as-secret`, without saving or funding it.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let print = Print::new(global_args.quiet);
+        let print = Print::from(global_args);
+
+        let seed_phrase = if self.default_seed {
+            Secret::test_seed_phrase()
+        } else {
+            Secret::from_seed(self.seed.as_deref())
+        }?;
+
+        let secret = if self.as_secret {
+            seed_phrase.private_key(self.hd_path)?.into()
+        } else {
+            seed_phrase
+        };
+
+        if self.dry_run {
+            print.outln(secret.public_key(self.hd_path)?);
+            if self.as_secret {
+                print.outln(secret.private_key(self.hd_path)?);
+            }
+            return Ok(());
+        }
 
         if self.config_locator.read_identity(&self.name).is_ok() {
             if !self.overwrite {
@@ -84,22 +113,14 @@ impl Cmd {
             );
         }
 
-        let seed_phrase = if self.default_seed {
-            Secret::test_seed_phrase()
-        } else {
-            Secret::from_seed(self.seed.as_deref())
-        }?;
-
-        let secret = if self.as_secret {
-            seed_phrase.private_key(self.hd_path)?.into()
-        } else {
-            seed_phrase
-        };
-
         let path = self.config_locator.write_identity(&self.name, &secret)?;
         print.checkln(format!("Key saved with alias {:?} in {path:?}", self.name));
+        if self.print_address {
+            print.outln(secret.public_key(self.hd_path)?);
+        }
 
-        if !self.no_fund {
+        // Funding needs the network, so it is skipped with `--offline`
+        if !self.no_fund && !global_args.offline {
             let addr = secret.public_key(self.hd_path)?;
             let network = self.network.get(&self.config_locator)?;
             network
@@ -7,6 +7,7 @@ pub mod default;
 pub mod fund;
 pub mod generate;
 pub mod ls;
+pub mod resolve;
 pub mod rm;
 pub mod secret;
 
@@ -27,6 +28,9 @@ pub enum Cmd {
     /// List identities
     Ls(ls::Cmd),
 
+    /// Show how a name resolves to an account, and whether it came from an identity, a secret or a literal address
+    Resolve(resolve::Cmd),
+
     /// Remove an identity
     Rm(rm::Cmd),
 
@@ -54,6 +58,9 @@ pub enum Error {
     #[error(transparent)]
     Generate(#[from] generate::Error),
 
+    #[error(transparent)]
+    Resolve(#[from] resolve::Error),
+
     #[error(transparent)]
     Rm(#[from] rm::Error),
 
@@ -71,12 +78,13 @@ impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match self {
             Cmd::Add(cmd) => cmd.run(global_args)?,
-            Cmd::Address(cmd) => cmd.run()?,
+            Cmd::Address(cmd) => cmd.run(global_args)?,
             Cmd::Fund(cmd) => cmd.run(global_args).await?,
             Cmd::Generate(cmd) => cmd.run(global_args).await?,
-            Cmd::Ls(cmd) => cmd.run()?,
+            Cmd::Ls(cmd) => cmd.run(global_args)?,
+            Cmd::Resolve(cmd) => cmd.run(global_args)?,
             Cmd::Rm(cmd) => cmd.run()?,
-            Cmd::Secret(cmd) => cmd.run()?,
+            Cmd::Secret(cmd) => cmd.run(global_args)?,
             Cmd::Default(cmd) => cmd.run(global_args)?,
         };
         Ok(())
@@ -0,0 +1,34 @@
+use clap::arg;
+
+use crate::{
+    commands::global,
+    config::{address, locator, UnresolvedMuxedAccount},
+    print::Print,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Address(#[from] address::Error),
+}
+
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    /// Identity name, secret key, seed phrase or address to resolve
+    pub name: UnresolvedMuxedAccount,
+
+    /// If the name resolves to a seed phrase use this hd path, default is 0
+    #[arg(long)]
+    pub hd_path: Option<usize>,
+
+    #[command(flatten)]
+    pub locator: locator::Args,
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        Print::from(global_args).outln(self.name.describe(&self.locator, self.hd_path)?);
+        Ok(())
+    }
+}
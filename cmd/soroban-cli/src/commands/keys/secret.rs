@@ -1,6 +1,10 @@
 use clap::arg;
 
-use crate::config::{locator, secret};
+use crate::{
+    commands::global,
+    config::{locator, secret},
+    print::Print,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -30,8 +34,8 @@ pub struct Cmd {
 }
 
 impl Cmd {
-    pub fn run(&self) -> Result<(), Error> {
-        println!("{}", self.private_key()?.to_string());
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        Print::from(global_args).outln(self.private_key()?.to_string());
         Ok(())
     }
 
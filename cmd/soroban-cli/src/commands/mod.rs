@@ -6,6 +6,7 @@ use clap::{command, error::ErrorKind, CommandFactory, FromArgMatches, Parser};
 use crate::config;
 
 pub mod cache;
+pub mod cfg;
 pub mod completion;
 pub mod container;
 pub mod contract;
@@ -109,17 +110,18 @@ impl Root {
     }
     pub async fn run(&mut self) -> Result<(), Error> {
         match &mut self.cmd {
-            Cmd::Completion(completion) => completion.run(),
+            Cmd::Completion(completion) => completion.run(&self.global_args),
             Cmd::Contract(contract) => contract.run(&self.global_args).await?,
-            Cmd::Events(events) => events.run().await?,
+            Cmd::Events(events) => events.run(&self.global_args).await?,
             Cmd::Xdr(xdr) => xdr.run()?,
             Cmd::Network(network) => network.run(&self.global_args).await?,
             Cmd::Container(container) => container.run(&self.global_args).await?,
             Cmd::Snapshot(snapshot) => snapshot.run(&self.global_args).await?,
-            Cmd::Version(version) => version.run(),
+            Cmd::Version(version) => version.run(&self.global_args),
             Cmd::Keys(id) => id.run(&self.global_args).await?,
             Cmd::Tx(tx) => tx.run(&self.global_args).await?,
-            Cmd::Cache(cache) => cache.run()?,
+            Cmd::Cache(cache) => cache.run(&self.global_args)?,
+            Cmd::Config(config) => config.run(&self.global_args)?,
             Cmd::Env(env) => env.run(&self.global_args)?,
         };
         Ok(())
@@ -182,6 +184,10 @@ pub enum Cmd {
     #[command(subcommand)]
     Cache(cache::Cmd),
 
+    /// Inspect and migrate the CLI configuration
+    #[command(subcommand)]
+    Config(cfg::Cmd),
+
     /// Print version information
     Version(version::Cmd),
 }
@@ -222,10 +228,17 @@ pub enum Error {
     #[error(transparent)]
     Cache(#[from] cache::Error),
 
+    #[error(transparent)]
+    Config(#[from] cfg::Error),
+
     #[error(transparent)]
     Env(#[from] env::Error),
 }
 
+/// A command that can be run against an RPC server.
+///
+/// Implementations call [`config::network::ensure_online`] with the `global_args` before making
+/// any request, so `--offline` is respected when commands are run as a library too.
 #[async_trait]
 pub trait NetworkRunnable {
     type Error;
@@ -99,7 +99,7 @@ impl Cmd {
             Cmd::Default(cmd) => cmd.run(global_args)?,
             Cmd::Add(cmd) => cmd.run()?,
             Cmd::Rm(new) => new.run()?,
-            Cmd::Ls(cmd) => cmd.run()?,
+            Cmd::Ls(cmd) => cmd.run(global_args)?,
             Cmd::Container(cmd) => cmd.run(global_args).await?,
 
             // TODO Remove this once `network start` is removed
@@ -109,7 +109,7 @@ impl Cmd {
             }
             // TODO Remove this once `network stop` is removed
             Cmd::Stop(cmd) => {
-                println!(
+                eprintln!(
                     "⚠️ Warning: `network stop` has been deprecated. Use `container stop` instead"
                 );
                 cmd.run(global_args).await?;
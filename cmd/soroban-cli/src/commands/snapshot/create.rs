@@ -4,13 +4,12 @@ use clap::{arg, Parser, ValueEnum};
 use futures::StreamExt;
 use humantime::format_duration;
 use itertools::{Either, Itertools};
-use sha2::{Digest, Sha256};
 use soroban_ledger_snapshot::LedgerSnapshot;
 use std::{
     collections::HashSet,
     fs,
     io::{self},
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
     time::{Duration, Instant},
 };
@@ -32,7 +31,7 @@ use crate::{
     config::{self, locator, network::passphrase},
     print,
     tx::builder,
-    utils::get_name_from_stellar_asset_contract_storage,
+    utils::{get_name_from_stellar_asset_contract_storage, NetworkId},
 };
 use crate::{config::address::UnresolvedMuxedAccount, utils::http};
 
@@ -92,6 +91,9 @@ pub struct Cmd {
     /// Archive URL
     #[arg(long, help_heading = HEADING_RPC, env = "STELLAR_ARCHIVE_URL")]
     archive_url: Option<Url>,
+    /// Maximum number of buckets to download from the archive at the same time.
+    #[arg(long, default_value = "8", value_parser = clap::value_parser!(u16).range(1..))]
+    concurrency: u16,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -154,12 +156,21 @@ impl Cmd {
         let print = print::Print::new(global_args.quiet);
         let start = Instant::now();
 
+        config::network::ensure_online(Some(global_args))?;
+        if self.archive_url.is_none() {
+            // Fetches the passphrase for `--auto-passphrase`, which the archive url is guessed
+            // from. Like other failures to resolve the network, errors only leave it unguessed.
+            self.network
+                .resolve(&self.locator, Some(global_args))
+                .await
+                .ok();
+        }
         let archive_url = self.archive_url()?;
         let history = get_history(&print, &archive_url, self.ledger).await?;
 
         let ledger = history.current_ledger;
         let network_passphrase = &history.network_passphrase;
-        let network_id = Sha256::digest(network_passphrase);
+        let Hash(network_id) = NetworkId::new(network_passphrase).into();
 
         print.infoln(format!("Ledger: {ledger}"));
         print.infoln(format!("Network Passphrase: {network_passphrase}"));
@@ -175,9 +186,7 @@ impl Cmd {
             .collect::<Vec<_>>();
 
         // Pre-cache the buckets.
-        for (i, bucket) in buckets.iter().enumerate() {
-            cache_bucket(&print, &archive_url, i, bucket).await?;
-        }
+        cache_buckets(&print, &archive_url, &buckets, self.concurrency.into()).await?;
 
         // The snapshot is what will be written to file at the end. Fields will
         // be updated while parsing the history archive.
@@ -186,7 +195,7 @@ impl Cmd {
             protocol_version: 0,
             sequence_number: ledger,
             timestamp: 0,
-            network_id: network_id.into(),
+            network_id,
             base_reserve: 1,
             min_persistent_entry_ttl: 0,
             min_temp_entry_ttl: 0,
@@ -243,9 +252,9 @@ impl Cmd {
             ));
 
             for (i, bucket) in buckets.iter().enumerate() {
-                // Defined where the bucket will be read from, either from cache on
-                // disk, or streamed from the archive.
-                let cache_path = cache_bucket(&print, &archive_url, i, bucket).await?;
+                // Buckets were all cached on disk above, so each is read from
+                // its cache file.
+                let cache_path = bucket_cache_path(bucket)?;
                 let file = std::fs::OpenOptions::new()
                     .read(true)
                     .open(&cache_path)
@@ -459,9 +468,7 @@ async fn get_history(
 
     print.globe(format!("Downloading history {history_url}"));
 
-    let response = http::client()
-        .get(history_url.as_str())
-        .send()
+    let response = http::get(history_url.as_str())
         .await
         .map_err(Error::DownloadingHistory)?;
 
@@ -493,66 +500,99 @@ async fn get_history(
     serde_json::from_slice::<History>(&body).map_err(Error::JsonDecodingHistory)
 }
 
-async fn cache_bucket(
+/// Downloads any buckets missing from the cache, with at most `concurrency`
+/// downloads in flight. Buckets are still read in order afterwards, so the
+/// snapshot is the same regardless of the order downloads complete in.
+///
+/// Downloads don't print anything themselves, a line is printed here as each
+/// one completes so that the output of concurrent downloads isn't interleaved.
+async fn cache_buckets(
     print: &print::Print,
     archive_url: &Url,
-    bucket_index: usize,
-    bucket: &str,
-) -> Result<PathBuf, Error> {
-    let bucket_dir = data::bucket_dir().map_err(Error::GetBucketDir)?;
-    let cache_path = bucket_dir.join(format!("bucket-{bucket}.xdr"));
-    if !cache_path.exists() {
-        let bucket_0 = &bucket[0..=1];
-        let bucket_1 = &bucket[2..=3];
-        let bucket_2 = &bucket[4..=5];
-        let bucket_url =
-            format!("{archive_url}/bucket/{bucket_0}/{bucket_1}/{bucket_2}/bucket-{bucket}.xdr.gz");
-
-        print.globe(format!("Downloading bucket {bucket_index} {bucket}…"));
-
-        let bucket_url = Url::from_str(&bucket_url).map_err(Error::ParsingBucketUrl)?;
-
-        let response = http::client()
-            .get(bucket_url.as_str())
-            .send()
-            .await
-            .map_err(Error::GettingBucket)?;
-
-        if !response.status().is_success() {
-            print.println("");
-            return Err(Error::GettingBucketGotStatusCode(response.status()));
+    buckets: &[String],
+    concurrency: usize,
+) -> Result<(), Error> {
+    let mut queued = HashSet::new();
+    let mut tasks = tokio::task::JoinSet::new();
+    for (i, bucket) in buckets.iter().enumerate() {
+        let cache_path = bucket_cache_path(bucket)?;
+        if cache_path.exists() || !queued.insert(bucket) {
+            continue;
         }
-
-        if let Some(len) = response.content_length() {
-            print.clear_line();
-            print.globe(format!(
-                "Downloaded bucket {bucket_index} {bucket} ({})",
-                ByteSize(len)
-            ));
+        if tasks.len() >= concurrency {
+            if let Some(res) = tasks.join_next().await {
+                print_downloaded(print, res??);
+            }
         }
+        let archive_url = archive_url.clone();
+        let bucket = bucket.clone();
+        tasks.spawn(async move {
+            let len = download_bucket(&archive_url, &bucket, &cache_path).await?;
+            Ok::<_, Error>((i, bucket, len))
+        });
+    }
+    while let Some(res) = tasks.join_next().await {
+        print_downloaded(print, res??);
+    }
+    Ok(())
+}
+
+fn print_downloaded(print: &print::Print, (i, bucket, len): (usize, String, Option<u64>)) {
+    let size = len.map(|len| format!(" ({})", ByteSize(len)));
+    print.globeln(format!(
+        "Downloaded bucket {i} {bucket}{}",
+        size.unwrap_or_default()
+    ));
+}
 
-        print.println("");
-
-        let stream = response
-            .bytes_stream()
-            .map(|result| result.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
-        let stream_reader = StreamReader::new(stream);
-        let buf_reader = BufReader::new(stream_reader);
-        let mut decoder = GzipDecoder::new(buf_reader);
-        let dl_path = cache_path.with_extension("dl");
-        let mut file = OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(&dl_path)
-            .await
-            .map_err(Error::WriteOpeningCachedBucket)?;
-        tokio::io::copy(&mut decoder, &mut file)
-            .await
-            .map_err(Error::StreamingBucket)?;
-        fs::rename(&dl_path, &cache_path).map_err(Error::RenameDownloadFile)?;
+fn bucket_cache_path(bucket: &str) -> Result<PathBuf, Error> {
+    let bucket_dir = data::bucket_dir().map_err(Error::GetBucketDir)?;
+    Ok(bucket_dir.join(format!("bucket-{bucket}.xdr")))
+}
+
+/// Downloads `bucket` to `cache_path`, returning its compressed size when the
+/// archive reports it.
+async fn download_bucket(
+    archive_url: &Url,
+    bucket: &str,
+    cache_path: &Path,
+) -> Result<Option<u64>, Error> {
+    let bucket_0 = &bucket[0..=1];
+    let bucket_1 = &bucket[2..=3];
+    let bucket_2 = &bucket[4..=5];
+    let bucket_url =
+        format!("{archive_url}/bucket/{bucket_0}/{bucket_1}/{bucket_2}/bucket-{bucket}.xdr.gz");
+
+    let bucket_url = Url::from_str(&bucket_url).map_err(Error::ParsingBucketUrl)?;
+
+    let response = http::get(bucket_url.as_str())
+        .await
+        .map_err(Error::GettingBucket)?;
+
+    if !response.status().is_success() {
+        return Err(Error::GettingBucketGotStatusCode(response.status()));
     }
-    Ok(cache_path)
+    let len = response.content_length();
+
+    let stream = response
+        .bytes_stream()
+        .map(|result| result.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
+    let stream_reader = StreamReader::new(stream);
+    let buf_reader = BufReader::new(stream_reader);
+    let mut decoder = GzipDecoder::new(buf_reader);
+    let dl_path = cache_path.with_extension("dl");
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(&dl_path)
+        .await
+        .map_err(Error::WriteOpeningCachedBucket)?;
+    tokio::io::copy(&mut decoder, &mut file)
+        .await
+        .map_err(Error::StreamingBucket)?;
+    fs::rename(&dl_path, cache_path).map_err(Error::RenameDownloadFile)?;
+    Ok(len)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
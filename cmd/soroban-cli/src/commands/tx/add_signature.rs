@@ -0,0 +1,89 @@
+use super::check_auth::verify_ed25519;
+use crate::{
+    commands::global,
+    config::network,
+    print::Print,
+    utils::envelope_hash,
+    xdr::{
+        self, DecoratedSignature, Limits, Signature, SignatureHint, TransactionEnvelope, WriteXdr,
+    },
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    XdrArgs(#[from] super::xdr::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+    #[error(transparent)]
+    Xdr(#[from] xdr::Error),
+    #[error("signature must be 64 bytes of hex: {0}")]
+    InvalidSignatureHex(String),
+    #[error("signature is not a valid signature by {key} of transaction {hash} for network passphrase {passphrase:?}")]
+    InvalidSignature {
+        key: stellar_strkey::ed25519::PublicKey,
+        hash: String,
+        passphrase: String,
+    },
+}
+
+/// Command to append a signature produced outside of the CLI, e.g. by a hardware wallet, to a
+/// transaction envelope from stdin
+/// e.g. `cat file.txt | stellar tx add-signature --public-key GDKW... --signature 5a1f...`
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    /// Public key of the account that produced the signature, e.g. `GDKW...`
+    #[arg(long)]
+    pub public_key: stellar_strkey::ed25519::PublicKey,
+    /// Hex encoded ed25519 signature of the transaction hash printed by `tx hash`
+    #[arg(long)]
+    pub signature: String,
+    #[command(flatten)]
+    pub network: network::Args,
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let tx_env = super::xdr::tx_envelope_from_stdin()?;
+        let network = self.network.get(&global_args.locator)?;
+        let tx_env = self.add_signature(tx_env, &network.network_passphrase)?;
+        Print::from(global_args).outln(tx_env.to_xdr_base64(Limits::none())?);
+        Ok(())
+    }
+
+    /// Verifies the signature against the hash of the envelope and appends it, hinted with the
+    /// last four bytes of the public key.
+    pub fn add_signature(
+        &self,
+        mut tx_env: TransactionEnvelope,
+        passphrase: &str,
+    ) -> Result<TransactionEnvelope, Error> {
+        let signature = hex::decode(&self.signature)
+            .ok()
+            .filter(|bytes| bytes.len() == 64)
+            .ok_or_else(|| Error::InvalidSignatureHex(self.signature.clone()))?;
+        let key = self.public_key.0;
+        let decorated = DecoratedSignature {
+            hint: SignatureHint([key[28], key[29], key[30], key[31]]),
+            signature: Signature(signature.try_into()?),
+        };
+        let hash = envelope_hash(&tx_env, passphrase)?;
+        if !verify_ed25519(&key, &hash, &decorated) {
+            return Err(Error::InvalidSignature {
+                key: stellar_strkey::ed25519::PublicKey(key),
+                hash: hex::encode(hash),
+                passphrase: passphrase.to_string(),
+            });
+        }
+        let signatures = match &mut tx_env {
+            TransactionEnvelope::TxV0(env) => &mut env.signatures,
+            TransactionEnvelope::Tx(env) => &mut env.signatures,
+            TransactionEnvelope::TxFeeBump(env) => &mut env.signatures,
+        };
+        let mut appended = signatures.to_vec();
+        appended.push(decorated);
+        *signatures = appended.try_into()?;
+        Ok(tx_env)
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
     commands::{global, txn_result::TxnEnvelopeResult},
-    config::{self, data, network, secret},
+    config::{self, address, data, network, secret},
     fee,
+    print::Print,
     rpc::{self, Client, GetTransactionResponse},
+    signer,
     tx::builder::{self, TxExt},
+    utils,
     xdr::{self, Limits, WriteXdr},
 };
 
@@ -14,6 +17,46 @@ pub struct Args {
     pub fee: fee::Args,
     #[clap(flatten)]
     pub config: config::Args,
+    /// Format of the transaction written to stdout when using `--build-only`
+    #[arg(long, value_enum, default_value_t)]
+    pub output: Output,
+    /// Memo to attach to the transaction, e.g. `text:hello`, `id:42`, `hash:<64 hex chars>` or `return:<64 hex chars>`
+    #[arg(long)]
+    pub memo: Option<builder::Memo>,
+    /// Source account of the operation, if it differs from the transaction's source account. When it is an identity, the transaction is also signed with it
+    #[arg(
+        long,
+        visible_alias = "op-source",
+        env = "STELLAR_OPERATION_SOURCE_ACCOUNT"
+    )]
+    pub operation_source_account: Option<address::UnresolvedMuxedAccount>,
+    /// Earliest time, as a unix timestamp in seconds, at which the transaction is valid
+    #[arg(long)]
+    pub min_time: Option<u64>,
+    /// Latest time, as a unix timestamp in seconds, at which the transaction is valid
+    #[arg(long)]
+    pub max_time: Option<u64>,
+    /// Earliest ledger in which the transaction is valid
+    #[arg(long)]
+    pub min_ledger: Option<u32>,
+    /// Latest ledger in which the transaction is valid
+    #[arg(long)]
+    pub max_ledger: Option<u32>,
+    /// Minimum number of seconds since the source account's sequence number last changed for the transaction to be valid
+    #[arg(long)]
+    pub min_seq_age: Option<u64>,
+    /// Sequence number of the transaction, instead of the next one of the source account fetched from the network. Together with `--build-only` this allows building a transaction with `--offline`
+    #[arg(long)]
+    pub sequence_number: Option<i64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum Output {
+    /// Base64 encoded XDR transaction envelope
+    #[default]
+    Xdr,
+    /// JSON representation of the transaction envelope
+    Json,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -32,31 +75,91 @@ pub enum Error {
     Data(#[from] data::Error),
     #[error(transparent)]
     Xdr(#[from] xdr::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Address(#[from] address::Error),
+    #[error(transparent)]
+    Signer(#[from] signer::Error),
 }
 
 impl Args {
-    pub async fn tx(&self, body: impl Into<xdr::OperationBody>) -> Result<xdr::Transaction, Error> {
+    pub async fn tx(
+        &self,
+        body: impl Into<xdr::OperationBody>,
+        global_args: &global::Args,
+    ) -> Result<xdr::Transaction, Error> {
+        let network = self.config.resolve_network(Some(global_args)).await?;
         let source_account = self.source_account()?;
-        let seq_num = self
-            .config
-            .next_sequence_number(source_account.clone().account_id())
-            .await?;
-        // Once we have a way to add operations this will be updated to allow for a different source account
+        let seq_num = if let Some(seq_num) = self.sequence_number {
+            xdr::SequenceNumber(seq_num)
+        } else {
+            network::ensure_online(Some(global_args))?;
+            self.config
+                .next_sequence_number(source_account.clone().account_id())
+                .await?
+        };
         let operation = xdr::Operation {
-            source_account: None,
+            source_account: self.operation_source()?,
             body: body.into(),
         };
-        Ok(xdr::Transaction::new_tx(
-            source_account,
-            self.fee.fee,
-            seq_num,
-            operation,
-        ))
+        if self.fee.fee_auto.is_some() {
+            network::ensure_online(Some(global_args))?;
+        }
+        let fee = self.fee.inclusion_fee(&network).await?;
+        let tx = xdr::Transaction::new_tx(source_account, fee, seq_num, operation);
+        let tx = match self.preconditions() {
+            Some(cond) => tx.add_cond(cond),
+            None => tx,
+        };
+        Ok(match &self.memo {
+            Some(memo) => tx.add_memo(memo.into()),
+            None => tx,
+        })
+    }
+
+    /// Time and ledger bounds and minimum sequence age, or `None` if none of them are set. An
+    /// unset upper bound is `0`, meaning unbounded.
+    pub fn preconditions(&self) -> Option<xdr::Preconditions> {
+        if self.min_time.is_none()
+            && self.max_time.is_none()
+            && self.min_ledger.is_none()
+            && self.max_ledger.is_none()
+            && self.min_seq_age.is_none()
+        {
+            return None;
+        }
+        let time_bounds = if self.min_time.is_some() || self.max_time.is_some() {
+            Some(xdr::TimeBounds {
+                min_time: xdr::TimePoint(self.min_time.unwrap_or_default()),
+                max_time: xdr::TimePoint(self.max_time.unwrap_or_default()),
+            })
+        } else {
+            None
+        };
+        let ledger_bounds = if self.min_ledger.is_some() || self.max_ledger.is_some() {
+            Some(xdr::LedgerBounds {
+                min_ledger: self.min_ledger.unwrap_or_default(),
+                max_ledger: self.max_ledger.unwrap_or_default(),
+            })
+        } else {
+            None
+        };
+        Some(xdr::Preconditions::V2(xdr::PreconditionsV2 {
+            time_bounds,
+            ledger_bounds,
+            min_seq_num: None,
+            min_seq_age: xdr::Duration(self.min_seq_age.unwrap_or_default()),
+            min_seq_ledger_gap: 0,
+            extra_signers: xdr::VecM::default(),
+        }))
     }
 
     pub fn client(&self) -> Result<Client, Error> {
         let network = self.config.get_network()?;
-        Ok(Client::new(&network.rpc_url)?)
+        Ok(network.rpc_client()?)
     }
 
     pub async fn handle(
@@ -64,7 +167,7 @@ impl Args {
         op: impl Into<xdr::OperationBody>,
         global_args: &global::Args,
     ) -> Result<TxnEnvelopeResult<GetTransactionResponse>, Error> {
-        let tx = self.tx(op.into()).await?;
+        let tx = self.tx(op.into(), global_args).await?;
         self.handle_tx(tx, global_args).await
     }
     pub async fn handle_and_print(
@@ -74,7 +177,11 @@ impl Args {
     ) -> Result<(), Error> {
         let res = self.handle(op, global_args).await?;
         if let TxnEnvelopeResult::TxnEnvelope(tx) = res {
-            println!("{}", tx.to_xdr_base64(Limits::none())?);
+            let print = Print::from(global_args);
+            match self.output {
+                Output::Xdr => print.outln(tx.to_xdr_base64(Limits::none())?),
+                Output::Json => print.outln(serde_json::to_string(&tx)?),
+            }
         };
         Ok(())
     }
@@ -84,15 +191,24 @@ impl Args {
         tx: xdr::Transaction,
         args: &global::Args,
     ) -> Result<TxnEnvelopeResult<GetTransactionResponse>, Error> {
-        let network = self.config.get_network()?;
-        let client = Client::new(&network.rpc_url)?;
         if self.fee.build_only {
             return Ok(TxnEnvelopeResult::TxnEnvelope(Box::new(tx.into())));
         }
+        network::ensure_online(Some(args))?;
+        let network = self.config.resolve_network(Some(args)).await?;
+        let client = network.rpc_client()?;
 
-        let txn_resp = client
-            .send_transaction_polling(&self.config.sign_with_local_key(tx).await?)
-            .await?;
+        let signed = self.config.sign_with_local_key(tx).await?;
+        let signed = self.sign_with_operation_source(signed)?;
+        let print = Print::new(args.quiet);
+        let txn_resp = utils::rpc::send_transaction_polling(
+            &client,
+            &network,
+            &signed,
+            self.fee.tx_timeout(),
+            &print,
+        )
+        .await?;
 
         if !args.no_cache {
             data::write(txn_resp.clone().try_into().unwrap(), &network.rpc_uri()?)?;
@@ -104,4 +220,33 @@ impl Args {
     pub fn source_account(&self) -> Result<xdr::MuxedAccount, Error> {
         Ok(self.config.source_account()?)
     }
+
+    /// The operation's source account, or `None` when it is the transaction's source account.
+    pub fn operation_source(&self) -> Result<Option<xdr::MuxedAccount>, Error> {
+        let Some(op_source) = &self.operation_source_account else {
+            return Ok(None);
+        };
+        let op_source =
+            op_source.resolve_muxed_account(&self.config.locator, self.config.hd_path)?;
+        Ok((op_source != self.source_account()?).then_some(op_source))
+    }
+
+    /// Adds the operation source's signature when it is a different account stored as an
+    /// identity. A public key cannot sign here, so its signature has to be added separately.
+    fn sign_with_operation_source(
+        &self,
+        tx_env: xdr::TransactionEnvelope,
+    ) -> Result<xdr::TransactionEnvelope, Error> {
+        if self.operation_source()?.is_none() {
+            return Ok(tx_env);
+        }
+        let Some(op_source @ address::UnresolvedMuxedAccount::AliasOrSecret(_)) =
+            &self.operation_source_account
+        else {
+            return Ok(tx_env);
+        };
+        let secret = op_source.resolve_secret(&self.config.locator)?;
+        let signer = secret.signer(self.config.hd_path, Print::new(true))?;
+        Ok(signer.sign_tx_env(&tx_env, &self.config.get_network()?)?)
+    }
 }
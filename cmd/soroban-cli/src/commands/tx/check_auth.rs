@@ -0,0 +1,187 @@
+use async_trait::async_trait;
+use ed25519_dalek::Verifier;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    commands::{global, NetworkRunnable},
+    config::{self, locator, network},
+    print::Print,
+    utils::transaction_hash,
+    xdr::{
+        self, AccountEntry, DecoratedSignature, Operation, OperationBody, SignerKey,
+        ThresholdIndexes, Transaction, TransactionEnvelope, TransactionV1Envelope, Uint256,
+    },
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    XdrArgs(#[from] super::xdr::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+    #[error(transparent)]
+    Config(#[from] config::Error),
+    #[error(transparent)]
+    Rpc(#[from] crate::rpc::Error),
+    #[error(transparent)]
+    Xdr(#[from] xdr::Error),
+    #[error("signature weight {present} does not meet the required threshold of {required}")]
+    InsufficientWeight { present: u32, required: u32 },
+}
+
+/// Command to check that a signed transaction envelope carries enough signature weight
+/// to meet the source account's thresholds, e.g. `cat file.txt | stellar tx check-auth`
+///
+/// Only operations sourced from the transaction's source account are considered.
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    #[clap(flatten)]
+    pub network: network::Args,
+    #[clap(flatten)]
+    pub locator: locator::Args,
+}
+
+/// Signature weight present on an envelope compared to the weight it needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthCheck {
+    pub present: u32,
+    pub required: u32,
+}
+
+impl AuthCheck {
+    #[must_use]
+    pub fn is_sufficient(&self) -> bool {
+        self.present >= self.required
+    }
+}
+
+impl Cmd {
+    pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let check = self.run_against_rpc_server(Some(global_args), None).await?;
+        if !check.is_sufficient() {
+            return Err(Error::InsufficientWeight {
+                present: check.present,
+                required: check.required,
+            });
+        }
+        Print::new(global_args.quiet).checkln(format!(
+            "Signature weight {} meets the required threshold of {}",
+            check.present, check.required
+        ));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NetworkRunnable for Cmd {
+    type Error = Error;
+    type Result = AuthCheck;
+
+    async fn run_against_rpc_server(
+        &self,
+        global_args: Option<&global::Args>,
+        config: Option<&config::Args>,
+    ) -> Result<Self::Result, Self::Error> {
+        network::ensure_online(global_args)?;
+        let network = if let Some(config) = config {
+            config.resolve_network(global_args).await?
+        } else {
+            self.network.resolve(&self.locator, global_args).await?
+        };
+        let TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures }) =
+            super::xdr::tx_envelope_from_stdin()?
+        else {
+            return Err(super::xdr::Error::OnlyTransactionV1Supported.into());
+        };
+        let client = network.rpc_client()?;
+        let source = tx.source_account.clone().account_id();
+        let account = network
+            .timeout(client.get_account(&source.to_string()))
+            .await??;
+        let hash = transaction_hash(&tx, &network.network_passphrase)?;
+        Ok(AuthCheck {
+            present: signature_weight(&account, &hash, &signatures),
+            required: required_threshold(&account, &tx),
+        })
+    }
+}
+
+/// The highest threshold required by any operation sourced from the transaction's source
+/// account. A transaction always needs at least the low threshold and a weight of one.
+fn required_threshold(account: &AccountEntry, tx: &Transaction) -> u32 {
+    let source = tx.source_account.clone().account_id();
+    let level = tx
+        .operations
+        .iter()
+        .filter(|op| {
+            op.source_account
+                .as_ref()
+                .map_or(true, |s| s.clone().account_id() == source)
+        })
+        .map(threshold_level)
+        .max_by_key(|level| *level as usize)
+        .unwrap_or(ThresholdIndexes::Low);
+    u32::from(account.thresholds.0[level as usize]).max(1)
+}
+
+fn threshold_level(op: &Operation) -> ThresholdIndexes {
+    match &op.body {
+        OperationBody::AllowTrust(_)
+        | OperationBody::SetTrustLineFlags(_)
+        | OperationBody::BumpSequence(_)
+        | OperationBody::ClaimClaimableBalance(_)
+        | OperationBody::Inflation
+        | OperationBody::ExtendFootprintTtl(_)
+        | OperationBody::RestoreFootprint(_) => ThresholdIndexes::Low,
+        OperationBody::AccountMerge(_) => ThresholdIndexes::High,
+        OperationBody::SetOptions(op)
+            if op.master_weight.is_some()
+                || op.low_threshold.is_some()
+                || op.med_threshold.is_some()
+                || op.high_threshold.is_some()
+                || op.signer.is_some() =>
+        {
+            ThresholdIndexes::High
+        }
+        _ => ThresholdIndexes::Med,
+    }
+}
+
+/// Sum of the weights of the account's signers that have a valid signature on the envelope.
+/// Each signer is counted at most once.
+fn signature_weight(account: &AccountEntry, hash: &[u8; 32], sigs: &[DecoratedSignature]) -> u32 {
+    let xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(Uint256(master))) = &account.account_id;
+    let master_weight = u32::from(account.thresholds.0[ThresholdIndexes::MasterWeight as usize]);
+    let master_signed = sigs.iter().any(|sig| verify_ed25519(master, hash, sig));
+    let signers_weight: u32 = account
+        .signers
+        .iter()
+        .filter(|signer| match &signer.key {
+            SignerKey::Ed25519(Uint256(key)) => {
+                sigs.iter().any(|sig| verify_ed25519(key, hash, sig))
+            }
+            SignerKey::PreAuthTx(Uint256(key)) => key == hash,
+            SignerKey::HashX(Uint256(key)) => sigs.iter().any(|sig| {
+                sig.hint.0[..] == key[28..]
+                    && Sha256::digest(sig.signature.0.as_slice())[..] == key[..]
+            }),
+            SignerKey::Ed25519SignedPayload(_) => false,
+        })
+        .map(|signer| signer.weight)
+        .sum();
+    signers_weight + if master_signed { master_weight } else { 0 }
+}
+
+pub(super) fn verify_ed25519(key: &[u8; 32], hash: &[u8; 32], sig: &DecoratedSignature) -> bool {
+    if sig.hint.0[..] != key[28..] {
+        return false;
+    }
+    let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(key) else {
+        return false;
+    };
+    let Ok(signature) = ed25519_dalek::Signature::from_slice(sig.signature.0.as_slice()) else {
+        return false;
+    };
+    key.verify(hash, &signature).is_ok()
+}
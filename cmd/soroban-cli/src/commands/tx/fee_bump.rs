@@ -0,0 +1,94 @@
+use crate::{
+    commands::global,
+    config::{address, locator, network, secret, UnresolvedMuxedAccount},
+    print::Print,
+    signer::{self, LocalKey},
+    tx::builder::FeeBumpExt,
+    utils::fee_bump_transaction_hash,
+    xdr::{
+        self, FeeBumpTransaction, FeeBumpTransactionEnvelope, Limits, TransactionEnvelope, VecM,
+        WriteXdr,
+    },
+};
+
+/// Minimum fee per operation, in stroops.
+const BASE_FEE: i64 = 100;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    XdrArgs(#[from] super::xdr::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+    #[error(transparent)]
+    Address(#[from] address::Error),
+    #[error(transparent)]
+    Secret(#[from] secret::Error),
+    #[error(transparent)]
+    Signer(#[from] signer::Error),
+    #[error(transparent)]
+    Xdr(#[from] xdr::Error),
+    #[error("fee bump fee {fee} must be at least {min} stroops to cover the inner transaction")]
+    FeeTooLow { fee: i64, min: i64 },
+}
+
+/// Command to wrap a transaction envelope from stdin in a fee bump transaction
+/// e.g. `cat file.txt | stellar tx fee-bump --fee-source alice --fee 200`
+///
+/// The inner transaction should already be signed by its source account.
+#[derive(Debug, clap::Parser, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    /// Account that pays the fee for the inner transaction. Can be an identity (--fee-source alice), a public key (--fee-source GDKW...), a muxed account (--fee-source MDA…), a secret key (--fee-source SC36…), or a seed phrase (--fee-source "kite urban…"). Unless `--build-only` is provided, this key is used to sign the fee bump transaction.
+    #[arg(long)]
+    pub fee_source: UnresolvedMuxedAccount,
+    /// If using a seed phrase for the fee source, which hierarchical deterministic path to use, e.g. `m/44'/148'/{hd_path}`. Example: `--hd-path 1`. Default: `0`
+    #[arg(long)]
+    pub hd_path: Option<usize>,
+    /// Total fee for the fee bump transaction, in stroops. Must be at least the base fee multiplied by the number of operations in the inner transaction plus one
+    #[arg(long)]
+    pub fee: i64,
+    /// Build the fee bump transaction without signing it and write the base64 xdr to stdout
+    #[arg(long)]
+    pub build_only: bool,
+    #[command(flatten)]
+    pub network: network::Args,
+    #[command(flatten)]
+    pub locator: locator::Args,
+}
+
+impl Cmd {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
+        let TransactionEnvelope::Tx(inner) = super::xdr::tx_envelope_from_stdin()? else {
+            return Err(super::xdr::Error::OnlyTransactionV1Supported.into());
+        };
+        let ops = i64::try_from(inner.tx.operations.len()).unwrap_or(i64::MAX);
+        let min = (ops + 1) * BASE_FEE;
+        if self.fee < min {
+            return Err(Error::FeeTooLow { fee: self.fee, min });
+        }
+        let fee_source = self
+            .fee_source
+            .resolve_muxed_account(&self.locator, self.hd_path)?;
+        let tx = FeeBumpTransaction::new_fee_bump(fee_source, self.fee, inner);
+        let signatures = if self.build_only {
+            VecM::default()
+        } else {
+            let network = self.network.get(&self.locator)?;
+            let hash = fee_bump_transaction_hash(&tx, &network.network_passphrase)?;
+            Print::new(global_args.quiet).infoln(format!(
+                "Signing fee bump transaction: {}",
+                hex::encode(hash)
+            ));
+            let key = self
+                .fee_source
+                .resolve_secret(&self.locator)?
+                .key_pair(self.hd_path)?;
+            vec![LocalKey { key }.sign_tx_hash(hash)?].try_into()?
+        };
+        let envelope =
+            TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope { tx, signatures });
+        Print::from(global_args).outln(envelope.to_xdr_base64(Limits::none())?);
+        Ok(())
+    }
+}
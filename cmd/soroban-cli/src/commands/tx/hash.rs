@@ -1,6 +1,6 @@
 use hex;
 
-use crate::{commands::global, config::network, utils::transaction_hash};
+use crate::{commands::global, config::network, print::Print, utils::envelope_hash};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -14,6 +14,9 @@ pub enum Error {
 
 // Command to return the transaction hash submitted to a network
 /// e.g. `cat file.txt | soroban tx hash`
+///
+/// This is the hash signed by the source account, so it can be signed externally. For fee bump
+/// envelopes it is the hash of the fee bump transaction.
 #[derive(Debug, clap::Parser, Clone, Default)]
 #[group(skip)]
 pub struct Cmd {
@@ -23,12 +26,10 @@ pub struct Cmd {
 
 impl Cmd {
     pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let tx = super::xdr::unwrap_envelope_v1(super::xdr::tx_envelope_from_stdin()?)?;
+        let tx_env = super::xdr::tx_envelope_from_stdin()?;
         let network = &self.network.get(&global_args.locator)?;
-        println!(
-            "{}",
-            hex::encode(transaction_hash(&tx, &network.network_passphrase)?)
-        );
+        let hash = envelope_hash(&tx_env, &network.network_passphrase)?;
+        Print::from(global_args).outln(hex::encode(hash));
         Ok(())
     }
 }
@@ -6,9 +6,18 @@ https://developers.stellar.org/docs/learn/fundamentals/stellar-data-structures/a
 
 pub const CREATE_ACCOUNT: &str =
     "Creates and funds a new account with the specified starting balance";
+pub const CREATE_PASSIVE_SELL_OFFER: &str = r"Creates an offer to sell one asset for another without taking a reverse offer of equal price
+Learn more about passive offers:
+https://developers.stellar.org/docs/learn/encyclopedia/sdex/liquidity-on-stellar-sdex-liquidity-pools#passive-offers";
 pub const MANAGE_DATA: &str = r"Sets, modifies, or deletes a data entry (name/value pair) that is attached to an account
 Learn more about entries and subentries:
 https://developers.stellar.org/docs/learn/fundamentals/stellar-data-structures/accounts#subentries";
+pub const MANAGE_BUY_OFFER: &str = r"Creates, updates, or deletes an offer to buy a specific amount of an asset for another
+Learn more about offers:
+https://developers.stellar.org/docs/learn/encyclopedia/sdex/liquidity-on-stellar-sdex-liquidity-pools#orderbook";
+pub const MANAGE_SELL_OFFER: &str = r"Creates, updates, or deletes an offer to sell a specific amount of an asset for another
+Learn more about offers:
+https://developers.stellar.org/docs/learn/encyclopedia/sdex/liquidity-on-stellar-sdex-liquidity-pools#orderbook";
 pub const PAYMENT: &str = "Sends an amount in a specific asset to a destination account";
 pub const SET_OPTIONS: &str = r"Set option for an account such as flags, inflation destination, signers, home domain, and master key weight
 Learn more about flags:
@@ -1,6 +1,9 @@
 use super::global;
 
+pub mod add_signature;
 pub mod args;
+pub mod check_auth;
+pub mod fee_bump;
 pub mod hash;
 pub mod help;
 pub mod new;
@@ -14,6 +17,12 @@ pub use args::Args;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum Cmd {
+    /// Append a signature produced outside of the CLI to a transaction envelope from stdin, after checking it against the transaction hash
+    AddSignature(add_signature::Cmd),
+    /// Check that a signed transaction envelope from stdin has enough signature weight to meet the source account's thresholds
+    CheckAuth(check_auth::Cmd),
+    /// Wrap a transaction envelope from stdin in a fee bump transaction paid by another account
+    FeeBump(fee_bump::Cmd),
     /// Calculate the hash of a transaction envelope from stdin
     Hash(hash::Cmd),
     /// Create a new transaction
@@ -32,6 +41,12 @@ pub enum Cmd {
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
+    #[error(transparent)]
+    AddSignature(#[from] add_signature::Error),
+    #[error(transparent)]
+    CheckAuth(#[from] check_auth::Error),
+    #[error(transparent)]
+    FeeBump(#[from] fee_bump::Error),
     #[error(transparent)]
     Hash(#[from] hash::Error),
     #[error(transparent)]
@@ -49,6 +64,9 @@ pub enum Error {
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match self {
+            Cmd::AddSignature(cmd) => cmd.run(global_args)?,
+            Cmd::CheckAuth(cmd) => cmd.run(global_args).await?,
+            Cmd::FeeBump(cmd) => cmd.run(global_args)?,
             Cmd::Hash(cmd) => cmd.run(global_args)?,
             Cmd::New(cmd) => cmd.run(global_args).await?,
             Cmd::Operation(cmd) => cmd.run(global_args)?,
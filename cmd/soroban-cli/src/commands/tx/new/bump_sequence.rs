@@ -1,6 +1,16 @@
 use clap::{command, Parser};
 
-use crate::{commands::tx, xdr};
+use crate::{commands::tx, config, xdr};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Tx(#[from] tx::args::Error),
+    #[error(transparent)]
+    Config(#[from] config::Error),
+    #[error("bumping sequence number {current} by {bump_by} overflows")]
+    Overflow { current: i64, bump_by: i64 },
+}
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -8,7 +18,18 @@ pub struct Cmd {
     #[command(flatten)]
     pub tx: tx::Args,
     #[clap(flatten)]
-    pub op: Args,
+    pub op: Target,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+#[group(required = true, multiple = false)]
+pub struct Target {
+    /// Sequence number to bump to
+    #[arg(long)]
+    pub bump_to: Option<i64>,
+    /// Amount to add to the source account's current sequence number, which is fetched from the network
+    #[arg(long)]
+    pub bump_by: Option<i64>,
 }
 
 #[derive(Debug, clap::Args, Clone)]
@@ -18,6 +39,27 @@ pub struct Args {
     pub bump_to: i64,
 }
 
+impl Cmd {
+    /// The operation to submit, resolving `--bump-by` against the source account's current
+    /// sequence number.
+    pub async fn op(&self) -> Result<Args, Error> {
+        let bump_by = match self.op {
+            Target {
+                bump_to: Some(bump_to),
+                ..
+            } => return Ok(Args { bump_to }),
+            Target { bump_by, .. } => bump_by.unwrap_or_default(),
+        };
+        let source_account = self.tx.source_account()?.account_id();
+        let next = self.tx.config.next_sequence_number(source_account).await?;
+        let current = next.0 - 1;
+        let bump_to = current
+            .checked_add(bump_by)
+            .ok_or(Error::Overflow { current, bump_by })?;
+        Ok(Args { bump_to })
+    }
+}
+
 impl From<&Args> for xdr::OperationBody {
     fn from(cmd: &Args) -> Self {
         xdr::OperationBody::BumpSequence(xdr::BumpSequenceOp {
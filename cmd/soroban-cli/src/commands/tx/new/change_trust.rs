@@ -1,6 +1,6 @@
 use clap::{command, Parser};
 
-use crate::{commands::tx, tx::builder, xdr};
+use crate::{commands::tx, config::locator, tx::builder, xdr};
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -13,23 +13,25 @@ pub struct Cmd {
 
 #[derive(Debug, clap::Args, Clone)]
 pub struct Args {
+    /// Asset to trust, e.g. `USDC:G...`. The code must be 1 to 12 ASCII letters or digits
     #[arg(long)]
-    pub line: builder::Asset,
+    pub line: builder::UnresolvedAsset,
     /// Limit for the trust line, 0 to remove the trust line
     #[arg(long, default_value = i64::MAX.to_string())]
     pub limit: i64,
 }
 
-impl From<&Args> for xdr::OperationBody {
-    fn from(cmd: &Args) -> Self {
-        let line = match cmd.line.0.clone() {
+impl Args {
+    /// The operation, with asset aliases resolved against `locator`.
+    pub fn op(&self, locator: &locator::Args) -> Result<xdr::OperationBody, builder::asset::Error> {
+        let line = match self.line.resolve(locator)?.0 {
             xdr::Asset::CreditAlphanum4(asset) => xdr::ChangeTrustAsset::CreditAlphanum4(asset),
             xdr::Asset::CreditAlphanum12(asset) => xdr::ChangeTrustAsset::CreditAlphanum12(asset),
             xdr::Asset::Native => xdr::ChangeTrustAsset::Native,
         };
-        xdr::OperationBody::ChangeTrust(xdr::ChangeTrustOp {
+        Ok(xdr::OperationBody::ChangeTrust(xdr::ChangeTrustOp {
             line,
-            limit: cmd.limit,
-        })
+            limit: self.limit,
+        }))
     }
 }
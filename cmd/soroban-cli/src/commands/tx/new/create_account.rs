@@ -16,7 +16,7 @@ pub struct Args {
     /// Account Id to create, e.g. `GBX...`
     #[arg(long, alias = "dest")]
     pub destination: xdr::AccountId,
-    /// Initial balance in stroops of the account, default 1 XLM
+    /// Initial balance of the account in stroops, or in XLM with a decimal point, e.g. `1.5`. Default 1 XLM
     #[arg(long, default_value = "10_000_000")]
     pub starting_balance: builder::Amount,
 }
@@ -0,0 +1,46 @@
+use clap::{command, Parser};
+
+use crate::{commands::tx, config::locator, tx::builder, xdr};
+
+#[derive(Parser, Debug, Clone)]
+#[group(skip)]
+pub struct Cmd {
+    #[command(flatten)]
+    pub tx: tx::Args,
+    #[clap(flatten)]
+    pub op: Args,
+}
+
+#[derive(Debug, clap::Args, Clone)]
+pub struct Args {
+    /// Asset to sell, e.g. `native` or `USDC:GBX...`
+    #[arg(long)]
+    pub selling: builder::UnresolvedAsset,
+    /// Asset to buy, e.g. `native` or `USDC:GBX...`
+    #[arg(long)]
+    pub buying: builder::UnresolvedAsset,
+    /// Amount of the selling asset to offer, in stroops. e.g. `10_000_000` (1 unit). An amount of `0` deletes the offer
+    #[arg(long, value_parser = builder::amount::parse_stroops)]
+    pub amount: builder::Amount,
+    /// Price of 1 unit of the selling asset in terms of the buying asset, as a rational `n:d` or `n/d`, or a decimal. e.g. `5:4`, `5/4` or `1.25`
+    #[arg(long)]
+    pub price: builder::Price,
+    /// ID of the offer to update or delete. `0` creates a new offer
+    #[arg(long, default_value = "0")]
+    pub offer_id: i64,
+}
+
+impl Args {
+    /// The operation, with asset aliases resolved against `locator`.
+    pub fn op(&self, locator: &locator::Args) -> Result<xdr::OperationBody, builder::asset::Error> {
+        Ok(xdr::OperationBody::ManageSellOffer(
+            xdr::ManageSellOfferOp {
+                selling: self.selling.resolve(locator)?.into(),
+                buying: self.buying.resolve(locator)?.into(),
+                amount: self.amount.into(),
+                price: self.price.clone().into(),
+                offer_id: self.offer_id,
+            },
+        ))
+    }
+}
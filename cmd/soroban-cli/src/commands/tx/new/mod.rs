@@ -1,12 +1,16 @@
 use clap::Parser;
 
 use super::global;
+use crate::tx::builder;
 
 pub mod account_merge;
 pub mod bump_sequence;
 pub mod change_trust;
 pub mod create_account;
+pub mod create_passive_sell_offer;
+pub mod manage_buy_offer;
 pub mod manage_data;
+pub mod manage_sell_offer;
 pub mod payment;
 pub mod set_options;
 pub mod set_trustline_flags;
@@ -22,8 +26,14 @@ pub enum Cmd {
     ChangeTrust(change_trust::Cmd),
     #[command(about = super::help::CREATE_ACCOUNT)]
     CreateAccount(create_account::Cmd),
+    #[command(about = super::help::CREATE_PASSIVE_SELL_OFFER)]
+    CreatePassiveSellOffer(create_passive_sell_offer::Cmd),
+    #[command(about = super::help::MANAGE_BUY_OFFER)]
+    ManageBuyOffer(manage_buy_offer::Cmd),
     #[command(about = super::help::MANAGE_DATA)]
     ManageData(manage_data::Cmd),
+    #[command(about = super::help::MANAGE_SELL_OFFER)]
+    ManageSellOffer(manage_sell_offer::Cmd),
     #[command(about = super::help::PAYMENT)]
     Payment(payment::Cmd),
     #[command(about = super::help::SET_OPTIONS)]
@@ -36,19 +46,50 @@ pub enum Cmd {
 pub enum Error {
     #[error(transparent)]
     Tx(#[from] super::args::Error),
+    #[error(transparent)]
+    BumpSequence(#[from] bump_sequence::Error),
+    #[error(transparent)]
+    Payment(#[from] payment::Error),
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
 }
 
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         match self {
             Cmd::AccountMerge(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
-            Cmd::BumpSequence(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
-            Cmd::ChangeTrust(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
+            Cmd::BumpSequence(cmd) => {
+                let op = cmd.op().await?;
+                cmd.tx.handle_and_print(&op, global_args).await
+            }
+            Cmd::ChangeTrust(cmd) => {
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
             Cmd::CreateAccount(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
+            Cmd::CreatePassiveSellOffer(cmd) => {
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
+            Cmd::ManageBuyOffer(cmd) => {
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
             Cmd::ManageData(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
-            Cmd::Payment(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
+            Cmd::ManageSellOffer(cmd) => {
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
+            Cmd::Payment(cmd) => {
+                cmd.check_balance(global_args).await?;
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
             Cmd::SetOptions(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
-            Cmd::SetTrustlineFlags(cmd) => cmd.tx.handle_and_print(&cmd.op, global_args).await,
+            Cmd::SetTrustlineFlags(cmd) => {
+                let op = cmd.op.op(&cmd.tx.config.locator)?;
+                cmd.tx.handle_and_print(op, global_args).await
+            }
         }?;
         Ok(())
     }
@@ -1,6 +1,39 @@
 use clap::{command, Parser};
 
-use crate::{commands::tx, tx::builder, xdr};
+use crate::{
+    commands::{global, tx},
+    config::{self, locator, network},
+    rpc,
+    tx::builder,
+    xdr,
+};
+
+/// Base reserve of the Stellar networks, in stroops. An account has to hold two base reserves, plus
+/// one for each subentry it owns or sponsors. RPC doesn't expose the ledger header the network's
+/// value is in, so this is assumed, and the error says so.
+const BASE_RESERVE: i64 = 5_000_000;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Tx(#[from] tx::args::Error),
+    #[error(transparent)]
+    Config(#[from] config::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+    #[error(transparent)]
+    Rpc(#[from] rpc::Error),
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
+    #[error(
+        "insufficient balance: {account} can spend {available} stroops after reserves and selling liabilities, but {required} stroops are needed. Reserves assume a base reserve of {BASE_RESERVE} stroops, use `--build-only` to skip this check"
+    )]
+    InsufficientBalance {
+        account: String,
+        available: i64,
+        required: i64,
+    },
+}
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -16,20 +49,85 @@ pub struct Args {
     /// Account to send to, e.g. `GBX...`
     #[arg(long, visible_alias = "dest")]
     pub destination: xdr::MuxedAccount,
+    /// Muxed account id to send to. Wraps a `G...` destination into a muxed account with this id, replacing the id of an `M...` destination
+    #[arg(long)]
+    pub to_muxed_id: Option<u64>,
     /// Asset to send, default native, e.i. XLM
     #[arg(long, default_value = "native")]
-    pub asset: builder::Asset,
-    /// Amount of the aforementioned asset to send. e.g. `10_000_000` (1 XLM)
+    pub asset: builder::UnresolvedAsset,
+    /// Amount of the aforementioned asset to send, in stroops, e.g. `10_000_000` (1 XLM), or in units with a decimal point, e.g. `1.0`
     #[arg(long)]
     pub amount: builder::Amount,
 }
 
-impl From<&Args> for xdr::OperationBody {
-    fn from(cmd: &Args) -> Self {
-        xdr::OperationBody::Payment(xdr::PaymentOp {
-            destination: cmd.destination.clone(),
-            asset: cmd.asset.clone().into(),
-            amount: cmd.amount.into(),
-        })
+impl Cmd {
+    /// Checks that the paying account can afford a native payment, and the fee when it is also the
+    /// transaction's source account, before the transaction is submitted.
+    pub async fn check_balance(&self, global_args: &global::Args) -> Result<(), Error> {
+        if self.tx.fee.build_only
+            || self.op.asset.resolve(&self.tx.config.locator)?.0 != xdr::Asset::Native
+        {
+            return Ok(());
+        }
+        network::ensure_online(Some(global_args))?;
+        let network = self.tx.config.resolve_network(Some(global_args)).await?;
+        let (payer, fee) = match self.tx.operation_source()? {
+            Some(op_source) => (op_source, 0),
+            None => {
+                let fee = self.tx.fee.inclusion_fee(&network).await?;
+                (self.tx.source_account()?, i64::from(fee))
+            }
+        };
+        let account = payer.account_id().to_string();
+        let client = network.rpc_client()?;
+        let entry = network.timeout(client.get_account(&account)).await??;
+        let available = spendable_balance(&entry);
+        let required = i64::from(&self.op.amount).saturating_add(fee);
+        if required > available {
+            return Err(Error::InsufficientBalance {
+                account,
+                available,
+                required,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Balance of the account that is neither locked up in reserves nor in offers selling XLM.
+fn spendable_balance(account: &xdr::AccountEntry) -> i64 {
+    let (selling, sponsoring, sponsored) = match &account.ext {
+        xdr::AccountEntryExt::V0 => (0, 0, 0),
+        xdr::AccountEntryExt::V1(v1) => match &v1.ext {
+            xdr::AccountEntryExtensionV1Ext::V0 => (v1.liabilities.selling, 0, 0),
+            xdr::AccountEntryExtensionV1Ext::V2(v2) => {
+                (v1.liabilities.selling, v2.num_sponsoring, v2.num_sponsored)
+            }
+        },
+    };
+    let reserves =
+        2 + i64::from(account.num_sub_entries) + i64::from(sponsoring) - i64::from(sponsored);
+    account.balance - reserves * BASE_RESERVE - selling
+}
+
+impl Args {
+    fn destination(&self) -> xdr::MuxedAccount {
+        let Some(id) = self.to_muxed_id else {
+            return self.destination.clone();
+        };
+        let ed25519 = match &self.destination {
+            xdr::MuxedAccount::Ed25519(ed25519) => ed25519.clone(),
+            xdr::MuxedAccount::MuxedEd25519(muxed) => muxed.ed25519.clone(),
+        };
+        xdr::MuxedAccount::MuxedEd25519(xdr::MuxedAccountMed25519 { id, ed25519 })
+    }
+
+    /// The operation, with asset aliases resolved against `locator`.
+    pub fn op(&self, locator: &locator::Args) -> Result<xdr::OperationBody, builder::asset::Error> {
+        Ok(xdr::OperationBody::Payment(xdr::PaymentOp {
+            destination: self.destination(),
+            asset: self.asset.resolve(locator)?.into(),
+            amount: self.amount.into(),
+        }))
     }
 }
@@ -58,12 +58,16 @@ pub struct Args {
     /// https://developers.stellar.org/docs/tokens/control-asset-access#authorization-immutable-0x4
     pub set_immutable: bool,
     #[arg(long)]
+    /// Clear the authorization required flag, so accounts no longer need the issuer's approval to hold its asset.
     pub clear_required: bool,
     #[arg(long)]
+    /// Clear the authorization revocable flag, so the issuer can no longer revoke existing trustlines.
     pub clear_revocable: bool,
     #[arg(long)]
+    /// Clear the authorization immutable flag. The network rejects this once the flag is set, since flags of an immutable account cannot change.
     pub clear_immutable: bool,
     #[arg(long)]
+    /// Clear the clawback enabled flag. Only affects trustlines created afterwards.
     pub clear_clawback_enabled: bool,
 }
 
@@ -1,6 +1,6 @@
 use clap::{command, Parser};
 
-use crate::{commands::tx, tx::builder, xdr};
+use crate::{commands::tx, config::locator, tx::builder, xdr};
 
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
@@ -19,7 +19,7 @@ pub struct Args {
     pub trustor: xdr::AccountId,
     /// Asset to set trustline flags for
     #[arg(long)]
-    pub asset: builder::Asset,
+    pub asset: builder::UnresolvedAsset,
     #[arg(long, conflicts_with = "clear_authorize")]
     /// Signifies complete authorization allowing an account to transact freely with the asset to make and receive payments and place orders.
     pub set_authorize: bool,
@@ -38,38 +38,41 @@ pub struct Args {
     pub clear_trustline_clawback_enabled: bool,
 }
 
-impl From<&Args> for xdr::OperationBody {
-    fn from(cmd: &Args) -> Self {
+impl Args {
+    /// The operation, with asset aliases resolved against `locator`.
+    pub fn op(&self, locator: &locator::Args) -> Result<xdr::OperationBody, builder::asset::Error> {
         let mut set_flags = 0;
         let mut set_flag = |flag: xdr::TrustLineFlags| set_flags |= flag as u32;
 
-        if cmd.set_authorize {
+        if self.set_authorize {
             set_flag(xdr::TrustLineFlags::AuthorizedFlag);
         };
-        if cmd.set_authorize_to_maintain_liabilities {
+        if self.set_authorize_to_maintain_liabilities {
             set_flag(xdr::TrustLineFlags::AuthorizedToMaintainLiabilitiesFlag);
         };
-        if cmd.set_trustline_clawback_enabled {
+        if self.set_trustline_clawback_enabled {
             set_flag(xdr::TrustLineFlags::TrustlineClawbackEnabledFlag);
         };
 
         let mut clear_flags = 0;
         let mut clear_flag = |flag: xdr::TrustLineFlags| clear_flags |= flag as u32;
-        if cmd.clear_authorize {
+        if self.clear_authorize {
             clear_flag(xdr::TrustLineFlags::AuthorizedFlag);
         };
-        if cmd.clear_authorize_to_maintain_liabilities {
+        if self.clear_authorize_to_maintain_liabilities {
             clear_flag(xdr::TrustLineFlags::AuthorizedToMaintainLiabilitiesFlag);
         };
-        if cmd.clear_trustline_clawback_enabled {
+        if self.clear_trustline_clawback_enabled {
             clear_flag(xdr::TrustLineFlags::TrustlineClawbackEnabledFlag);
         };
 
-        xdr::OperationBody::SetTrustLineFlags(xdr::SetTrustLineFlagsOp {
-            trustor: cmd.trustor.clone(),
-            asset: cmd.asset.clone().into(),
-            clear_flags,
-            set_flags,
-        })
+        Ok(xdr::OperationBody::SetTrustLineFlags(
+            xdr::SetTrustLineFlagsOp {
+                trustor: self.trustor.clone(),
+                asset: self.asset.resolve(locator)?.into(),
+                clear_flags,
+                set_flags,
+            },
+        ))
     }
 }
@@ -1,7 +1,7 @@
 use clap::Parser;
 
 use super::super::{global, help, xdr::tx_envelope_from_stdin};
-use crate::xdr::WriteXdr;
+use crate::{print::Print, tx::builder, xdr::WriteXdr};
 
 pub(crate) use super::super::{new, xdr};
 
@@ -10,7 +10,10 @@ mod args;
 mod bump_sequence;
 mod change_trust;
 mod create_account;
+mod create_passive_sell_offer;
+mod manage_buy_offer;
 mod manage_data;
+mod manage_sell_offer;
 mod payment;
 mod set_options;
 mod set_trustline_flags;
@@ -26,8 +29,14 @@ pub enum Cmd {
     ChangeTrust(change_trust::Cmd),
     #[command(about = help::CREATE_ACCOUNT)]
     CreateAccount(create_account::Cmd),
+    #[command(about = help::CREATE_PASSIVE_SELL_OFFER)]
+    CreatePassiveSellOffer(create_passive_sell_offer::Cmd),
+    #[command(about = help::MANAGE_BUY_OFFER)]
+    ManageBuyOffer(manage_buy_offer::Cmd),
     #[command(about = help::MANAGE_DATA)]
     ManageData(manage_data::Cmd),
+    #[command(about = help::MANAGE_SELL_OFFER)]
+    ManageSellOffer(manage_sell_offer::Cmd),
     #[command(about = help::PAYMENT)]
     Payment(payment::Cmd),
     #[command(about = help::SET_OPTIONS)]
@@ -44,22 +53,29 @@ pub enum Error {
     TxXdr(#[from] super::super::xdr::Error),
     #[error(transparent)]
     Xdr(#[from] crate::xdr::Error),
+    #[error(transparent)]
+    Asset(#[from] builder::asset::Error),
 }
 
 impl Cmd {
-    pub fn run(&self, _: &global::Args) -> Result<(), Error> {
+    pub fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let tx_env = tx_envelope_from_stdin()?;
         let res = match self {
             Cmd::AccountMerge(cmd) => cmd.args.add_op(&cmd.op, tx_env),
             Cmd::BumpSequence(cmd) => cmd.args.add_op(&cmd.op, tx_env),
-            Cmd::ChangeTrust(cmd) => cmd.args.add_op(&cmd.op, tx_env),
+            Cmd::ChangeTrust(cmd) => cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env),
             Cmd::CreateAccount(cmd) => cmd.args.add_op(&cmd.op, tx_env),
+            Cmd::CreatePassiveSellOffer(cmd) => {
+                cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env)
+            }
+            Cmd::ManageBuyOffer(cmd) => cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env),
             Cmd::ManageData(cmd) => cmd.args.add_op(&cmd.op, tx_env),
-            Cmd::Payment(cmd) => cmd.args.add_op(&cmd.op, tx_env),
+            Cmd::ManageSellOffer(cmd) => cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env),
+            Cmd::Payment(cmd) => cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env),
             Cmd::SetOptions(cmd) => cmd.args.add_op(&cmd.op, tx_env),
-            Cmd::SetTrustlineFlags(cmd) => cmd.args.add_op(&cmd.op, tx_env),
+            Cmd::SetTrustlineFlags(cmd) => cmd.args.add_op(cmd.op.op(&cmd.args.locator)?, tx_env),
         }?;
-        println!("{}", res.to_xdr_base64(crate::xdr::Limits::none())?);
+        Print::from(global_args).outln(res.to_xdr_base64(crate::xdr::Limits::none())?);
         Ok(())
     }
 }
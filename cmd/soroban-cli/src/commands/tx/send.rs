@@ -1,4 +1,9 @@
-use crate::{print::Print, utils::transaction_hash};
+use std::time::Duration;
+
+use crate::{
+    print::Print,
+    utils::{self, transaction_hash},
+};
 use async_trait::async_trait;
 use soroban_rpc::GetTransactionResponse;
 
@@ -18,9 +23,14 @@ pub enum Error {
     #[error(transparent)]
     Rpc(#[from] crate::rpc::Error),
     #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
     SerdeJson(#[from] serde_json::Error),
 }
 
+/// How long to wait for a sent transaction to succeed or fail.
+const TX_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Debug, clap::Parser, Clone)]
 #[group(skip)]
 /// Command to send a transaction envelope to the network
@@ -35,7 +45,7 @@ pub struct Cmd {
 impl Cmd {
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
         let response = self.run_against_rpc_server(Some(global_args), None).await?;
-        println!("{}", serde_json::to_string_pretty(&response)?);
+        Print::from(global_args).outln(serde_json::to_string_pretty(&response)?);
         Ok(())
     }
 }
@@ -50,21 +60,25 @@ impl NetworkRunnable for Cmd {
         globals: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<Self::Result, Self::Error> {
+        network::ensure_online(globals)?;
         let network = if let Some(config) = config {
-            config.get_network()?
+            config.resolve_network(globals).await?
         } else {
-            self.network.get(&self.locator)?
+            self.network.resolve(&self.locator, globals).await?
         };
         let client = network.rpc_client()?;
         let tx_env = super::xdr::tx_envelope_from_stdin()?;
 
+        let print = Print::new(globals.map_or(false, |g| g.quiet));
         if let Ok(Ok(hash)) = super::xdr::unwrap_envelope_v1(tx_env.clone())
             .map(|tx| transaction_hash(&tx, &network.network_passphrase))
         {
-            let print = Print::new(globals.map_or(false, |g| g.quiet));
             print.infoln(format!("Transaction Hash: {}", hex::encode(hash)));
         }
 
-        Ok(client.send_transaction_polling(&tx_env).await?)
+        let response =
+            utils::rpc::send_transaction_polling(&client, &network, &tx_env, TX_TIMEOUT, &print)
+                .await?;
+        Ok(response)
     }
 }
@@ -1,7 +1,14 @@
+use super::check_auth::verify_ed25519;
 use crate::{
     commands::global,
     config::{locator, network, sign_with},
-    xdr::{self, Limits, WriteXdr},
+    print::Print,
+    signer,
+    utils::envelope_hash,
+    xdr::{
+        self, FeeBumpTransactionEnvelope, Limits, TransactionEnvelope, TransactionV1Envelope,
+        WriteXdr,
+    },
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -15,7 +22,28 @@ pub enum Error {
     #[error(transparent)]
     SignWith(#[from] sign_with::Error),
     #[error(transparent)]
+    Signer(#[from] signer::Error),
+    #[error(transparent)]
     Xdr(#[from] xdr::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(
+        "signature {index} is not a valid signature by {key} for network passphrase {passphrase:?}"
+    )]
+    InvalidSignature {
+        index: usize,
+        key: stellar_strkey::ed25519::PublicKey,
+        passphrase: String,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum Output {
+    /// Base64 encoded XDR of the signed transaction envelope
+    #[default]
+    Xdr,
+    /// JSON object with the signed envelope as base64 XDR, the transaction hash and the public key of the signer
+    Json,
 }
 
 #[derive(Debug, clap::Parser, Clone)]
@@ -27,19 +55,126 @@ pub struct Cmd {
     pub network: network::Args,
     #[command(flatten)]
     pub locator: locator::Args,
+    /// Encoding of the transaction envelope read from stdin
+    #[arg(long, value_enum, default_value_t)]
+    pub input_format: super::xdr::InputFormat,
+    /// Format of the signed transaction written to stdout
+    #[arg(long, value_enum, default_value_t)]
+    pub output: Output,
+    /// Check every signature on the signed envelope against the transaction hash before printing it. Signatures are matched to the signer, the transaction source and the operation sources by their hint
+    #[arg(long)]
+    pub verify: bool,
+}
+
+/// A signed transaction envelope, with the hash that was signed and who signed it.
+#[derive(Debug, Clone)]
+pub struct Signed {
+    pub envelope: TransactionEnvelope,
+    pub hash: [u8; 32],
+    /// `None` when signed through Lab.
+    pub signer: Option<stellar_strkey::ed25519::PublicKey>,
+}
+
+impl Signed {
+    pub fn to_json(&self) -> Result<serde_json::Value, Error> {
+        Ok(serde_json::json!({
+            "envelope": self.envelope.to_xdr_base64(Limits::none())?,
+            "hash": hex::encode(self.hash),
+            "signer": self.signer.as_ref().map(ToString::to_string),
+        }))
+    }
 }
 
 impl Cmd {
     #[allow(clippy::unused_async)]
     pub async fn run(&self, global_args: &global::Args) -> Result<(), Error> {
-        let tx_env = super::xdr::tx_envelope_from_stdin()?;
-        let tx_env_signed = self.sign_with.sign_tx_env(
-            &tx_env,
-            &self.locator,
-            &self.network.get(&self.locator)?,
-            global_args.quiet,
-        )?;
-        println!("{}", tx_env_signed.to_xdr_base64(Limits::none())?);
+        let tx_env = super::xdr::from_stdin_with_format(self.input_format)?;
+        self.network
+            .resolve(&self.locator, Some(global_args))
+            .await?;
+        let signed = self.sign(&tx_env, global_args.quiet)?;
+        let print = Print::from(global_args);
+        match self.output {
+            Output::Xdr => print.outln(signed.envelope.to_xdr_base64(Limits::none())?),
+            Output::Json => print.outln(serde_json::to_string(&signed.to_json()?)?),
+        }
+        Ok(())
+    }
+
+    pub fn sign(&self, tx_env: &TransactionEnvelope, quiet: bool) -> Result<Signed, Error> {
+        let network = self.network.get(&self.locator)?;
+        let signer = self.sign_with.signer(&self.locator, quiet)?;
+        let envelope = signer.sign_tx_env(tx_env, &network)?;
+        let hash = envelope_hash(&envelope, &network.network_passphrase)?;
+        let signed = Signed {
+            envelope,
+            hash,
+            signer: signer.public_key(),
+        };
+        if self.verify {
+            signed.verify(&network.network_passphrase, &Print::new(quiet))?;
+        }
+        Ok(signed)
+    }
+}
+
+impl Signed {
+    /// Checks each signature against the keys known to the envelope whose hint it carries.
+    /// Signatures from other keys cannot be checked and are reported as warnings.
+    fn verify(&self, passphrase: &str, print: &Print) -> Result<(), Error> {
+        let key = |account: &xdr::MuxedAccount| {
+            let xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(key))) =
+                account.clone().account_id();
+            key
+        };
+        let (mut keys, signatures) = match &self.envelope {
+            TransactionEnvelope::Tx(TransactionV1Envelope { tx, signatures }) => (
+                std::iter::once(&tx.source_account)
+                    .chain(
+                        tx.operations
+                            .iter()
+                            .filter_map(|op| op.source_account.as_ref()),
+                    )
+                    .map(key)
+                    .collect::<Vec<_>>(),
+                signatures,
+            ),
+            TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope { tx, signatures }) => {
+                (vec![key(&tx.fee_source)], signatures)
+            }
+            TransactionEnvelope::TxV0(_) => {
+                return Err(signer::Error::UnsupportedTransactionEnvelopeType.into());
+            }
+        };
+        keys.extend(self.signer.as_ref().map(|key| key.0));
+        keys.sort_unstable();
+        keys.dedup();
+        let mut verified = 0;
+        for (index, sig) in signatures.iter().enumerate() {
+            let candidates = keys
+                .iter()
+                .filter(|key| sig.hint.0[..] == key[28..])
+                .collect::<Vec<_>>();
+            if candidates.is_empty() {
+                print.warnln(format!(
+                    "Cannot verify signature {index}, its hint {} matches no known key",
+                    hex::encode(sig.hint.0)
+                ));
+                continue;
+            }
+            if !candidates
+                .iter()
+                .any(|key| verify_ed25519(key, &self.hash, sig))
+            {
+                return Err(Error::InvalidSignature {
+                    index,
+                    key: stellar_strkey::ed25519::PublicKey(*candidates[0]),
+                    passphrase: passphrase.to_string(),
+                });
+            }
+            verified += 1;
+        }
+        print.checkln(format!("Verified {verified} signature(s)"));
         Ok(())
     }
 }
@@ -1,6 +1,10 @@
 use crate::{
     assembled::{simulate_and_assemble_transaction, Assembled},
-    xdr::{self, TransactionEnvelope, WriteXdr},
+    print::Print,
+    xdr::{
+        self, SorobanResources, SorobanTransactionData, TransactionEnvelope, TransactionExt,
+        WriteXdr,
+    },
 };
 use async_trait::async_trait;
 
@@ -18,6 +22,17 @@ pub enum Error {
     Xdr(#[from] xdr::Error),
     #[error(transparent)]
     Network(#[from] config::network::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum Output {
+    /// Base64 encoded XDR of the assembled transaction envelope
+    #[default]
+    Xdr,
+    /// Table of the resources, minimum resource fee and events of the simulation
+    Table,
 }
 
 /// Command to simulate a transaction envelope via rpc
@@ -25,6 +40,9 @@ pub enum Error {
 #[derive(Debug, clap::Parser, Clone, Default)]
 #[group(skip)]
 pub struct Cmd {
+    /// Format of the simulation result written to stdout
+    #[arg(long, value_enum, default_value_t)]
+    pub output: Output,
     #[clap(flatten)]
     pub config: super::super::config::Args,
 }
@@ -34,12 +52,74 @@ impl Cmd {
         let res = self
             .run_against_rpc_server(Some(global_args), Some(&self.config))
             .await?;
-        let tx_env: TransactionEnvelope = res.transaction().clone().into();
-        println!("{}", tx_env.to_xdr_base64(xdr::Limits::none())?);
+        let print = Print::from(global_args);
+        match self.output {
+            Output::Xdr => {
+                let tx_env: TransactionEnvelope = res.transaction().clone().into();
+                print.outln(tx_env.to_xdr_base64(xdr::Limits::none())?);
+            }
+            Output::Table => print_table(&res, &print)?,
+        }
         Ok(())
     }
 }
 
+fn print_table(assembled: &Assembled, print: &Print) -> Result<(), Error> {
+    let sim = assembled.sim_response();
+    let events = sim.events()?;
+    let mut rows = vec![(
+        "Min resource fee",
+        format!("{} stroops", sim.min_resource_fee),
+    )];
+    let mut footprint = None;
+    if let TransactionExt::V1(SorobanTransactionData {
+        resources:
+            SorobanResources {
+                footprint: fp,
+                instructions,
+                read_bytes,
+                write_bytes,
+            },
+        ..
+    }) = &assembled.transaction().ext
+    {
+        rows.extend([
+            ("Instructions", instructions.to_string()),
+            ("Read bytes", read_bytes.to_string()),
+            ("Write bytes", write_bytes.to_string()),
+            ("Read-only entries", fp.read_only.len().to_string()),
+            ("Read-write entries", fp.read_write.len().to_string()),
+        ]);
+        footprint = Some(fp);
+    }
+    rows.push(("Events", events.len().to_string()));
+
+    let width = rows
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (name, value) in rows {
+        print.outln(format!("{name:<width$}  {value}"));
+    }
+    if let Some(fp) = footprint {
+        print.outln("\nFootprint");
+        for key in fp.read_only.as_slice() {
+            print.outln(format!("  read-only   {}", serde_json::to_string(key)?));
+        }
+        for key in fp.read_write.as_slice() {
+            print.outln(format!("  read-write  {}", serde_json::to_string(key)?));
+        }
+    }
+    if !events.is_empty() {
+        print.outln("\nEvents");
+        for (i, event) in events.iter().enumerate() {
+            print.outln(format!("  {i}: {}", serde_json::to_string(event)?));
+        }
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl NetworkRunnable for Cmd {
     type Error = Error;
@@ -47,14 +127,17 @@ impl NetworkRunnable for Cmd {
     type Result = Assembled;
     async fn run_against_rpc_server(
         &self,
-        _: Option<&global::Args>,
+        global_args: Option<&global::Args>,
         config: Option<&config::Args>,
     ) -> Result<Self::Result, Self::Error> {
+        config::network::ensure_online(global_args)?;
         let config = config.unwrap_or(&self.config);
-        let network = config.get_network()?;
+        let network = config.resolve_network(global_args).await?;
         let client = network.rpc_client()?;
         let tx = super::xdr::unwrap_envelope_v1(super::xdr::tx_envelope_from_stdin()?)?;
-        let tx = simulate_and_assemble_transaction(&client, &tx).await?;
+        let tx = network
+            .timeout(simulate_and_assemble_transaction(&client, &tx))
+            .await??;
         Ok(tx)
     }
 }
@@ -23,15 +23,38 @@ pub enum Error {
     TooManyOperations,
 }
 
+/// Encoding of XDR read from stdin.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, clap::ValueEnum)]
+pub enum InputFormat {
+    /// Base64 encoded XDR
+    #[default]
+    Base64,
+    /// Raw binary XDR
+    Raw,
+}
+
 pub fn tx_envelope_from_stdin() -> Result<TransactionEnvelope, Error> {
     from_stdin()
 }
 pub fn from_stdin<T: ReadXdr>() -> Result<T, Error> {
-    let mut buf = String::new();
+    from_stdin_with_format(InputFormat::Base64)
+}
+pub fn from_stdin_with_format<T: ReadXdr>(format: InputFormat) -> Result<T, Error> {
+    let mut buf = Vec::new();
     let _ = stdin()
-        .read_to_string(&mut buf)
+        .read_to_end(&mut buf)
         .map_err(|_| Error::StdinDecode)?;
-    T::from_xdr_base64(buf.trim(), Limits::none()).map_err(|_| Error::StdinDecode)
+    decode(&buf, format)
+}
+
+fn decode<T: ReadXdr>(buf: &[u8], format: InputFormat) -> Result<T, Error> {
+    match format {
+        InputFormat::Base64 => {
+            let buf = std::str::from_utf8(buf).map_err(|_| Error::StdinDecode)?;
+            T::from_xdr_base64(buf.trim(), Limits::none()).map_err(|_| Error::StdinDecode)
+        }
+        InputFormat::Raw => T::from_xdr(buf, Limits::none()).map_err(|_| Error::StdinDecode),
+    }
 }
 
 pub fn unwrap_envelope_v1(tx_env: TransactionEnvelope) -> Result<Transaction, Error> {
@@ -48,3 +71,39 @@ pub fn add_op(tx_env: TransactionEnvelope, op: Operation) -> Result<TransactionE
     tx.operations = ops.try_into().map_err(|_| Error::TooManyOperations)?;
     Ok(tx.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::xdr::{Limits, WriteXdr};
+
+    use super::*;
+
+    #[test]
+    fn raw_and_base64_decode_to_same_envelope() {
+        let tx_env = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: crate::xdr::MuxedAccount::Ed25519([1; 32].into()),
+                fee: 100,
+                seq_num: 1.into(),
+                cond: crate::xdr::Preconditions::None,
+                memo: crate::xdr::Memo::None,
+                operations: [Operation {
+                    source_account: None,
+                    body: crate::xdr::OperationBody::Inflation,
+                }]
+                .try_into()
+                .unwrap(),
+                ext: crate::xdr::TransactionExt::V0,
+            },
+            signatures: crate::xdr::VecM::default(),
+        });
+        let raw = tx_env.to_xdr(Limits::none()).unwrap();
+        let base64 = format!("{}\n", tx_env.to_xdr_base64(Limits::none()).unwrap());
+        let from_raw: TransactionEnvelope = decode(&raw, InputFormat::Raw).unwrap();
+        let from_base64: TransactionEnvelope =
+            decode(base64.as_bytes(), InputFormat::Base64).unwrap();
+        assert_eq!(from_raw, tx_env);
+        assert_eq!(from_base64, tx_env);
+        assert!(decode::<TransactionEnvelope>(&raw, InputFormat::Base64).is_err());
+    }
+}
@@ -1,14 +1,16 @@
 use clap::Parser;
 use std::fmt::Debug;
 
+use crate::{commands::global, print::Print};
+
 #[derive(Parser, Debug, Clone)]
 #[group(skip)]
 pub struct Cmd;
 
 impl Cmd {
     #[allow(clippy::unused_self)]
-    pub fn run(&self) {
-        println!("stellar {}", long());
+    pub fn run(&self, global_args: &global::Args) {
+        Print::from(global_args).outln(format!("stellar {}", long()));
     }
 }
 
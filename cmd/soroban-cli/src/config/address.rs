@@ -1,22 +1,72 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 use crate::xdr;
 
 use super::{locator, secret};
 
 /// Address can be either a public key or eventually an alias of a address.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub enum UnresolvedMuxedAccount {
     Resolved(xdr::MuxedAccount),
     AliasOrSecret(String),
 }
 
+impl std::fmt::Debug for UnresolvedMuxedAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnresolvedMuxedAccount::Resolved(account) => {
+                f.debug_tuple("Resolved").field(account).finish()
+            }
+            UnresolvedMuxedAccount::AliasOrSecret(s) => f
+                .debug_tuple("AliasOrSecret")
+                .field(&secret::SecretOrName(s))
+                .finish(),
+        }
+    }
+}
+
 impl Default for UnresolvedMuxedAccount {
     fn default() -> Self {
         UnresolvedMuxedAccount::AliasOrSecret(String::default())
     }
 }
 
+/// Where the account an [`UnresolvedMuxedAccount`] resolves to came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResolvedAddress {
+    /// A public key or muxed account given directly.
+    Literal(xdr::MuxedAccount),
+    /// A secret key or seed phrase given directly.
+    Secret(xdr::MuxedAccount),
+    /// An identity stored in the config directory.
+    Identity {
+        name: String,
+        account: xdr::MuxedAccount,
+    },
+}
+
+impl ResolvedAddress {
+    pub fn account(&self) -> &xdr::MuxedAccount {
+        match self {
+            ResolvedAddress::Literal(account)
+            | ResolvedAddress::Secret(account)
+            | ResolvedAddress::Identity { account, .. } => account,
+        }
+    }
+}
+
+impl Display for ResolvedAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolvedAddress::Literal(account) => write!(f, "{account} (literal address)"),
+            ResolvedAddress::Secret(account) => write!(f, "{account} (from secret key)"),
+            ResolvedAddress::Identity { name, account } => {
+                write!(f, "{account} (from identity {name:?})")
+            }
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error(transparent)]
@@ -64,6 +114,32 @@ impl UnresolvedMuxedAccount {
         })
     }
 
+    /// Report how this address resolves, without reading anything beyond what is needed to
+    /// derive its public key.
+    pub fn describe(
+        &self,
+        locator: &locator::Args,
+        hd_path: Option<usize>,
+    ) -> Result<ResolvedAddress, Error> {
+        match self {
+            UnresolvedMuxedAccount::Resolved(muxed_account) => {
+                Ok(ResolvedAddress::Literal(muxed_account.clone()))
+            }
+            UnresolvedMuxedAccount::AliasOrSecret(alias) => {
+                if let Ok(secret) = alias.parse::<secret::Secret>() {
+                    return Ok(ResolvedAddress::Secret(xdr::MuxedAccount::Ed25519(
+                        secret.public_key(hd_path)?.0.into(),
+                    )));
+                }
+                let account = Self::resolve_muxed_account_with_alias(alias, locator, hd_path)?;
+                Ok(ResolvedAddress::Identity {
+                    name: alias.clone(),
+                    account,
+                })
+            }
+        }
+    }
+
     pub fn resolve_secret(&self, locator: &locator::Args) -> Result<secret::Secret, Error> {
         match &self {
             UnresolvedMuxedAccount::Resolved(muxed_account) => {
@@ -73,3 +149,60 @@ impl UnresolvedMuxedAccount {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET_KEY: &str = "SBFGFF27Y64ZUGFAIG5AMJGQODZZKV2YQKAVUUN4HNE24XZXD2OEUVUP";
+
+    fn account(secret: &secret::Secret) -> xdr::MuxedAccount {
+        xdr::MuxedAccount::Ed25519(secret.public_key(None).unwrap().0.into())
+    }
+
+    #[test]
+    fn describe_literal() {
+        let secret: secret::Secret = SECRET_KEY.parse().unwrap();
+        let address = account(&secret).to_string();
+        let resolved = address
+            .parse::<UnresolvedMuxedAccount>()
+            .unwrap()
+            .describe(&locator::Args::default(), None)
+            .unwrap();
+        assert_eq!(resolved, ResolvedAddress::Literal(account(&secret)));
+    }
+
+    #[test]
+    fn describe_secret() {
+        let secret: secret::Secret = SECRET_KEY.parse().unwrap();
+        let resolved = SECRET_KEY
+            .parse::<UnresolvedMuxedAccount>()
+            .unwrap()
+            .describe(&locator::Args::default(), None)
+            .unwrap();
+        assert_eq!(resolved, ResolvedAddress::Secret(account(&secret)));
+    }
+
+    #[test]
+    fn describe_identity() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = locator::Args {
+            global: false,
+            config_dir: Some(dir.path().to_path_buf()),
+        };
+        let secret: secret::Secret = SECRET_KEY.parse().unwrap();
+        locator.write_identity("alice", &secret).unwrap();
+        let resolved = "alice"
+            .parse::<UnresolvedMuxedAccount>()
+            .unwrap()
+            .describe(&locator, None)
+            .unwrap();
+        assert_eq!(
+            resolved,
+            ResolvedAddress::Identity {
+                name: "alice".to_string(),
+                account: account(&secret),
+            }
+        );
+    }
+}
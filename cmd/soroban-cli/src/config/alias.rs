@@ -9,6 +9,11 @@ pub struct Data {
     pub ids: HashMap<String, String>,
 }
 
+#[derive(Serialize, Deserialize, Default)]
+pub struct AssetData {
+    pub asset: String,
+}
+
 /// Address can be either a contract address, C.. or eventually an alias of a contract address.
 #[derive(Clone, Debug)]
 pub enum UnresolvedContract {
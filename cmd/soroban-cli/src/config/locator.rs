@@ -3,6 +3,7 @@ use directories::UserDirs;
 use itertools::Itertools;
 use serde::de::DeserializeOwned;
 use std::{
+    collections::HashMap,
     ffi::OsStr,
     fmt::Display,
     fs::{self, create_dir_all, OpenOptions},
@@ -89,6 +90,10 @@ pub enum Error {
     KeyCannotOverlapWithContractAlias(String),
 }
 
+/// File in the config directory holding named network profiles, see
+/// [`Args::read_network_profile`].
+pub const NETWORK_PROFILES_FILE: &str = "networks.toml";
+
 #[derive(Debug, clap::Args, Default, Clone)]
 #[group(skip)]
 pub struct Args {
@@ -252,6 +257,9 @@ impl Args {
     pub fn read_network(&self, name: &str) -> Result<Network, Error> {
         let res = KeyType::Network.read_with_global(name, &self.local_config()?);
         if let Err(Error::ConfigMissing(_, _)) = &res {
+            if let Some(network) = self.read_network_profile(name)? {
+                return Ok(network);
+            }
             let Some(network) = network::DEFAULTS.get(name) else {
                 return res;
             };
@@ -260,6 +268,28 @@ impl Args {
         res
     }
 
+    /// Reads the network `name` from a `networks.toml` in the local or global config directory,
+    /// which holds one table per named network, e.g.
+    ///
+    /// ```toml
+    /// [mainnet]
+    /// rpc_url = "https://mainnet.example.org"
+    /// network_passphrase = "Public Global Stellar Network ; September 2015"
+    /// ```
+    pub fn read_network_profile(&self, name: &str) -> Result<Option<Network>, Error> {
+        for dir in [self.local_config()?, global_config_path()?] {
+            let path = dir.join(NETWORK_PROFILES_FILE);
+            if !path.exists() {
+                continue;
+            }
+            let mut profiles: HashMap<String, Network> = KeyType::read_from_path(&path)?;
+            if let Some(network) = profiles.remove(name) {
+                return Ok(Some(network));
+            }
+        }
+        Ok(None)
+    }
+
     pub fn remove_identity(&self, name: &str) -> Result<(), Error> {
         KeyType::Identity.remove(name, &self.config_dir()?)
     }
@@ -358,6 +388,38 @@ impl Args {
             .map_err(|e| Error::CannotParseContractId(alias.to_owned(), e))
     }
 
+    fn asset_alias_path(&self, alias: &str) -> Result<PathBuf, Error> {
+        let file_name = format!("{alias}.json");
+        let config_dir = self.config_dir()?;
+        Ok(config_dir.join("asset-aliases").join(file_name))
+    }
+
+    pub fn save_asset_alias(&self, alias: &str, asset: &str) -> Result<(), Error> {
+        let path = self.asset_alias_path(alias)?;
+        let dir = path.parent().ok_or(Error::CannotAccessConfigDir)?;
+
+        create_dir_all(dir).map_err(|_| Error::CannotAccessConfigDir)?;
+
+        let content = serde_json::to_string(&alias::AssetData {
+            asset: asset.to_owned(),
+        })?;
+
+        Ok(fs::write(path, content)?)
+    }
+
+    pub fn get_asset_alias(&self, alias: &str) -> Result<Option<String>, Error> {
+        let path = self.asset_alias_path(alias)?;
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        let data: alias::AssetData = serde_json::from_str(&content)?;
+
+        Ok(Some(data.asset))
+    }
+
     pub fn resolve_contract_id(
         &self,
         alias_or_contract_id: &str,
@@ -520,7 +582,7 @@ pub fn global_config_path() -> Result<PathBuf, Error> {
     let stellar_exists = stellar_dir.exists();
 
     if stellar_exists && soroban_exists {
-        tracing::warn!("the .stellar and .soroban config directories exist at path {config_dir:?}, using the .stellar");
+        tracing::warn!("the .stellar and .soroban config directories exist at path {config_dir:?}, using the .stellar, run `stellar config migrate --global` to merge them");
     }
 
     if stellar_exists {
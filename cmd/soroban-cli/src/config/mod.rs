@@ -6,6 +6,7 @@ use std::{
 };
 
 use crate::{
+    commands::global,
     print::Print,
     signer::{self, LocalKey, Signer, SignerKind},
     xdr::{self, SequenceNumber, Transaction, TransactionEnvelope},
@@ -43,6 +44,8 @@ pub enum Error {
     StellarStrkey(#[from] stellar_strkey::DecodeError),
     #[error(transparent)]
     Address(#[from] address::Error),
+    #[error("muxed account {0} cannot be the source account of this transaction, use its G... account instead")]
+    MuxedSourceAccount(String),
 }
 
 #[derive(Debug, clap::Args, Clone, Default)]
@@ -76,6 +79,24 @@ impl Args {
             .resolve_muxed_account(&self.locator, self.hd_path)?)
     }
 
+    /// The source account along with the ed25519 account id behind it. For a muxed source
+    /// account this is the id of its underlying account.
+    pub fn source_account_and_id(&self) -> Result<(xdr::MuxedAccount, xdr::AccountId), Error> {
+        let source_account = self.source_account()?;
+        let account_id = source_account.clone().account_id();
+        Ok((source_account, account_id))
+    }
+
+    /// The account id of the source account, for transactions that cannot have a muxed source.
+    pub fn unmuxed_source_account(&self) -> Result<xdr::AccountId, Error> {
+        match self.source_account_and_id()? {
+            (xdr::MuxedAccount::Ed25519(_), account_id) => Ok(account_id),
+            (muxed @ xdr::MuxedAccount::MuxedEd25519(_), _) => {
+                Err(Error::MuxedSourceAccount(muxed.to_string()))
+            }
+        }
+    }
+
     pub fn key_pair(&self) -> Result<ed25519_dalek::SigningKey, Error> {
         let key = &self.source_account.resolve_secret(&self.locator)?;
         Ok(key.key_pair(self.hd_path)?)
@@ -87,13 +108,18 @@ impl Args {
 
     #[allow(clippy::unused_async)]
     pub async fn sign(&self, tx: Transaction) -> Result<TransactionEnvelope, Error> {
-        let key = self.key_pair()?;
         let network = &self.get_network()?;
-        let signer = Signer {
-            kind: SignerKind::Local(LocalKey { key }),
-            print: Print::new(false),
-        };
-        Ok(signer.sign_tx(tx, network)?)
+        Ok(self.signer(Print::new(false))?.sign_tx(tx, network)?)
+    }
+
+    /// Signer for the local key of the source account.
+    pub fn signer(&self, print: Print) -> Result<Signer, Error> {
+        Ok(Signer {
+            kind: SignerKind::Local(LocalKey {
+                key: self.key_pair()?,
+            }),
+            print,
+        })
     }
 
     pub async fn sign_soroban_authorizations(
@@ -104,7 +130,7 @@ impl Args {
         let network = self.get_network()?;
         let source_key = self.key_pair()?;
         let client = network.rpc_client()?;
-        let latest_ledger = client.get_latest_ledger().await?.sequence;
+        let latest_ledger = network.timeout(client.get_latest_ledger()).await??.sequence;
         let seq_num = latest_ledger + 60; // ~ 5 min
         Ok(signer::sign_soroban_authorizations(
             tx,
@@ -119,15 +145,23 @@ impl Args {
         Ok(self.network.get(&self.locator)?)
     }
 
+    /// Resolves the network once for the command, see [`network::Args::resolve`].
+    pub async fn resolve_network(
+        &self,
+        global_args: Option<&global::Args>,
+    ) -> Result<Network, Error> {
+        Ok(self.network.resolve(&self.locator, global_args).await?)
+    }
+
     pub async fn next_sequence_number(
         &self,
         account: impl Into<xdr::AccountId>,
     ) -> Result<SequenceNumber, Error> {
         let network = self.get_network()?;
         let client = network.rpc_client()?;
-        Ok((client
-            .get_account(&account.into().to_string())
-            .await?
+        Ok((network
+            .timeout(client.get_account(&account.into().to_string()))
+            .await??
             .seq_num
             .0
             + 1)
@@ -155,6 +189,14 @@ impl ArgsLocatorAndNetwork {
     pub fn get_network(&self) -> Result<Network, Error> {
         Ok(self.network.get(&self.locator)?)
     }
+
+    /// Resolves the network once for the command, see [`network::Args::resolve`].
+    pub async fn resolve_network(
+        &self,
+        global_args: Option<&global::Args>,
+    ) -> Result<Network, Error> {
+        Ok(self.network.resolve(&self.locator, global_args).await?)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -200,3 +242,37 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT: &str = "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ";
+    const MUXED: &str = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAAAAFK542";
+
+    fn args(source: &str) -> Args {
+        Args {
+            source_account: source.parse().unwrap(),
+            ..Args::default()
+        }
+    }
+
+    #[test]
+    fn ed25519_source_account() {
+        let (source, account_id) = args(ACCOUNT).source_account_and_id().unwrap();
+        assert_eq!(source.to_string(), ACCOUNT);
+        assert_eq!(account_id.to_string(), ACCOUNT);
+        assert_eq!(args(ACCOUNT).unmuxed_source_account().unwrap(), account_id);
+    }
+
+    #[test]
+    fn muxed_source_account() {
+        let (source, account_id) = args(MUXED).source_account_and_id().unwrap();
+        assert_eq!(source.to_string(), MUXED);
+        assert_eq!(account_id.to_string(), ACCOUNT);
+        assert!(matches!(
+            args(MUXED).unmuxed_source_account(),
+            Err(Error::MuxedSourceAccount(account)) if account == MUXED
+        ));
+    }
+}
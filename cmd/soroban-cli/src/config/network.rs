@@ -6,14 +6,17 @@ use reqwest::header::{HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderV
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::future::Future;
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use stellar_strkey::ed25519::PublicKey;
 use url::Url;
 
 use super::locator;
 use crate::utils::http;
 use crate::{
-    commands::HEADING_RPC,
+    commands::{global, HEADING_RPC},
     rpc::{self, Client},
 };
 pub mod passphrase;
@@ -55,6 +58,32 @@ STELLAR_NETWORK, STELLAR_RPC_URL and STELLAR_NETWORK_PASSPHRASE"#
     InvalidHeaderValue(#[from] InvalidHeaderValue),
     #[error("invalid HTTP header: must be in the form 'key:value'")]
     InvalidHeader,
+    #[error("RPC request timed out after {0} seconds, use `--rpc-timeout` to wait longer")]
+    RpcTimeout(u64),
+    #[error("network access is disabled by `--offline` (or `STELLAR_OFFLINE`)")]
+    Offline,
+    #[error("`--auto-passphrase` is not supported by this command, use `--network-passphrase`")]
+    AutoPassphraseNotFetched,
+    #[error("{0} is not a known network, use `--rpc-url` and `--network-passphrase` instead")]
+    UnknownNetworkUrl(String),
+    #[error(
+        "cannot read the p{percentile} inclusion fee from the fee stats of {rpc_url}: {response}"
+    )]
+    InvalidFeeStats {
+        percentile: u8,
+        rpc_url: String,
+        response: Value,
+    },
+}
+
+/// Returns [`Error::Offline`] if network access is disabled with `--offline` in `global_args`.
+/// Commands call it before creating an RPC client or making any other request.
+pub fn ensure_online(global_args: Option<&global::Args>) -> Result<(), Error> {
+    if global_args.is_some_and(|args| args.offline) {
+        Err(Error::Offline)
+    } else {
+        Ok(())
+    }
 }
 
 #[derive(Debug, clap::Args, Clone, Default)]
@@ -63,10 +92,12 @@ pub struct Args {
     /// RPC server endpoint
     #[arg(
         long = "rpc-url",
-        env = "STELLAR_RPC_URL",
         help_heading = HEADING_RPC,
     )]
     pub rpc_url: Option<String>,
+    /// `STELLAR_RPC_URL`, kept apart from `--rpc-url` as only the flag overrides a named network
+    #[arg(skip = std::env::var("STELLAR_RPC_URL").ok())]
+    pub rpc_url_env: Option<String>,
     /// RPC Header(s) to include in requests to the RPC provider
     #[arg(
         long = "rpc-header",
@@ -81,35 +112,132 @@ pub struct Args {
     /// Network passphrase to sign the transaction sent to the rpc server
     #[arg(
         long = "network-passphrase",
-        env = "STELLAR_NETWORK_PASSPHRASE",
         help_heading = HEADING_RPC,
     )]
     pub network_passphrase: Option<String>,
-    /// Name of network to use from config
+    /// `STELLAR_NETWORK_PASSPHRASE`, kept apart from `--network-passphrase` like `rpc_url_env`
+    #[arg(skip = std::env::var("STELLAR_NETWORK_PASSPHRASE").ok())]
+    pub network_passphrase_env: Option<String>,
+    /// Name of network to use from config, `networks.toml` or the defaults. An RPC url and network passphrase given together are used instead of it. Otherwise `--rpc-url`, `--network-passphrase`, `--rpc-header` and `--rpc-timeout` override its values; `STELLAR_RPC_URL` and `STELLAR_NETWORK_PASSPHRASE` do not
     #[arg(
         long,
         env = "STELLAR_NETWORK",
         help_heading = HEADING_RPC,
     )]
     pub network: Option<String>,
+    /// Seconds to wait for a response from the RPC server before giving up
+    #[arg(
+        long = "rpc-timeout",
+        env = "STELLAR_RPC_TIMEOUT",
+        help_heading = HEADING_RPC,
+    )]
+    pub rpc_timeout: Option<u64>,
+    /// Fetch the network passphrase from the RPC server at `--rpc-url` instead of requiring `--network-passphrase`. A provided passphrase that doesn't match the server's is replaced, with a warning. Not allowed with `--offline`
+    #[arg(long, help_heading = HEADING_RPC)]
+    pub auto_passphrase: bool,
+    /// Passphrase fetched for `--auto-passphrase` by [`Args::resolve`], shared between clones so
+    /// that the rest of the command reuses it
+    #[arg(skip)]
+    pub fetched_passphrase: Arc<OnceLock<String>>,
+    /// Name (local, testnet, futurenet) or base URL of a standard network, from which the RPC url and network passphrase are inferred, e.g. `--network-url testnet` or `--network-url https://soroban-testnet.stellar.org`
+    #[arg(
+        long,
+        env = "STELLAR_NETWORK_URL",
+        help_heading = HEADING_RPC,
+        conflicts_with_all = ["rpc_url", "network_passphrase", "network"],
+    )]
+    pub network_url: Option<String>,
 }
 
 impl Args {
+    /// Resolves the network like [`Args::get`], first fetching the passphrase from the RPC server
+    /// when `--auto-passphrase` is given. It is fetched once, later calls on this command's
+    /// arguments and [`Args::get`] reuse it.
+    pub async fn resolve(
+        &self,
+        locator: &locator::Args,
+        global_args: Option<&global::Args>,
+    ) -> Result<Network, Error> {
+        let rpc_url = self.rpc_url.clone().or_else(|| self.rpc_url_env.clone());
+        if let (true, None, Some(rpc_url)) =
+            (self.auto_passphrase, self.fetched_passphrase.get(), rpc_url)
+        {
+            ensure_online(global_args)?;
+            let network = Network {
+                rpc_url,
+                rpc_headers: self.rpc_headers.clone(),
+                network_passphrase: String::new(),
+                rpc_timeout: self.rpc_timeout,
+            };
+            let fetched = network.fetch_network_passphrase().await?;
+            let provided = (self.network_passphrase.as_ref())
+                .or(self.network_passphrase_env.as_ref())
+                .filter(|p| **p != fetched);
+            if let Some(provided) = provided {
+                // Logged rather than printed, so that `--quiet` silences it like other
+                // warnings about the configuration.
+                tracing::warn!(
+                    "Network passphrase {provided:?} does not match the RPC server's \
+                    {fetched:?}, using the RPC server's"
+                );
+            }
+            let _ = self.fetched_passphrase.set(fetched);
+        }
+        self.get(locator)
+    }
+
+    /// Resolves the network from the arguments. With `--auto-passphrase` the passphrase has to
+    /// have been fetched by [`Args::resolve`].
     pub fn get(&self, locator: &locator::Args) -> Result<Network, Error> {
+        if let Some(network_url) = &self.network_url {
+            return Ok(Network {
+                rpc_headers: self.rpc_headers.clone(),
+                rpc_timeout: self.rpc_timeout,
+                ..Network::from_network_url(network_url)?
+            });
+        }
         match (
             self.network.as_deref(),
-            self.rpc_url.clone(),
-            self.network_passphrase.clone(),
+            self.rpc_url.clone().or_else(|| self.rpc_url_env.clone()),
+            self.network_passphrase
+                .clone()
+                .or_else(|| self.network_passphrase_env.clone()),
         ) {
             (None, None, None) => Err(Error::Network),
-            (_, Some(_), None) => Err(Error::MissingNetworkPassphrase),
-            (_, None, Some(_)) => Err(Error::MissingRpcUrl),
-            (Some(network), None, None) => Ok(locator.read_network(network)?),
+            (_, Some(rpc_url), _) if self.auto_passphrase => Ok(Network {
+                rpc_url,
+                rpc_headers: self.rpc_headers.clone(),
+                network_passphrase: self
+                    .fetched_passphrase
+                    .get()
+                    .ok_or(Error::AutoPassphraseNotFetched)?
+                    .clone(),
+                rpc_timeout: self.rpc_timeout,
+            }),
             (_, Some(rpc_url), Some(network_passphrase)) => Ok(Network {
                 rpc_url,
                 rpc_headers: self.rpc_headers.clone(),
                 network_passphrase,
+                rpc_timeout: self.rpc_timeout,
             }),
+            (Some(network), _, _) => {
+                let network = locator.read_network(network)?;
+                Ok(Network {
+                    rpc_url: self.rpc_url.clone().unwrap_or(network.rpc_url),
+                    rpc_headers: if self.rpc_headers.is_empty() {
+                        network.rpc_headers
+                    } else {
+                        self.rpc_headers.clone()
+                    },
+                    network_passphrase: self
+                        .network_passphrase
+                        .clone()
+                        .unwrap_or(network.network_passphrase),
+                    rpc_timeout: self.rpc_timeout.or(network.rpc_timeout),
+                })
+            }
+            (None, Some(_), None) => Err(Error::MissingNetworkPassphrase),
+            (None, None, Some(_)) => Err(Error::MissingRpcUrl),
         }
     }
 }
@@ -134,6 +262,7 @@ pub struct Network {
         value_delimiter = '\n',
         value_parser = parse_http_header,
     )]
+    #[serde(default)]
     pub rpc_headers: Vec<(String, String)>,
     /// Network passphrase to sign the transaction sent to the rpc server
     #[arg(
@@ -142,6 +271,14 @@ pub struct Network {
             help_heading = HEADING_RPC,
         )]
     pub network_passphrase: String,
+    /// Seconds to wait for a response from the RPC server before giving up
+    #[arg(
+        long = "rpc-timeout",
+        env = "STELLAR_RPC_TIMEOUT",
+        help_heading = HEADING_RPC,
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_timeout: Option<u64>,
 }
 
 fn parse_http_header(header: &str) -> Result<(String, String), Error> {
@@ -160,6 +297,32 @@ fn parse_http_header(header: &str) -> Result<(String, String), Error> {
 }
 
 impl Network {
+    /// Infer a standard network from its name in [`DEFAULTS`] or the base URL of its RPC server.
+    /// Only networks with a public RPC server are known, so `mainnet` needs `--rpc-url`.
+    pub fn from_network_url(network_url: &str) -> Result<Network, Error> {
+        let unknown = || Error::UnknownNetworkUrl(network_url.to_string());
+        if let Some(network) = DEFAULTS.get(network_url) {
+            return Url::parse(network.0)
+                .map(|_| network.into())
+                .map_err(|_| unknown());
+        }
+        let url = if network_url.contains("://") {
+            Url::parse(network_url)
+        } else {
+            Url::parse(&format!("https://{network_url}"))
+        }
+        .map_err(|_| unknown())?;
+        let same_server = |rpc_url: &Url| {
+            rpc_url.host_str() == url.host_str()
+                && rpc_url.port_or_known_default() == url.port_or_known_default()
+        };
+        DEFAULTS
+            .entries()
+            .find(|(_, (rpc_url, _))| Url::parse(rpc_url).is_ok_and(|u| same_server(&u)))
+            .map(|(_, network)| network.into())
+            .ok_or_else(unknown)
+    }
+
     pub async fn helper_url(&self, addr: &str) -> Result<Url, Error> {
         tracing::debug!("address {addr:?}");
         let rpc_url = Url::from_str(&self.rpc_url)
@@ -188,7 +351,7 @@ impl Network {
     pub async fn fund_address(&self, addr: &PublicKey) -> Result<(), Error> {
         let uri = self.helper_url(&addr.to_string()).await?;
         tracing::debug!("URL {uri:?}");
-        let response = http::client().get(uri.as_str()).send().await?;
+        let response = http::get(uri.as_str()).await?;
 
         let request_successful = response.status().is_success();
         let body = response.bytes().await?;
@@ -229,6 +392,54 @@ impl Network {
 
         Ok(rpc::Client::new_with_headers(&self.rpc_url, header_map)?)
     }
+
+    /// Fetch the passphrase of the network the RPC server is running on.
+    pub async fn fetch_network_passphrase(&self) -> Result<String, Error> {
+        let client = self.rpc_client()?;
+        Ok(self.timeout(client.get_network()).await??.passphrase)
+    }
+
+    /// Fetch the inclusion fee, in stroops, paid at the given percentile by transactions in
+    /// recent ledgers, using the RPC `getFeeStats` method.
+    pub async fn fetch_inclusion_fee(&self, percentile: u8) -> Result<u32, Error> {
+        let mut request = http::client().post(&self.rpc_url).json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getFeeStats",
+        }));
+        for (name, value) in &self.rpc_headers {
+            request = request.header(name, value);
+        }
+        let response = self
+            .timeout(async {
+                request
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<Value>()
+                    .await
+            })
+            .await??;
+        response["result"]["inclusionFee"][format!("p{percentile}")]
+            .as_str()
+            .and_then(|fee| fee.parse().ok())
+            .ok_or_else(|| Error::InvalidFeeStats {
+                percentile,
+                rpc_url: self.rpc_url.clone(),
+                response,
+            })
+    }
+
+    /// Awaits an RPC request, giving up with [`Error::RpcTimeout`] if `rpc_timeout` is set and
+    /// the request takes longer.
+    pub async fn timeout<F: Future>(&self, request: F) -> Result<F::Output, Error> {
+        let Some(secs) = self.rpc_timeout else {
+            return Ok(request.await);
+        };
+        tokio::time::timeout(Duration::from_secs(secs), request)
+            .await
+            .map_err(|_| Error::RpcTimeout(secs))
+    }
 }
 
 pub static DEFAULTS: phf::Map<&'static str, (&'static str, &'static str)> = phf_map! {
@@ -257,6 +468,7 @@ impl From<&(&str, &str)> for Network {
             rpc_url: n.0.to_string(),
             rpc_headers: Vec::new(),
             network_passphrase: n.1.to_string(),
+            rpc_timeout: None,
         }
     }
 }
@@ -266,6 +478,7 @@ mod tests {
     use super::*;
     use mockito::Server;
     use serde_json::json;
+    use std::io::Write;
 
     const INVALID_HEADER_NAME: &str = "api key";
     const INVALID_HEADER_VALUE: &str = "cannot include a carriage return \r in the value";
@@ -276,6 +489,7 @@ mod tests {
             rpc_url: "http://localhost:8000".to_string(),
             network_passphrase: passphrase::LOCAL.to_string(),
             rpc_headers: Vec::new(),
+            rpc_timeout: None,
         };
 
         let result = network
@@ -314,6 +528,7 @@ mod tests {
             rpc_url: server.url(),
             network_passphrase: passphrase::TESTNET.to_string(),
             rpc_headers: Vec::new(),
+            rpc_timeout: None,
         };
         let url = network
             .helper_url("GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI")
@@ -345,6 +560,7 @@ mod tests {
             rpc_url: server.url(),
             network_passphrase: passphrase::TESTNET.to_string(),
             rpc_headers: Vec::new(),
+            rpc_timeout: None,
         };
         let url = network
             .helper_url("GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI")
@@ -390,6 +606,7 @@ mod tests {
             rpc_url: "http://localhost:1234".to_string(),
             network_passphrase: "Network passphrase".to_string(),
             rpc_headers: [].to_vec(),
+            rpc_timeout: None,
         };
 
         let result = network.rpc_client();
@@ -402,6 +619,7 @@ mod tests {
             rpc_url: "http://localhost:1234".to_string(),
             network_passphrase: "Network passphrase".to_string(),
             rpc_headers: [("Authorization".to_string(), "Bearer 1234".to_string())].to_vec(),
+            rpc_timeout: None,
         };
 
         let result = network.rpc_client();
@@ -418,6 +636,7 @@ mod tests {
                 ("api-key".to_string(), "5678".to_string()),
             ]
             .to_vec(),
+            rpc_timeout: None,
         };
 
         let result = network.rpc_client();
@@ -430,6 +649,7 @@ mod tests {
             rpc_url: "http://localhost:8000".to_string(),
             network_passphrase: passphrase::LOCAL.to_string(),
             rpc_headers: [(INVALID_HEADER_NAME.to_string(), "Bearer".to_string())].to_vec(),
+            rpc_timeout: None,
         };
 
         let result = network.rpc_client();
@@ -446,6 +666,7 @@ mod tests {
             rpc_url: "http://localhost:8000".to_string(),
             network_passphrase: passphrase::LOCAL.to_string(),
             rpc_headers: [("api-key".to_string(), INVALID_HEADER_VALUE.to_string())].to_vec(),
+            rpc_timeout: None,
         };
 
         let result = network.rpc_client();
@@ -455,4 +676,243 @@ mod tests {
             format!("invalid HTTP header: must be in the form 'key:value'")
         );
     }
+
+    #[tokio::test]
+    async fn test_rpc_timeout_is_applied() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/")
+            .with_chunked_body(|w| {
+                std::thread::sleep(Duration::from_secs(3));
+                w.write_all(b"{}")
+            })
+            .create_async()
+            .await;
+
+        let network = Network {
+            rpc_url: server.url(),
+            network_passphrase: passphrase::TESTNET.to_string(),
+            rpc_headers: Vec::new(),
+            rpc_timeout: Some(1),
+        };
+        let client = network.rpc_client().unwrap();
+        let start = std::time::Instant::now();
+        let result = network.timeout(client.get_network()).await;
+
+        assert!(matches!(result, Err(Error::RpcTimeout(1))));
+        assert!(start.elapsed() < Duration::from_secs(3));
+    }
+
+    async fn mock_get_network(server: &mut Server) -> mockito::Mock {
+        server
+            .mock("POST", "/")
+            .expect(1)
+            .with_body_from_request(|req| {
+                let body: Value = serde_json::from_slice(req.body().unwrap()).unwrap();
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"].clone(),
+                    "result": {
+                        "friendbotUrl": "https://friendbot.stellar.org/",
+                        "passphrase": passphrase::TESTNET.to_string(),
+                        "protocolVersion": 22
+                    }
+                })
+                .to_string()
+                .into()
+            })
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_auto_passphrase_from_rpc() {
+        let mut server = Server::new_async().await;
+        let mock = mock_get_network(&mut server).await;
+        let args = Args {
+            rpc_url: Some(server.url()),
+            auto_passphrase: true,
+            ..Args::default()
+        };
+        let locator = locator::Args::default();
+        assert!(matches!(
+            args.get(&locator),
+            Err(Error::AutoPassphraseNotFetched)
+        ));
+        let network = args.resolve(&locator, None).await.unwrap();
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+        assert_eq!(network.rpc_url, server.url());
+
+        // The passphrase is fetched once, and shared with clones of the arguments
+        let args = args.clone();
+        let network = args.resolve(&locator, None).await.unwrap();
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+        let network = args.get(&locator).unwrap();
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_auto_passphrase_replaces_mismatched_passphrase() {
+        let mut server = Server::new_async().await;
+        let _mock = mock_get_network(&mut server).await;
+        let args = Args {
+            rpc_url: Some(server.url()),
+            network_passphrase: Some(passphrase::LOCAL.to_string()),
+            auto_passphrase: true,
+            ..Args::default()
+        };
+        let network = args.resolve(&locator::Args::default(), None).await.unwrap();
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+    }
+
+    #[tokio::test]
+    async fn test_auto_passphrase_is_not_fetched_offline() {
+        let args = Args {
+            rpc_url: Some("http://localhost:8000/rpc".to_string()),
+            auto_passphrase: true,
+            ..Args::default()
+        };
+        let global_args = global::Args {
+            offline: true,
+            ..global::Args::default()
+        };
+        let result = args
+            .resolve(&locator::Args::default(), Some(&global_args))
+            .await;
+        assert!(matches!(result, Err(Error::Offline)));
+    }
+
+    #[test]
+    fn test_network_url_shorthands() {
+        for (name, passphrase) in [
+            ("testnet", passphrase::TESTNET),
+            ("futurenet", passphrase::FUTURENET),
+            ("local", passphrase::LOCAL),
+        ] {
+            let args = Args {
+                network_url: Some(name.to_string()),
+                rpc_timeout: Some(5),
+                ..Args::default()
+            };
+            let network = args.get(&locator::Args::default()).unwrap();
+            assert_eq!(network.rpc_url, DEFAULTS[name].0);
+            assert_eq!(network.network_passphrase, passphrase);
+            assert_eq!(network.rpc_timeout, Some(5));
+        }
+    }
+
+    #[test]
+    fn test_network_url_base_urls() {
+        for (url, name) in [
+            ("https://soroban-testnet.stellar.org", "testnet"),
+            ("soroban-testnet.stellar.org", "testnet"),
+            ("https://rpc-futurenet.stellar.org", "futurenet"),
+            ("http://localhost:8000", "local"),
+        ] {
+            let network = Network::from_network_url(url).unwrap();
+            assert_eq!(network.rpc_url, DEFAULTS[name].0);
+            assert_eq!(network.network_passphrase, DEFAULTS[name].1);
+        }
+    }
+
+    #[test]
+    fn test_network_url_unknown() {
+        for url in ["mainnet", "https://example.com", "not a url"] {
+            assert!(matches!(
+                Network::from_network_url(url),
+                Err(Error::UnknownNetworkUrl(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_network_profile_with_flag_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_dir = dir.path().join(".stellar");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(
+            config_dir.join(locator::NETWORK_PROFILES_FILE),
+            format!(
+                r#"
+[staging]
+rpc_url = "https://staging.example.org/rpc"
+network_passphrase = "{}"
+rpc_timeout = 10
+"#,
+                passphrase::TESTNET
+            ),
+        )
+        .unwrap();
+        let locator = locator::Args {
+            global: false,
+            config_dir: Some(dir.path().to_path_buf()),
+        };
+
+        let args = Args {
+            network: Some("staging".to_string()),
+            ..Args::default()
+        };
+        let network = args.get(&locator).unwrap();
+        assert_eq!(network.rpc_url, "https://staging.example.org/rpc");
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+        assert_eq!(network.rpc_timeout, Some(10));
+        assert!(network.rpc_headers.is_empty());
+
+        let args = Args {
+            network: Some("staging".to_string()),
+            rpc_url: Some("http://localhost:8000/rpc".to_string()),
+            rpc_timeout: Some(30),
+            ..Args::default()
+        };
+        let network = args.get(&locator).unwrap();
+        assert_eq!(network.rpc_url, "http://localhost:8000/rpc");
+        assert_eq!(network.network_passphrase, passphrase::TESTNET);
+        assert_eq!(network.rpc_timeout, Some(30));
+
+        // `STELLAR_RPC_URL` doesn't override the named network
+        let args = Args {
+            network: Some("staging".to_string()),
+            rpc_url_env: Some("http://env.example.org/rpc".to_string()),
+            ..Args::default()
+        };
+        let network = args.get(&locator).unwrap();
+        assert_eq!(network.rpc_url, "https://staging.example.org/rpc");
+
+        // A flag overrides the named network even when the environment has the same value
+        let args = Args {
+            network: Some("staging".to_string()),
+            rpc_url: Some("http://env.example.org/rpc".to_string()),
+            rpc_url_env: Some("http://env.example.org/rpc".to_string()),
+            ..Args::default()
+        };
+        let network = args.get(&locator).unwrap();
+        assert_eq!(network.rpc_url, "http://env.example.org/rpc");
+    }
+
+    #[test]
+    fn test_rpc_url_and_passphrase_are_used_without_reading_the_named_network() {
+        // With both given, the named network is not read, so a missing one is not an error
+        let args = Args {
+            network: Some("missing".to_string()),
+            rpc_url_env: Some("http://localhost:8000/rpc".to_string()),
+            network_passphrase_env: Some(passphrase::LOCAL.to_string()),
+            ..Args::default()
+        };
+        let network = args.get(&locator::Args::default()).unwrap();
+        assert_eq!(network.rpc_url, "http://localhost:8000/rpc");
+        assert_eq!(network.network_passphrase, passphrase::LOCAL);
+    }
+
+    #[test]
+    fn test_missing_passphrase_without_auto_passphrase() {
+        let args = Args {
+            rpc_url: Some("http://localhost:8000/rpc".to_string()),
+            ..Args::default()
+        };
+        assert!(matches!(
+            args.get(&locator::Args::default()),
+            Err(Error::MissingNetworkPassphrase)
+        ));
+    }
 }
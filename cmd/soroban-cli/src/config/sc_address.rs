@@ -2,16 +2,30 @@ use std::str::FromStr;
 
 use crate::xdr;
 
-use super::{address, locator, UnresolvedContract};
+use super::{address, locator, secret, UnresolvedContract};
 
 /// `ScAddress` can be either a resolved `xdr::ScAddress` or an alias of a `Contract` or `MuxedAccount`.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub enum UnresolvedScAddress {
     Resolved(xdr::ScAddress),
     Alias(String),
 }
 
+impl std::fmt::Debug for UnresolvedScAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnresolvedScAddress::Resolved(address) => {
+                f.debug_tuple("Resolved").field(address).finish()
+            }
+            UnresolvedScAddress::Alias(alias) => f
+                .debug_tuple("Alias")
+                .field(&secret::SecretOrName(alias))
+                .finish(),
+        }
+    }
+}
+
 impl Default for UnresolvedScAddress {
     fn default() -> Self {
         UnresolvedScAddress::Alias(String::default())
@@ -1,6 +1,17 @@
 use clap::arg;
+use ring::{
+    aead::{self, Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
 use serde::{Deserialize, Serialize};
-use std::{io::Write, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    num::NonZeroU32,
+    str::FromStr,
+    sync::{Mutex, PoisonError},
+};
 use stellar_strkey::ed25519::{PrivateKey, PublicKey};
 
 use crate::{
@@ -25,8 +36,32 @@ pub enum Error {
     InvalidSecretOrSeedPhrase,
     #[error(transparent)]
     Signer(#[from] signer::Error),
+    #[error("failed to encrypt secret")]
+    Encryption,
+    #[error("failed to decrypt secret, check that the passphrase is correct")]
+    Decryption,
+    #[error("secret is already encrypted")]
+    AlreadyEncrypted,
+    #[error("passphrases do not match")]
+    PassphraseMismatch,
+    #[error("invalid encrypted secret: {0}")]
+    InvalidEncryptedSecret(String),
 }
 
+/// Environment variable that provides the passphrase for encrypted identities instead of
+/// prompting for it.
+pub const PASSPHRASE_ENV: &str = "STELLAR_SECRET_PASSPHRASE";
+
+const SALT_LEN: usize = 16;
+const PBKDF2_ITERATIONS: NonZeroU32 = match NonZeroU32::new(600_000) {
+    Some(iterations) => iterations,
+    None => unreachable!(),
+};
+
+/// Secrets decrypted so far, keyed by their ciphertext, so that a command asks for the
+/// passphrase of an identity only once however often it needs the key.
+static DECRYPTED: Mutex<BTreeMap<String, Secret>> = Mutex::new(BTreeMap::new());
+
 #[derive(Debug, clap::Args, Clone)]
 #[group(skip)]
 pub struct Args {
@@ -52,11 +87,78 @@ impl Args {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Secret material, e.g. a secret key or seed phrase, which is redacted when formatted with
+/// `Debug` or `Display`, so that it doesn't end up in logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Redacted<T>(pub T);
+
+impl<T> std::fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T> std::fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// An argument that is either a secret key or seed phrase, or a name such as an identity. Its
+/// `Debug` output is [`Redacted`] when it is a secret, and the name otherwise.
+pub struct SecretOrName<'a>(pub &'a str);
+
+impl std::fmt::Debug for SecretOrName<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.parse::<Secret>().is_ok() {
+            std::fmt::Debug::fmt(&Redacted(self.0), f)
+        } else {
+            std::fmt::Debug::fmt(self.0, f)
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Secret {
-    SecretKey { secret_key: String },
-    SeedPhrase { seed_phrase: String },
+    SecretKey {
+        secret_key: String,
+    },
+    SeedPhrase {
+        seed_phrase: String,
+    },
+    /// A secret key or seed phrase encrypted with ChaCha20-Poly1305, using a key derived from a
+    /// passphrase with PBKDF2-HMAC-SHA256. All fields are hex encoded.
+    Encrypted {
+        salt: String,
+        nonce: String,
+        ciphertext: String,
+    },
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Secret::SecretKey { secret_key } => f
+                .debug_struct("SecretKey")
+                .field("secret_key", &Redacted(secret_key))
+                .finish(),
+            Secret::SeedPhrase { seed_phrase } => f
+                .debug_struct("SeedPhrase")
+                .field("seed_phrase", &Redacted(seed_phrase))
+                .finish(),
+            Secret::Encrypted {
+                salt,
+                nonce,
+                ciphertext,
+            } => f
+                .debug_struct("Encrypted")
+                .field("salt", salt)
+                .field("nonce", nonce)
+                .field("ciphertext", ciphertext)
+                .finish(),
+        }
+    }
 }
 
 impl FromStr for Secret {
@@ -95,9 +197,90 @@ impl Secret {
                     .private()
                     .0,
             )?,
+            Secret::Encrypted { .. } => self.decrypted()?.private_key(index)?,
+        })
+    }
+
+    /// The plaintext of an encrypted secret, prompting for the passphrase the first time it is
+    /// needed. Plaintext secrets are returned as is.
+    fn decrypted(&self) -> Result<Self, Error> {
+        self.decrypted_with(&DECRYPTED, || {
+            read_passphrase("Enter passphrase to decrypt identity:")
         })
     }
 
+    /// Like [`Secret::decrypted`], with the cache of decrypted secrets and the source of the
+    /// passphrase given.
+    fn decrypted_with(
+        &self,
+        cache: &Mutex<BTreeMap<String, Secret>>,
+        passphrase: impl FnOnce() -> Result<String, Error>,
+    ) -> Result<Self, Error> {
+        let Secret::Encrypted { ciphertext, .. } = self else {
+            return Ok(self.clone());
+        };
+        let mut decrypted = cache.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(secret) = decrypted.get(ciphertext) {
+            return Ok(secret.clone());
+        }
+        let secret = self.decrypt(&passphrase()?)?;
+        decrypted.insert(ciphertext.clone(), secret.clone());
+        Ok(secret)
+    }
+
+    /// Encrypt a plaintext secret with a key derived from `passphrase`.
+    pub fn encrypt(&self, passphrase: &str) -> Result<Self, Error> {
+        let plaintext = match self {
+            Secret::SecretKey { secret_key } => secret_key,
+            Secret::SeedPhrase { seed_phrase } => seed_phrase,
+            Secret::Encrypted { .. } => return Err(Error::AlreadyEncrypted),
+        };
+        let rng = SystemRandom::new();
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        rng.fill(&mut salt).map_err(|_| Error::Encryption)?;
+        rng.fill(&mut nonce).map_err(|_| Error::Encryption)?;
+        let key = derive_key(passphrase, &salt).map_err(|_| Error::Encryption)?;
+        let mut ciphertext = plaintext.as_bytes().to_vec();
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut ciphertext,
+        )
+        .map_err(|_| Error::Encryption)?;
+        Ok(Secret::Encrypted {
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt an encrypted secret with `passphrase`. Plaintext secrets are returned as is.
+    pub fn decrypt(&self, passphrase: &str) -> Result<Self, Error> {
+        let Secret::Encrypted {
+            salt,
+            nonce,
+            ciphertext,
+        } = self
+        else {
+            return Ok(self.clone());
+        };
+        let decode = |field: &str| {
+            hex::decode(field).map_err(|e| Error::InvalidEncryptedSecret(e.to_string()))
+        };
+        let nonce = Nonce::try_assume_unique_for_key(&decode(nonce)?).map_err(|_| {
+            Error::InvalidEncryptedSecret(format!("nonce must be {} bytes", aead::NONCE_LEN))
+        })?;
+        let key = derive_key(passphrase, &decode(salt)?).map_err(|_| Error::Decryption)?;
+        let mut ciphertext = decode(ciphertext)?;
+        let plaintext = key
+            .open_in_place(nonce, Aad::empty(), &mut ciphertext)
+            .map_err(|_| Error::Decryption)?;
+        std::str::from_utf8(plaintext)
+            .map_err(|_| Error::Decryption)?
+            .parse()
+    }
+
     pub fn public_key(&self, index: Option<usize>) -> Result<PublicKey, Error> {
         let key = self.key_pair(index)?;
         Ok(stellar_strkey::ed25519::PublicKey::from_payload(
@@ -107,7 +290,7 @@ impl Secret {
 
     pub fn signer(&self, index: Option<usize>, print: Print) -> Result<Signer, Error> {
         let kind = match self {
-            Secret::SecretKey { .. } | Secret::SeedPhrase { .. } => {
+            Secret::SecretKey { .. } | Secret::SeedPhrase { .. } | Secret::Encrypted { .. } => {
                 let key = self.key_pair(index)?;
                 SignerKind::Local(LocalKey { key })
             }
@@ -115,6 +298,12 @@ impl Secret {
         Ok(Signer { kind, print })
     }
 
+    /// Like [`Secret::signer`], but the returned signer never prints to stderr, for library
+    /// consumers signing outside of the CLI.
+    pub fn signer_silent(&self, index: Option<usize>) -> Result<Signer, Error> {
+        self.signer(index, Print::new(true))
+    }
+
     pub fn key_pair(&self, index: Option<usize>) -> Result<ed25519_dalek::SigningKey, Error> {
         Ok(utils::into_signing_key(&self.private_key(index)?))
     }
@@ -135,7 +324,173 @@ impl Secret {
     }
 }
 
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<LessSafeKey, ring::error::Unspecified> {
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        PBKDF2_ITERATIONS,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    Ok(LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, &key)?))
+}
+
+/// Read the passphrase of an encrypted identity from `STELLAR_SECRET_PASSPHRASE`, or prompt for it.
+pub fn read_passphrase(prompt: &str) -> Result<String, Error> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    eprintln!("{prompt}");
+    read_password()
+}
+
+/// Read a new passphrase from `STELLAR_SECRET_PASSPHRASE`, or prompt for it twice.
+pub fn read_new_passphrase() -> Result<String, Error> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    let passphrase = read_passphrase("Enter a passphrase to encrypt the identity:")?;
+    if passphrase != read_passphrase("Confirm passphrase:")? {
+        return Err(Error::PassphraseMismatch);
+    }
+    Ok(passphrase)
+}
+
 fn read_password() -> Result<String, Error> {
     std::io::stdout().flush().map_err(|_| Error::PasswordRead)?;
     rpassword::read_password().map_err(|_| Error::PasswordRead)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET_KEY: &str = "SBFGFF27Y64ZUGFAIG5AMJGQODZZKV2YQKAVUUN4HNE24XZXD2OEUVUP";
+
+    #[test]
+    fn debug_redacts_secret_material() {
+        let redacted = format!("{:?} {}", Redacted(SECRET_KEY), Redacted(SECRET_KEY));
+        assert_eq!(redacted, "[REDACTED] [REDACTED]");
+
+        let secret: Secret = SECRET_KEY.parse().unwrap();
+        assert!(!format!("{secret:?}").contains(&SECRET_KEY[1..]));
+
+        let seed_phrase = Secret::test_seed_phrase().unwrap();
+        let Secret::SeedPhrase { seed_phrase: words } = &seed_phrase else {
+            panic!("expected a seed phrase");
+        };
+        let debug = format!("{seed_phrase:?}");
+        assert!(words.split(' ').all(|word| !debug.contains(word)));
+
+        assert_eq!(format!("{:?}", SecretOrName(SECRET_KEY)), "[REDACTED]");
+        assert_eq!(format!("{:?}", SecretOrName(words)), "[REDACTED]");
+        assert_eq!(format!("{:?}", SecretOrName("alice")), "\"alice\"");
+    }
+
+    #[test]
+    fn encrypt_decrypt_secret_key() {
+        let secret: Secret = SECRET_KEY.parse().unwrap();
+        let encrypted = secret.encrypt("correct horse").unwrap();
+        assert!(matches!(encrypted, Secret::Encrypted { .. }));
+        let decrypted = encrypted.decrypt("correct horse").unwrap();
+        assert_eq!(
+            decrypted.private_key(None).unwrap(),
+            secret.private_key(None).unwrap()
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_seed_phrase() {
+        let secret = Secret::test_seed_phrase().unwrap();
+        let encrypted = secret.encrypt("correct horse").unwrap();
+        let decrypted = encrypted.decrypt("correct horse").unwrap();
+        assert_eq!(
+            decrypted.private_key(Some(1)).unwrap(),
+            secret.private_key(Some(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn decrypt_with_wrong_passphrase_fails() {
+        let encrypted = SECRET_KEY
+            .parse::<Secret>()
+            .unwrap()
+            .encrypt("correct horse")
+            .unwrap();
+        assert!(matches!(
+            encrypted.decrypt("battery staple"),
+            Err(Error::Decryption)
+        ));
+    }
+
+    #[test]
+    fn decrypts_once_per_process() {
+        let secret: Secret = SECRET_KEY.parse().unwrap();
+        let encrypted = secret.encrypt("correct horse").unwrap();
+        let cache = Mutex::default();
+        let decrypted = encrypted
+            .decrypted_with(&cache, || Ok("correct horse".to_string()))
+            .unwrap();
+        assert_eq!(
+            decrypted.private_key(None).unwrap(),
+            secret.private_key(None).unwrap()
+        );
+        let decrypted = encrypted
+            .decrypted_with(&cache, || panic!("the passphrase was asked for again"))
+            .unwrap();
+        assert_eq!(
+            decrypted.private_key(None).unwrap(),
+            secret.private_key(None).unwrap()
+        );
+    }
+
+    #[test]
+    fn reads_encrypted_and_plaintext_identity_files() {
+        let encrypted = SECRET_KEY
+            .parse::<Secret>()
+            .unwrap()
+            .encrypt("correct horse")
+            .unwrap();
+        let toml = toml::to_string(&encrypted).unwrap();
+        assert!(!toml.contains(SECRET_KEY));
+        let read: Secret = toml::from_str(&toml).unwrap();
+        assert!(matches!(read, Secret::Encrypted { .. }));
+
+        let plaintext: Secret = toml::from_str(&format!("secret_key = \"{SECRET_KEY}\"")).unwrap();
+        assert!(matches!(plaintext, Secret::SecretKey { .. }));
+    }
+
+    #[test]
+    fn silent_signer_signs() {
+        use crate::{
+            config::network::Network,
+            xdr::{self, TransactionEnvelope},
+        };
+
+        let secret: Secret = SECRET_KEY.parse().unwrap();
+        let signer = secret.signer_silent(None).unwrap();
+        assert!(signer.print.quiet);
+        let tx = xdr::Transaction {
+            source_account: xdr::MuxedAccount::Ed25519(xdr::Uint256(
+                secret.public_key(None).unwrap().0,
+            )),
+            fee: 100,
+            seq_num: xdr::SequenceNumber(1),
+            cond: xdr::Preconditions::None,
+            memo: xdr::Memo::None,
+            operations: xdr::VecM::default(),
+            ext: xdr::TransactionExt::V0,
+        };
+        let network = Network {
+            rpc_url: String::new(),
+            rpc_headers: vec![],
+            network_passphrase: "passphrase".to_string(),
+            rpc_timeout: None,
+        };
+        let TransactionEnvelope::Tx(signed) = signer.sign_tx(tx, &network).unwrap() else {
+            panic!("expected a v1 envelope");
+        };
+        assert_eq!(signed.signatures.len(), 1);
+    }
+}
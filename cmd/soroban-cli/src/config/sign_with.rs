@@ -31,7 +31,7 @@ pub enum Error {
     Xdr(#[from] xdr::Error),
 }
 
-#[derive(Debug, clap::Args, Clone, Default)]
+#[derive(clap::Args, Clone, Default)]
 #[group(skip)]
 pub struct Args {
     /// Sign with a local key. Can be an identity (--sign-with-key alice), a secret key (--sign-with-key SC36…), or a seed phrase (--sign-with-key "kite urban…"). If using seed phrase, `--hd-path` defaults to the `0` path.
@@ -48,6 +48,19 @@ pub struct Args {
     pub sign_with_lab: bool,
 }
 
+impl std::fmt::Debug for Args {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Args")
+            .field(
+                "sign_with_key",
+                &self.sign_with_key.as_deref().map(secret::SecretOrName),
+            )
+            .field("hd_path", &self.hd_path)
+            .field("sign_with_lab", &self.sign_with_lab)
+            .finish()
+    }
+}
+
 impl Args {
     pub fn sign_tx_env(
         &self,
@@ -56,8 +69,13 @@ impl Args {
         network: &Network,
         quiet: bool,
     ) -> Result<TransactionEnvelope, Error> {
+        let signer = self.signer(locator, quiet)?;
+        Ok(signer.sign_tx_env(tx, network)?)
+    }
+
+    pub fn signer(&self, locator: &locator::Args, quiet: bool) -> Result<Signer, Error> {
         let print = Print::new(quiet);
-        let signer = if self.sign_with_lab {
+        Ok(if self.sign_with_lab {
             Signer {
                 kind: SignerKind::Lab,
                 print,
@@ -66,7 +84,6 @@ impl Args {
             let key_or_name = self.sign_with_key.as_deref().ok_or(Error::NoSignWithKey)?;
             let secret = locator.key(key_or_name)?;
             secret.signer(self.hd_path, print)?
-        };
-        Ok(signer.sign_tx_env(tx, network)?)
+        })
     }
 }
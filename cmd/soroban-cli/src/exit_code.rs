@@ -0,0 +1,337 @@
+//! Exit codes of the CLI. They are stable, so that scripts can tell classes of failures apart.
+use crate::{
+    commands::{self, contract, keys, tx},
+    config::{self, network},
+    signer, utils,
+};
+
+/// Any failure that isn't one of the classes below.
+pub const GENERIC: i32 = 1;
+/// Invalid arguments or configuration, also used by clap for parsing errors.
+pub const USAGE: i32 = 2;
+/// The RPC server or another network service could not be reached, or failed the request.
+pub const NETWORK: i32 = 3;
+/// The user declined to sign.
+pub const SIGNING_DECLINED: i32 = 4;
+
+/// Maps an error to one of the exit codes above.
+pub trait ExitCode {
+    fn exit_code(&self) -> i32;
+}
+
+impl ExitCode for commands::Error {
+    fn exit_code(&self) -> i32 {
+        use commands::Error;
+        match self {
+            Error::Clap(_) => USAGE,
+            Error::Contract(e) => e.exit_code(),
+            Error::Keys(e) => e.exit_code(),
+            Error::Tx(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::Error;
+        match self {
+            Error::Asset(contract::asset::Error::Deploy(e)) => e.exit_code(),
+            Error::Deploy(e) => e.exit_code(),
+            Error::Extend(e) => e.exit_code(),
+            Error::Install(e) => e.exit_code(),
+            Error::Invoke(e) => e.exit_code(),
+            Error::Read(e) => e.exit_code(),
+            Error::Restore(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::deploy::asset::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::deploy::asset::Error;
+        match self {
+            Error::Client(_) => NETWORK,
+            Error::Submit(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::deploy::wasm::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::deploy::wasm::Error;
+        match self {
+            Error::Rpc(_) | Error::JsonRpc(_) => NETWORK,
+            Error::RemoteWasm(e) => e.exit_code(),
+            Error::Submit(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::extend::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::extend::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Polling(e) => e.exit_code(),
+            Error::Submit(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::install::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::install::Error;
+        match self {
+            Error::Rpc(_) | Error::JsonRpc(_) => NETWORK,
+            Error::Polling(e) => e.exit_code(),
+            Error::Submit(e) => e.exit_code(),
+            Error::Restore(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::invoke::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::invoke::Error;
+        match self {
+            Error::Clap(_) => USAGE,
+            Error::Rpc(_) => NETWORK,
+            Error::Polling(e) => e.exit_code(),
+            Error::Submit(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::read::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::read::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for contract::restore::Error {
+    fn exit_code(&self) -> i32 {
+        use contract::restore::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Polling(e) => e.exit_code(),
+            Error::Submit(e) => e.exit_code(),
+            Error::Extend(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for keys::Error {
+    fn exit_code(&self) -> i32 {
+        use keys::{fund, Error};
+        match self {
+            Error::Fund(fund::Error::Network(e)) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for tx::Error {
+    fn exit_code(&self) -> i32 {
+        use tx::Error;
+        match self {
+            Error::New(e) => e.exit_code(),
+            Error::Send(e) => e.exit_code(),
+            Error::Sign(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for tx::new::Error {
+    fn exit_code(&self) -> i32 {
+        use tx::new::{bump_sequence, payment, Error};
+        match self {
+            Error::Tx(e)
+            | Error::BumpSequence(bump_sequence::Error::Tx(e))
+            | Error::Payment(payment::Error::Tx(e)) => e.exit_code(),
+            Error::BumpSequence(bump_sequence::Error::Config(e))
+            | Error::Payment(payment::Error::Config(e)) => e.exit_code(),
+            Error::Payment(payment::Error::Network(e)) => e.exit_code(),
+            Error::Payment(payment::Error::Rpc(_)) => NETWORK,
+            Error::BumpSequence(_)
+            | Error::Asset(_)
+            | Error::Payment(
+                payment::Error::InsufficientBalance { .. } | payment::Error::Asset(_),
+            ) => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for tx::args::Error {
+    fn exit_code(&self) -> i32 {
+        use tx::args::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Polling(e) => e.exit_code(),
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            Error::Signer(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for tx::send::Error {
+    fn exit_code(&self) -> i32 {
+        use tx::send::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Config(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+            Error::Polling(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for tx::sign::Error {
+    fn exit_code(&self) -> i32 {
+        use tx::sign::Error;
+        match self {
+            Error::Network(e) => e.exit_code(),
+            Error::Signer(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for crate::tx::submit::Error {
+    fn exit_code(&self) -> i32 {
+        use crate::tx::submit::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Signer(e) => e.exit_code(),
+            Error::Polling(e) => e.exit_code(),
+            Error::Network(e) => e.exit_code(),
+        }
+    }
+}
+
+impl ExitCode for config::Error {
+    fn exit_code(&self) -> i32 {
+        use config::Error;
+        match self {
+            Error::Rpc(_) => NETWORK,
+            Error::Network(e) => e.exit_code(),
+            Error::Signer(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for network::Error {
+    fn exit_code(&self) -> i32 {
+        use network::Error;
+        match self {
+            Error::Rpc(_)
+            | Error::HttpClient(_)
+            | Error::FailedToParseJSON(..)
+            | Error::FundingFailed(_)
+            | Error::RpcTimeout(_)
+            | Error::InvalidFeeStats { .. } => NETWORK,
+            Error::Network
+            | Error::MissingNetworkPassphrase
+            | Error::MissingRpcUrl
+            | Error::CannotUseBothRpcAndNetwork
+            | Error::InvalidUrl(_)
+            | Error::InvalidHeaderName(_)
+            | Error::InvalidHeaderValue(_)
+            | Error::InvalidHeader
+            | Error::Offline
+            | Error::AutoPassphraseNotFetched
+            | Error::UnknownNetworkUrl(_) => USAGE,
+            Error::Config(_) => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for signer::Error {
+    fn exit_code(&self) -> i32 {
+        use signer::Error;
+        match self {
+            Error::UserCancelledSigning => SIGNING_DECLINED,
+            _ => GENERIC,
+        }
+    }
+}
+
+impl ExitCode for utils::rpc::Error {
+    fn exit_code(&self) -> i32 {
+        use utils::rpc::Error;
+        match self {
+            Error::Rpc(_) | Error::TransactionTimeout { .. } => NETWORK,
+            Error::Network(e) => e.exit_code(),
+            _ => GENERIC,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_errors_map_to_their_class() {
+        let err = tx::args::Error::Network(network::Error::RpcTimeout(30));
+        let err = commands::Error::Tx(tx::Error::New(tx::new::Error::Tx(err)));
+        assert_eq!(err.exit_code(), NETWORK);
+
+        let err = tx::send::Error::Network(network::Error::MissingRpcUrl);
+        assert_eq!(commands::Error::Tx(tx::Error::Send(err)).exit_code(), USAGE);
+
+        let err = config::Error::Signer(signer::Error::UserCancelledSigning);
+        let err = tx::new::Error::Tx(tx::args::Error::Config(err));
+        assert_eq!(
+            commands::Error::Tx(tx::Error::New(err)).exit_code(),
+            SIGNING_DECLINED
+        );
+
+        let err = tx::new::bump_sequence::Error::Overflow {
+            current: i64::MAX,
+            bump_by: 1,
+        };
+        let err = tx::Error::New(tx::new::Error::BumpSequence(err));
+        assert_eq!(commands::Error::Tx(err).exit_code(), GENERIC);
+
+        let timeout = || network::Error::RpcTimeout(30);
+        let err = contract::extend::Error::Network(timeout());
+        assert_eq!(contract::Error::Extend(err).exit_code(), NETWORK);
+        let err = contract::restore::Error::Submit(crate::tx::submit::Error::Network(timeout()));
+        assert_eq!(contract::Error::Restore(err).exit_code(), NETWORK);
+        let err = contract::install::Error::Restore(contract::restore::Error::Network(timeout()));
+        assert_eq!(contract::Error::Install(err).exit_code(), NETWORK);
+        let err = contract::read::Error::Network(timeout());
+        assert_eq!(contract::Error::Read(err).exit_code(), NETWORK);
+    }
+}
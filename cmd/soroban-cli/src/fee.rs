@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use clap::arg;
 
 use crate::assembled::Assembled;
+use crate::config::network::{self, Network};
 use crate::xdr;
 
 use crate::{commands::HEADING_RPC, deprecated_arg};
@@ -9,21 +12,47 @@ const DEPRECATION_MESSAGE: &str = "--sim-only is deprecated and will be removed
 in the future versions of CLI. The same functionality is offered by `tx simulate` command. To \
 replicate the behaviour, run `stellar <command> --build only | stellar tx simulate`";
 
+/// Percentiles of recent inclusion fees reported by the RPC `getFeeStats` method.
+const FEE_PERCENTILES: [u8; 12] = [10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
 #[derive(Debug, clap::Args, Clone)]
 #[group(skip)]
 pub struct Args {
     /// fee amount for transaction, in stroops. 1 stroop = 0.0000001 xlm
     #[arg(long, default_value = "100", env = "STELLAR_FEE", help_heading = HEADING_RPC)]
     pub fee: u32,
+    /// Raise the fee to the given percentile (default 90) of the inclusion fees paid in recent ledgers, as reported by the RPC server. `--fee` is used as the floor. One of 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99 or 100
+    #[arg(
+        long,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "90",
+        value_name = "PERCENTILE",
+        value_parser = parse_percentile,
+        help_heading = HEADING_RPC,
+    )]
+    pub fee_auto: Option<u8>,
     /// Output the cost execution to stderr
     #[arg(long = "cost", help_heading = HEADING_RPC)]
     pub cost: bool,
     /// Number of instructions to simulate
     #[arg(long, help_heading = HEADING_RPC)]
     pub instructions: Option<u32>,
+    /// Number of bytes read from the ledger, used with `--resource-fee`
+    #[arg(long, help_heading = HEADING_RPC, requires = "resource_fee")]
+    pub read_bytes: Option<u32>,
+    /// Number of bytes written to the ledger, used with `--resource-fee`
+    #[arg(long, help_heading = HEADING_RPC, requires = "resource_fee")]
+    pub write_bytes: Option<u32>,
+    /// Resource fee, in stroops. When provided, commands with a known footprint (extend, restore) use it along with `--instructions`, `--read-bytes` and `--write-bytes` instead of simulating the transaction
+    #[arg(long, help_heading = HEADING_RPC)]
+    pub resource_fee: Option<u32>,
     /// Build the transaction and only write the base64 xdr to stdout
     #[arg(long, help_heading = HEADING_RPC)]
     pub build_only: bool,
+    /// Seconds to wait for a submitted transaction to succeed or fail before giving up
+    #[arg(long, default_value = "30", env = "STELLAR_TX_TIMEOUT", help_heading = HEADING_RPC)]
+    pub tx_timeout: u64,
     /// (Deprecated) simulate the transaction and only write the base64 xdr to stdout
     #[arg(
         long,
@@ -35,7 +64,53 @@ pub struct Args {
     pub sim_only: bool,
 }
 
+fn parse_percentile(s: &str) -> Result<u8, String> {
+    s.parse()
+        .ok()
+        .filter(|p| FEE_PERCENTILES.contains(p))
+        .ok_or_else(|| format!("expected one of {FEE_PERCENTILES:?}"))
+}
+
 impl Args {
+    /// The inclusion fee to build the transaction with: `--fee`, raised to the `--fee-auto`
+    /// percentile of recent fees on the network when given.
+    pub async fn inclusion_fee(&self, network: &Network) -> Result<u32, network::Error> {
+        let Some(percentile) = self.fee_auto else {
+            return Ok(self.fee);
+        };
+        let fee = network.fetch_inclusion_fee(percentile).await?;
+        Ok(fee.max(self.fee))
+    }
+
+    /// Whether resources were given on the command line, so simulation can be skipped.
+    pub fn has_manual_resources(&self) -> bool {
+        self.resource_fee.is_some()
+    }
+
+    /// Build the Soroban transaction data for a known footprint from the resource arguments,
+    /// returning it along with the total transaction fee.
+    pub fn soroban_transaction_data(
+        &self,
+        footprint: xdr::LedgerFootprint,
+    ) -> (u32, xdr::SorobanTransactionData) {
+        let resource_fee = self.resource_fee.unwrap_or_default();
+        let data = xdr::SorobanTransactionData {
+            ext: xdr::ExtensionPoint::V0,
+            resources: xdr::SorobanResources {
+                footprint,
+                instructions: self.instructions.unwrap_or_default(),
+                read_bytes: self.read_bytes.unwrap_or_default(),
+                write_bytes: self.write_bytes.unwrap_or_default(),
+            },
+            resource_fee: resource_fee.into(),
+        };
+        (self.fee.saturating_add(resource_fee), data)
+    }
+
+    pub fn tx_timeout(&self) -> Duration {
+        Duration::from_secs(self.tx_timeout)
+    }
+
     pub fn apply_to_assembled_txn(&self, txn: Assembled) -> Assembled {
         if let Some(instructions) = self.instructions {
             txn.set_max_instructions(instructions)
@@ -62,10 +137,106 @@ impl Default for Args {
     fn default() -> Self {
         Self {
             fee: 100,
+            fee_auto: None,
             cost: false,
             instructions: None,
+            read_bytes: None,
+            write_bytes: None,
+            resource_fee: None,
             build_only: false,
+            tx_timeout: 30,
             sim_only: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn inclusion_fee_from_fee_stats() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({ "method": "getFeeStats" })))
+            .with_body_from_request(|req| {
+                let body: Value = serde_json::from_slice(req.body().unwrap()).unwrap();
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"].clone(),
+                    "result": {
+                        "inclusionFee": { "p50": "120", "p90": "350", "p99": "900" },
+                        "latestLedger": 1,
+                    },
+                })
+                .to_string()
+                .into()
+            })
+            .expect(3)
+            .create_async()
+            .await;
+        let network = Network {
+            rpc_url: server.url(),
+            rpc_headers: Vec::new(),
+            network_passphrase: network::passphrase::LOCAL.to_string(),
+            rpc_timeout: None,
+        };
+        let auto = |fee, percentile| Args {
+            fee,
+            fee_auto: Some(percentile),
+            ..Args::default()
+        };
+        assert_eq!(auto(100, 90).inclusion_fee(&network).await.unwrap(), 350);
+        assert_eq!(auto(200, 50).inclusion_fee(&network).await.unwrap(), 200);
+        assert_eq!(Args::default().inclusion_fee(&network).await.unwrap(), 100);
+        assert!(matches!(
+            auto(100, 20).inclusion_fee(&network).await,
+            Err(network::Error::InvalidFeeStats { percentile: 20, .. })
+        ));
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn parses_fee_percentile() {
+        assert_eq!(parse_percentile("95"), Ok(95));
+        assert!(parse_percentile("42").is_err());
+        assert!(parse_percentile("p90").is_err());
+    }
+
+    #[test]
+    fn soroban_transaction_data_from_manual_resources() {
+        let args = Args {
+            fee: 100,
+            instructions: Some(1_000_000),
+            read_bytes: Some(2_000),
+            write_bytes: Some(300),
+            resource_fee: Some(50_000),
+            ..Args::default()
+        };
+        assert!(args.has_manual_resources());
+        let footprint = xdr::LedgerFootprint {
+            read_only: vec![].try_into().unwrap(),
+            read_write: vec![].try_into().unwrap(),
+        };
+        let (fee, data) = args.soroban_transaction_data(footprint.clone());
+        assert_eq!(fee, 50_100);
+        assert_eq!(
+            data,
+            xdr::SorobanTransactionData {
+                ext: xdr::ExtensionPoint::V0,
+                resources: xdr::SorobanResources {
+                    footprint,
+                    instructions: 1_000_000,
+                    read_bytes: 2_000,
+                    write_bytes: 300,
+                },
+                resource_fee: 50_000,
+            }
+        );
+        assert!(!Args::default().has_manual_resources());
+    }
+}
@@ -16,6 +16,8 @@ pub enum Error {
     CannotParseContractSpec(FromWasmError),
     #[error(transparent)]
     Rpc(#[from] rpc::Error),
+    #[error(transparent)]
+    RemoteWasm(#[from] crate::utils::rpc::Error),
     #[error("missing result")]
     MissingResult,
     #[error(transparent)]
@@ -46,7 +48,9 @@ pub async fn get_remote_contract_spec(
     tracing::trace!(?network);
     let client = network.rpc_client()?;
     // Get contract data
-    let r = client.get_contract_data(contract_id).await?;
+    let r = network
+        .timeout(client.get_contract_data(contract_id))
+        .await??;
     tracing::trace!("{r:?}");
 
     let ContractDataEntry {
@@ -64,7 +68,9 @@ pub async fn get_remote_contract_spec(
             if let Ok(entries) = data::read_spec(&hash_str) {
                 entries
             } else {
-                let raw_wasm = get_remote_wasm_from_hash(&client, &hash).await?;
+                let raw_wasm = network
+                    .timeout(get_remote_wasm_from_hash(&client, &hash))
+                    .await??;
                 let res = contract_spec::Spec::new(&raw_wasm)?;
                 let res = res.spec;
                 if global_args.map_or(true, |a| !a.no_cache) {
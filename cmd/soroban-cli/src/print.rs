@@ -1,9 +1,14 @@
-use std::{env, fmt::Display};
+use std::{
+    env,
+    fmt::Display,
+    io::{self, Write},
+};
 
 use crate::xdr::{Error as XdrError, Transaction};
 
 use crate::{
-    config::network::Network, utils::explorer_url_for_transaction, utils::transaction_hash,
+    commands::global, config::network::Network, utils::explorer_url_for_transaction,
+    utils::transaction_hash,
 };
 
 const TERMS: &[&str] = &["Apple_Terminal", "vscode"];
@@ -11,11 +16,39 @@ const TERMS: &[&str] = &["Apple_Terminal", "vscode"];
 #[derive(Clone)]
 pub struct Print {
     pub quiet: bool,
+    /// Drop the output written to stdout, see `--quiet-success`
+    pub quiet_success: bool,
 }
 
 impl Print {
     pub fn new(quiet: bool) -> Print {
-        Print { quiet }
+        Print {
+            quiet,
+            quiet_success: false,
+        }
+    }
+
+    /// Writes the output of a command to stdout, unless `--quiet-success` is set.
+    pub fn out<T: Display + Sized>(&self, message: T) {
+        if !self.quiet_success {
+            print!("{message}");
+        }
+    }
+
+    /// Writes a line of the output of a command to stdout, unless `--quiet-success` is set.
+    pub fn outln<T: Display + Sized>(&self, message: T) {
+        if !self.quiet_success {
+            println!("{message}");
+        }
+    }
+
+    /// Stdout, or a sink discarding everything when `--quiet-success` is set.
+    pub fn stdout(&self) -> Box<dyn Write> {
+        if self.quiet_success {
+            Box::new(io::sink())
+        } else {
+            Box::new(io::stdout())
+        }
     }
 
     pub fn print<T: Display + Sized>(&self, message: T) {
@@ -75,6 +108,15 @@ impl Print {
     }
 }
 
+impl From<&global::Args> for Print {
+    fn from(args: &global::Args) -> Self {
+        Print {
+            quiet: args.quiet,
+            quiet_success: args.quiet_success,
+        }
+    }
+}
+
 macro_rules! create_print_functions {
     ($name:ident, $nameln:ident, $icon:expr) => {
         impl Print {
@@ -1,15 +1,20 @@
-use ed25519_dalek::ed25519::signature::Signer as _;
+use ed25519_dalek::ed25519::signature::{Signer as _, Verifier as _};
 use sha2::{Digest, Sha256};
 
 use crate::xdr::{
-    self, AccountId, DecoratedSignature, Hash, HashIdPreimage, HashIdPreimageSorobanAuthorization,
-    InvokeHostFunctionOp, Limits, Operation, OperationBody, PublicKey, ScAddress, ScMap, ScSymbol,
-    ScVal, Signature, SignatureHint, SorobanAddressCredentials, SorobanAuthorizationEntry,
+    self, AccountId, DecoratedSignature, FeeBumpTransactionEnvelope, Hash, HashIdPreimage,
+    HashIdPreimageSorobanAuthorization, InvokeHostFunctionOp, Limits, MuxedAccount,
+    MuxedAccountMed25519, Operation, OperationBody, PublicKey, ScAddress, ScMap, ScSymbol, ScVal,
+    Signature, SignatureHint, SorobanAddressCredentials, SorobanAuthorizationEntry,
     SorobanAuthorizedFunction, SorobanCredentials, Transaction, TransactionEnvelope,
     TransactionV1Envelope, Uint256, VecM, WriteXdr,
 };
 
-use crate::{config::network::Network, print::Print, utils::transaction_hash};
+use crate::{
+    config::network::Network,
+    print::Print,
+    utils::{fee_bump_transaction_hash, transaction_hash, NetworkId},
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -25,7 +30,7 @@ pub enum Error {
     UserCancelledSigning,
     #[error(transparent)]
     Xdr(#[from] xdr::Error),
-    #[error("Only Transaction envelope V1 type is supported")]
+    #[error("Only Transaction envelope V1 and fee bump types are supported")]
     UnsupportedTransactionEnvelopeType,
     #[error(transparent)]
     Url(#[from] url::ParseError),
@@ -33,6 +38,10 @@ pub enum Error {
     Open(#[from] std::io::Error),
     #[error("Returning a signature from Lab is not yet supported; Transaction can be found and submitted in lab")]
     ReturningSignatureFromLab,
+    #[error("Produced signature failed verification against the signing key")]
+    SignatureVerificationFailed,
+    #[error("Auth entry uses source account credentials of {actual}, but signing as {expected}")]
+    SourceAccountMismatch { expected: String, actual: String },
 }
 
 fn requires_auth(txn: &Transaction) -> Option<xdr::Operation> {
@@ -64,6 +73,14 @@ pub fn sign_soroban_authorizations(
         return Ok(None);
     };
 
+    let source_account = match op.source_account.as_ref().unwrap_or(&tx.source_account) {
+        MuxedAccount::Ed25519(Uint256(key))
+        | MuxedAccount::MuxedEd25519(MuxedAccountMed25519 {
+            ed25519: Uint256(key),
+            ..
+        }) => *key,
+    };
+
     let Operation {
         body: OperationBody::InvokeHostFunction(ref mut body),
         ..
@@ -72,7 +89,7 @@ pub fn sign_soroban_authorizations(
         return Ok(None);
     };
 
-    let network_id = Hash(Sha256::digest(network_passphrase.as_bytes()).into());
+    let network_id: Hash = NetworkId::new(network_passphrase).into();
 
     let verification_key = source_key.verifying_key();
     let source_address = verification_key.as_bytes();
@@ -88,7 +105,14 @@ pub fn sign_soroban_authorizations(
                 ..
             } = auth
             else {
-                // Doesn't need special signing
+                // Authorized by the operation source's signature on the transaction, so no
+                // special signing is needed as long as that is the account we're signing as
+                if source_account != *source_address {
+                    return Err(Error::SourceAccountMismatch {
+                        expected: stellar_strkey::ed25519::PublicKey(*source_address).to_string(),
+                        actual: stellar_strkey::ed25519::PublicKey(source_account).to_string(),
+                    });
+                }
                 return Ok(auth);
             };
             let SorobanAddressCredentials { ref address, .. } = credentials;
@@ -210,6 +234,16 @@ pub enum SignerKind {
 }
 
 impl Signer {
+    /// Public key of the local key this signer signs with, or `None` when signing through Lab.
+    pub fn public_key(&self) -> Option<stellar_strkey::ed25519::PublicKey> {
+        match &self.kind {
+            SignerKind::Local(LocalKey { key }) => Some(stellar_strkey::ed25519::PublicKey(
+                key.verifying_key().to_bytes(),
+            )),
+            SignerKind::Lab => None,
+        }
+    }
+
     pub fn sign_tx(
         &self,
         tx: Transaction,
@@ -243,7 +277,24 @@ impl Signer {
                     signatures: sigs.try_into()?,
                 }))
             }
-            _ => Err(Error::UnsupportedTransactionEnvelopeType),
+            TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope { tx, signatures }) => {
+                let tx_hash = fee_bump_transaction_hash(tx, &network.network_passphrase)?;
+                self.print.infoln(format!(
+                    "Signing fee bump transaction: {}",
+                    hex::encode(tx_hash),
+                ));
+                let decorated_signature = match &self.kind {
+                    SignerKind::Local(key) => key.sign_tx_hash(tx_hash)?,
+                    SignerKind::Lab => Lab::sign_tx_env(tx_env, network, &self.print)?,
+                };
+                let mut sigs = signatures.clone().into_vec();
+                sigs.push(decorated_signature);
+                Ok(TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope {
+                    tx: tx.clone(),
+                    signatures: sigs.try_into()?,
+                }))
+            }
+            TransactionEnvelope::TxV0(_) => Err(Error::UnsupportedTransactionEnvelopeType),
         }
     }
 }
@@ -252,12 +303,36 @@ pub struct LocalKey {
     pub key: ed25519_dalek::SigningKey,
 }
 
+/// Set to re-check every signature produced by a local key against its verifying key.
+/// Always enabled in debug builds.
+pub const VERIFY_SIGNATURES_ENV: &str = "STELLAR_VERIFY_SIGNATURES";
+
+fn verify_signatures() -> bool {
+    cfg!(debug_assertions) || std::env::var_os(VERIFY_SIGNATURES_ENV).is_some()
+}
+
 impl LocalKey {
     pub fn sign_tx_hash(&self, tx_hash: [u8; 32]) -> Result<DecoratedSignature, Error> {
         let hint = SignatureHint(self.key.verifying_key().to_bytes()[28..].try_into()?);
-        let signature = Signature(self.key.sign(&tx_hash).to_bytes().to_vec().try_into()?);
+        let signature = self.key.sign(&tx_hash);
+        if verify_signatures() {
+            self.verify(tx_hash, &signature)?;
+        }
+        let signature = Signature(signature.to_bytes().to_vec().try_into()?);
         Ok(DecoratedSignature { hint, signature })
     }
+
+    /// Checks that `signature` is a valid signature of `tx_hash` by this key.
+    pub fn verify(
+        &self,
+        tx_hash: [u8; 32],
+        signature: &ed25519_dalek::Signature,
+    ) -> Result<(), Error> {
+        self.key
+            .verifying_key()
+            .verify(&tx_hash, signature)
+            .map_err(|_| Error::SignatureVerificationFailed)
+    }
 }
 
 pub struct Lab;
@@ -284,3 +359,136 @@ impl Lab {
         Err(Error::ReturningSignatureFromLab)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::{thread_rng, RngCore};
+
+    use super::*;
+    use crate::{
+        tx::builder::{FeeBumpExt, TxExt},
+        xdr::{FeeBumpTransaction, HostFunction, InvokeContractArgs, SorobanAuthorizedInvocation},
+    };
+
+    fn random_key() -> LocalKey {
+        let mut seed = [0u8; 32];
+        thread_rng().fill_bytes(&mut seed);
+        LocalKey {
+            key: ed25519_dalek::SigningKey::from_bytes(&seed),
+        }
+    }
+
+    #[test]
+    fn signature_verifies() {
+        let key = random_key();
+        let hash = [7u8; 32];
+        let DecoratedSignature { hint, signature } = key.sign_tx_hash(hash).unwrap();
+        assert_eq!(hint.0, key.key.verifying_key().to_bytes()[28..]);
+        let signature = ed25519_dalek::Signature::from_slice(&signature.0).unwrap();
+        key.verify(hash, &signature).unwrap();
+    }
+
+    #[test]
+    fn signatures_over_random_hashes_verify() {
+        let mut rng = thread_rng();
+        for _ in 0..256 {
+            let key = random_key();
+            let mut hash = [0u8; 32];
+            rng.fill_bytes(&mut hash);
+            let DecoratedSignature { signature, .. } = key.sign_tx_hash(hash).unwrap();
+            let signature = ed25519_dalek::Signature::from_slice(&signature.0).unwrap();
+            key.verify(hash, &signature).unwrap();
+            let mut other = hash;
+            other[0] ^= 1;
+            assert!(matches!(
+                key.verify(other, &signature),
+                Err(Error::SignatureVerificationFailed)
+            ));
+        }
+    }
+
+    fn source_account_auth_tx(source: &LocalKey) -> Transaction {
+        let args = InvokeContractArgs {
+            contract_address: ScAddress::Contract(Hash([0; 32])),
+            function_name: ScSymbol("hello".try_into().unwrap()),
+            args: VecM::default(),
+        };
+        let auth = SorobanAuthorizationEntry {
+            credentials: SorobanCredentials::SourceAccount,
+            root_invocation: SorobanAuthorizedInvocation {
+                function: SorobanAuthorizedFunction::ContractFn(args.clone()),
+                sub_invocations: VecM::default(),
+            },
+        };
+        let op = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(args),
+                auth: vec![auth].try_into().unwrap(),
+            }),
+        };
+        let source = MuxedAccount::Ed25519(Uint256(source.key.verifying_key().to_bytes()));
+        Transaction::new_tx(source, 100, 1, op)
+    }
+
+    #[test]
+    fn source_account_auth_matches_source() {
+        let key = random_key();
+        let tx = source_account_auth_tx(&key);
+        let signed = sign_soroban_authorizations(&tx, &key.key, &[], 1, "passphrase").unwrap();
+        assert_eq!(signed, Some(tx));
+    }
+
+    #[test]
+    fn source_account_auth_mismatched_source() {
+        let tx = source_account_auth_tx(&random_key());
+        let key = random_key();
+        assert!(matches!(
+            sign_soroban_authorizations(&tx, &key.key, &[], 1, "passphrase"),
+            Err(Error::SourceAccountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn signs_fee_bump_envelope() {
+        let inner_key = random_key();
+        let fee_key = random_key();
+        let network = Network {
+            rpc_url: String::new(),
+            rpc_headers: vec![],
+            network_passphrase: "passphrase".to_string(),
+            rpc_timeout: None,
+        };
+        let inner_tx = source_account_auth_tx(&inner_key);
+        let inner_hash = transaction_hash(&inner_tx, &network.network_passphrase).unwrap();
+        let inner = TransactionV1Envelope {
+            tx: inner_tx,
+            signatures: vec![inner_key.sign_tx_hash(inner_hash).unwrap()]
+                .try_into()
+                .unwrap(),
+        };
+        let fee_source = MuxedAccount::Ed25519(Uint256(fee_key.key.verifying_key().to_bytes()));
+        let tx = FeeBumpTransaction::new_fee_bump(fee_source, 400, inner);
+        let envelope = TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope {
+            tx: tx.clone(),
+            signatures: VecM::default(),
+        });
+
+        let verifying_key = fee_key.key.verifying_key();
+        let signer = Signer {
+            kind: SignerKind::Local(fee_key),
+            print: Print::new(true),
+        };
+        let signed = signer.sign_tx_env(&envelope, &network).unwrap();
+        let TransactionEnvelope::TxFeeBump(signed) = signed else {
+            panic!("expected a fee bump envelope");
+        };
+        assert_eq!(signed.tx, tx);
+        let [DecoratedSignature { signature, .. }] = signed.signatures.as_slice() else {
+            panic!("expected one signature");
+        };
+        let hash = fee_bump_transaction_hash(&tx, &network.network_passphrase).unwrap();
+        let signature = ed25519_dalek::Signature::from_slice(&signature.0).unwrap();
+        verifying_key.verify(&hash, &signature).unwrap();
+    }
+}
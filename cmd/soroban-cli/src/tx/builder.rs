@@ -1,13 +1,19 @@
 pub mod amount;
 pub mod asset;
+pub mod memo;
+pub mod price;
 pub mod transaction;
 
 pub use amount::Amount;
-pub use asset::Asset;
-pub use transaction::TxExt;
+pub use asset::{Asset, UnresolvedAsset};
+pub use memo::Memo;
+pub use price::Price;
+pub use transaction::{FeeBumpExt, TxExt};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Transaction contains too many operations")]
     TooManyOperations,
+    #[error("Transaction must contain at least one operation")]
+    NoOperations,
 }
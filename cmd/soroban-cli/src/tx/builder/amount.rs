@@ -1,6 +1,11 @@
-use std::str::FromStr;
+use std::{num::IntErrorKind, str::FromStr};
 
-#[derive(Clone, Debug, Copy)]
+/// Number of decimal places of an amount in whole units, 1 unit is 10^7 stroops.
+const DECIMALS: usize = 7;
+
+/// An amount in stroops. Parsed from an integer number of stroops, e.g. `10_000_000`, or from
+/// whole units with a decimal point, e.g. `1.0` or `0.0000001`.
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub struct Amount(i64);
 
 #[derive(thiserror::Error, Debug)]
@@ -9,6 +14,12 @@ pub enum Error {
     CannotStartOrEndWithUnderscore(String),
     #[error(transparent)]
     IntParse(#[from] std::num::ParseIntError),
+    #[error(
+        "invalid decimal amount {0}, expected digits with at most {DECIMALS} decimal places, e.g. `1.5`"
+    )]
+    InvalidDecimal(String),
+    #[error("amount {0} is too large, at most {max} stroops are supported", max = i64::MAX)]
+    Overflow(String),
 }
 
 impl FromStr for Amount {
@@ -18,8 +29,36 @@ impl FromStr for Amount {
         if value.starts_with('_') || value.ends_with('_') {
             return Err(Error::CannotStartOrEndWithUnderscore(value.to_string()));
         }
-        Ok(Self(value.replace('_', "").parse::<i64>()?))
+        let amount = value.replace('_', "");
+        let Some((whole, fraction)) = amount.split_once('.') else {
+            return parse_stroops(value);
+        };
+        let digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if fraction.len() > DECIMALS
+            || !digits(whole)
+            || !digits(fraction)
+            || (whole.is_empty() && fraction.is_empty())
+        {
+            return Err(Error::InvalidDecimal(value.to_string()));
+        }
+        to_amount(&format!("{whole}{fraction:0<DECIMALS$}"), value)
+    }
+}
+
+/// Parses an integer number of stroops only, for arguments that do not take whole units, e.g.
+/// `#[arg(value_parser = builder::amount::parse_stroops)]`.
+pub fn parse_stroops(value: &str) -> Result<Amount, Error> {
+    if value.starts_with('_') || value.ends_with('_') {
+        return Err(Error::CannotStartOrEndWithUnderscore(value.to_string()));
     }
+    to_amount(&value.replace('_', ""), value)
+}
+
+fn to_amount(stroops: &str, value: &str) -> Result<Amount, Error> {
+    stroops.parse().map(Amount).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow => Error::Overflow(value.to_string()),
+        _ => e.into(),
+    })
 }
 
 impl From<Amount> for i64 {
@@ -33,3 +72,49 @@ impl From<&Amount> for i64 {
         (*builder).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(s: &str) -> i64 {
+        s.parse::<Amount>().unwrap().into()
+    }
+
+    #[test]
+    fn parses_stroops() {
+        assert_eq!(amount("1"), 1);
+        assert_eq!(amount("10_000_000"), 10_000_000);
+        assert_eq!(i64::from(parse_stroops("10_000_000").unwrap()), 10_000_000);
+        assert!(matches!(parse_stroops("1.0"), Err(Error::IntParse(_))));
+    }
+
+    #[test]
+    fn parses_whole_units() {
+        assert_eq!(amount("1.0"), 10_000_000);
+        assert_eq!(amount("1.5"), 15_000_000);
+        assert_eq!(amount("0.0000001"), 1);
+        assert_eq!(amount(".25"), 2_500_000);
+        assert_eq!(amount("1_000.000_000_1"), 10_000_000_001);
+        assert_eq!(amount("922337203685.4775807"), i64::MAX);
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        assert!(matches!(
+            "922337203685.4775808".parse::<Amount>(),
+            Err(Error::Overflow(_))
+        ));
+        assert!(matches!(
+            "9_223_372_036_854_775_808".parse::<Amount>(),
+            Err(Error::Overflow(_))
+        ));
+        assert!(matches!("1e7".parse::<Amount>(), Err(Error::IntParse(_))));
+        for invalid in ["0.00000001", "-1.5", "1.2.3", "."] {
+            assert!(
+                matches!(invalid.parse::<Amount>(), Err(Error::InvalidDecimal(_))),
+                "{invalid}"
+            );
+        }
+    }
+}
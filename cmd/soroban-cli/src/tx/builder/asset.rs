@@ -1,15 +1,32 @@
 use std::str::FromStr;
 
-use crate::xdr::{self, AlphaNum12, AlphaNum4, AssetCode};
+use crate::{
+    config::locator,
+    xdr::{self, AlphaNum12, AlphaNum4, AssetCode},
+};
 
 #[derive(Clone, Debug)]
 pub struct Asset(pub xdr::Asset);
 
+/// Asset given on the command line, either directly or as the name of an alias saved with
+/// `stellar contract asset alias`, which is resolved against the command's config locations.
+#[derive(Clone, Debug)]
+pub enum UnresolvedAsset {
+    Resolved(Asset),
+    Alias(String),
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
-    #[error("cannot parse asset: {0}, expected format: 'native' or 'code:issuer'")]
+    #[error("cannot parse asset: {0}, expected format: 'native', 'code:issuer' or an asset alias")]
     CannotParseAsset(String),
 
+    #[error("invalid asset code: {0:?}, expected 1 to 12 ASCII letters or digits")]
+    InvalidAssetCode(String),
+
+    #[error(transparent)]
+    Locator(#[from] locator::Error),
+
     #[error(transparent)]
     Xdr(#[from] xdr::Error),
 }
@@ -25,6 +42,9 @@ impl FromStr for Asset {
         let (Some(code), Some(issuer), None) = (iter.next(), iter.next(), iter.next()) else {
             return Err(Error::CannotParseAsset(value.to_string()));
         };
+        if !(1..=12).contains(&code.len()) || !code.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(Error::InvalidAssetCode(code.to_string()));
+        }
         let issuer = issuer.parse()?;
         Ok(Asset(match code.parse()? {
             AssetCode::CreditAlphanum4(asset_code) => {
@@ -37,6 +57,32 @@ impl FromStr for Asset {
     }
 }
 
+impl FromStr for UnresolvedAsset {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value != "native" && !value.contains(':') {
+            return Ok(UnresolvedAsset::Alias(value.to_string()));
+        }
+        Ok(UnresolvedAsset::Resolved(value.parse()?))
+    }
+}
+
+impl UnresolvedAsset {
+    pub fn resolve(&self, locator: &locator::Args) -> Result<Asset, Error> {
+        match self {
+            UnresolvedAsset::Resolved(asset) => Ok(asset.clone()),
+            UnresolvedAsset::Alias(alias) => {
+                let Some(asset) = locator.get_asset_alias(alias)? else {
+                    return Err(Error::CannotParseAsset(alias.clone()));
+                };
+                // Aliases always point at a concrete asset, never at another alias.
+                Ok(asset.parse()?)
+            }
+        }
+    }
+}
+
 impl From<Asset> for xdr::Asset {
     fn from(builder: Asset) -> Self {
         builder.0
@@ -48,3 +94,56 @@ impl From<&Asset> for xdr::Asset {
         builder.clone().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+
+    #[test]
+    fn parses_asset_codes() {
+        assert!(matches!(
+            format!("USDC:{ISSUER}").parse::<Asset>(),
+            Ok(Asset(xdr::Asset::CreditAlphanum4(_)))
+        ));
+        assert!(matches!(
+            format!("ABCDEFGHIJKL:{ISSUER}").parse::<Asset>(),
+            Ok(Asset(xdr::Asset::CreditAlphanum12(_)))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_asset_codes() {
+        for code in ["ABCDEFGHIJKLM", "", "US-D"] {
+            let err = format!("{code}:{ISSUER}").parse::<Asset>().unwrap_err();
+            assert!(matches!(&err, Error::InvalidAssetCode(c) if c == code));
+            assert!(err.to_string().contains("1 to 12 ASCII letters or digits"));
+        }
+    }
+
+    #[test]
+    fn resolves_aliases_with_the_given_locator() {
+        let dir = tempfile::tempdir().unwrap();
+        let locator = locator::Args {
+            config_dir: Some(dir.path().to_path_buf()),
+            ..locator::Args::default()
+        };
+        locator
+            .save_asset_alias("usdc", &format!("USDC:{ISSUER}"))
+            .unwrap();
+        let asset: UnresolvedAsset = "usdc".parse().unwrap();
+        assert!(matches!(
+            asset.resolve(&locator),
+            Ok(Asset(xdr::Asset::CreditAlphanum4(_)))
+        ));
+        let other = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            asset.resolve(&locator::Args {
+                config_dir: Some(other.path().to_path_buf()),
+                ..locator::Args::default()
+            }),
+            Err(Error::CannotParseAsset(alias)) if alias == "usdc"
+        ));
+    }
+}
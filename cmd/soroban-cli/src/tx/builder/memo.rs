@@ -0,0 +1,122 @@
+use std::str::FromStr;
+
+use crate::xdr;
+
+#[derive(Clone, Debug)]
+pub struct Memo(pub xdr::Memo);
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(
+        "cannot parse memo: {0}, expected format: 'text:...', 'id:...', 'hash:...' or 'return:...'"
+    )]
+    CannotParseMemo(String),
+    #[error("memo text must be at most 28 bytes, got {0}")]
+    TextTooLong(usize),
+    #[error("memo id must be an unsigned 64 bit integer: {0}")]
+    InvalidId(String),
+    #[error("memo hash must be 32 bytes of hex: {0}")]
+    InvalidHash(String),
+}
+
+impl FromStr for Memo {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((kind, memo)) = value.split_once(':') else {
+            return Err(Error::CannotParseMemo(value.to_string()));
+        };
+        let hash = || {
+            hex::decode(memo)
+                .ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .map(xdr::Hash)
+                .ok_or_else(|| Error::InvalidHash(memo.to_string()))
+        };
+        Ok(Memo(match kind {
+            "text" => {
+                let text = memo
+                    .try_into()
+                    .map_err(|_| Error::TextTooLong(memo.len()))?;
+                xdr::Memo::Text(text)
+            }
+            "id" => xdr::Memo::Id(
+                memo.parse()
+                    .map_err(|_| Error::InvalidId(memo.to_string()))?,
+            ),
+            "hash" => xdr::Memo::Hash(hash()?),
+            "return" => xdr::Memo::Return(hash()?),
+            _ => return Err(Error::CannotParseMemo(value.to_string())),
+        }))
+    }
+}
+
+impl From<Memo> for xdr::Memo {
+    fn from(builder: Memo) -> Self {
+        builder.0
+    }
+}
+
+impl From<&Memo> for xdr::Memo {
+    fn from(builder: &Memo) -> Self {
+        builder.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memo(s: &str) -> xdr::Memo {
+        s.parse::<Memo>().unwrap().into()
+    }
+
+    const HASH: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90";
+
+    #[test]
+    fn parses_text() {
+        assert_eq!(
+            memo("text:hello: world"),
+            xdr::Memo::Text("hello: world".try_into().unwrap())
+        );
+        assert_eq!(memo("text:"), xdr::Memo::Text("".try_into().unwrap()));
+    }
+
+    #[test]
+    fn parses_id() {
+        assert_eq!(memo("id:42"), xdr::Memo::Id(42));
+        assert_eq!(memo("id:18446744073709551615"), xdr::Memo::Id(u64::MAX));
+    }
+
+    #[test]
+    fn parses_hash_and_return() {
+        let hash = xdr::Hash(hex::decode(HASH).unwrap().try_into().unwrap());
+        assert_eq!(memo(&format!("hash:{HASH}")), xdr::Memo::Hash(hash.clone()));
+        assert_eq!(memo(&format!("return:{HASH}")), xdr::Memo::Return(hash));
+    }
+
+    #[test]
+    fn rejects_invalid_memos() {
+        assert!(matches!(
+            "text:this memo is longer than twenty-eight bytes".parse::<Memo>(),
+            Err(Error::TextTooLong(43))
+        ));
+        assert!(matches!("id:-1".parse::<Memo>(), Err(Error::InvalidId(_))));
+        assert!(matches!(
+            "hash:abcd".parse::<Memo>(),
+            Err(Error::InvalidHash(_))
+        ));
+        assert!(matches!(
+            format!("return:{HASH}00").parse::<Memo>(),
+            Err(Error::InvalidHash(_))
+        ));
+        assert!(matches!(
+            "hello".parse::<Memo>(),
+            Err(Error::CannotParseMemo(_))
+        ));
+        assert!(matches!(
+            "note:hi".parse::<Memo>(),
+            Err(Error::CannotParseMemo(_))
+        ));
+    }
+}
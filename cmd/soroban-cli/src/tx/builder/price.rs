@@ -0,0 +1,172 @@
+use std::str::FromStr;
+
+use crate::xdr;
+
+#[derive(Clone, Debug)]
+pub struct Price(pub xdr::Price);
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(
+        "cannot parse price: {0}, expected format: 'n:d', 'n/d' or a decimal, e.g. '5/4' or '1.25'"
+    )]
+    CannotParsePrice(String),
+    #[error("price cannot have a zero denominator: {0}")]
+    ZeroDenominator(String),
+    #[error("price must be positive and fit in a 32 bit rational: {0}")]
+    OutOfRange(String),
+}
+
+impl FromStr for Price {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Some((n, d)) = value.split_once([':', '/']) {
+            let parse = |s: &str| {
+                s.parse::<i64>()
+                    .map_err(|_| Error::CannotParsePrice(value.to_string()))
+            };
+            let (n, d) = (parse(n)?, parse(d)?);
+            if d == 0 {
+                return Err(Error::ZeroDenominator(value.to_string()));
+            }
+            let (Ok(n), Ok(d)) = (u64::try_from(n), u64::try_from(d)) else {
+                return Err(Error::OutOfRange(value.to_string()));
+            };
+            let divisor = gcd(n, d);
+            let (n, d) = (n / divisor, d / divisor);
+            let (Ok(n @ 1..), Ok(d @ 1..)) = (i32::try_from(n), i32::try_from(d)) else {
+                return Err(Error::OutOfRange(value.to_string()));
+            };
+            return Ok(Price(xdr::Price { n, d }));
+        }
+        from_decimal(value).map(Price)
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+/// Converts a decimal such as `1.25` into the closest rational whose numerator and
+/// denominator both fit in an `i32`.
+fn from_decimal(value: &str) -> Result<xdr::Price, Error> {
+    if value.starts_with('-') {
+        return Err(Error::OutOfRange(value.to_string()));
+    }
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let digits = format!("{whole}{fraction}");
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::CannotParsePrice(value.to_string()));
+    }
+    let out_of_range = || Error::OutOfRange(value.to_string());
+    let n = digits.parse::<u128>().map_err(|_| out_of_range())?;
+    let d = u32::try_from(fraction.len())
+        .ok()
+        .and_then(|digits| 10u128.checked_pow(digits))
+        .ok_or_else(out_of_range)?;
+    approximate(n, d).ok_or_else(out_of_range)
+}
+
+/// Best rational approximation of `n / d` with both terms bounded by `i32::MAX`, using the
+/// convergents of its continued fraction. Exact fractions come out fully reduced.
+fn approximate(mut n: u128, mut d: u128) -> Option<xdr::Price> {
+    let max = u128::from(i32::MAX.unsigned_abs());
+    let (mut h0, mut h1, mut k0, mut k1) = (0u128, 1u128, 1u128, 0u128);
+    while d != 0 {
+        let a = n / d;
+        let (Some(h2), Some(k2)) = (
+            a.checked_mul(h1).and_then(|x| x.checked_add(h0)),
+            a.checked_mul(k1).and_then(|x| x.checked_add(k0)),
+        ) else {
+            break;
+        };
+        if h2 > max || k2 > max {
+            break;
+        }
+        (h0, h1, k0, k1) = (h1, h2, k1, k2);
+        (n, d) = (d, n - a * d);
+    }
+    Some(xdr::Price {
+        n: i32::try_from(h1).ok().filter(|n| *n > 0)?,
+        d: i32::try_from(k1).ok().filter(|d| *d > 0)?,
+    })
+}
+
+impl From<Price> for xdr::Price {
+    fn from(builder: Price) -> Self {
+        builder.0
+    }
+}
+
+impl From<&Price> for xdr::Price {
+    fn from(builder: &Price) -> Self {
+        builder.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(s: &str) -> xdr::Price {
+        s.parse::<Price>().unwrap().into()
+    }
+
+    #[test]
+    fn parses_rationals_and_integers() {
+        assert_eq!(price("5:4"), xdr::Price { n: 5, d: 4 });
+        assert_eq!(price("3/2"), xdr::Price { n: 3, d: 2 });
+        assert_eq!(price("6/4"), xdr::Price { n: 3, d: 2 });
+        assert_eq!(price("10:5"), xdr::Price { n: 2, d: 1 });
+        assert_eq!(price("3"), xdr::Price { n: 3, d: 1 });
+    }
+
+    #[test]
+    fn converts_decimals() {
+        assert_eq!(price("1.25"), xdr::Price { n: 5, d: 4 });
+        assert_eq!(price("1.5"), xdr::Price { n: 3, d: 2 });
+        assert_eq!(price("0.1"), xdr::Price { n: 1, d: 10 });
+        assert_eq!(price("2.0"), xdr::Price { n: 2, d: 1 });
+        let xdr::Price { n, d } = price("3.14159265358979323846");
+        let approx = f64::from(n) / f64::from(d);
+        assert!((approx - std::f64::consts::PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn rejects_invalid_prices() {
+        assert!(matches!(
+            "1:0".parse::<Price>(),
+            Err(Error::ZeroDenominator(_))
+        ));
+        assert!(matches!(
+            "3/0".parse::<Price>(),
+            Err(Error::ZeroDenominator(_))
+        ));
+        assert!(matches!(
+            "4000000000/2".parse::<Price>(),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            "1/2/3".parse::<Price>(),
+            Err(Error::CannotParsePrice(_))
+        ));
+        assert!(matches!("-1:2".parse::<Price>(), Err(Error::OutOfRange(_))));
+        assert!(matches!("0".parse::<Price>(), Err(Error::OutOfRange(_))));
+        assert!(matches!(
+            "3000000000".parse::<Price>(),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            "1.2.3".parse::<Price>(),
+            Err(Error::CannotParsePrice(_))
+        ));
+        assert!(matches!(
+            "abc".parse::<Price>(),
+            Err(Error::CannotParsePrice(_))
+        ));
+    }
+}
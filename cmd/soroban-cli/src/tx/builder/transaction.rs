@@ -1,4 +1,6 @@
-use crate::xdr::{self, Memo, SequenceNumber, TransactionExt};
+use crate::xdr::{
+    self, FeeBumpTransactionExt, FeeBumpTransactionInnerTx, Memo, SequenceNumber, TransactionExt,
+};
 
 use super::Error;
 
@@ -10,6 +12,14 @@ pub trait TxExt {
         operation: xdr::Operation,
     ) -> xdr::Transaction;
 
+    /// Builds a transaction from a list of operations, which must hold between 1 and 100 of them.
+    fn from_operations(
+        source: xdr::MuxedAccount,
+        fee: u32,
+        seq_num: impl Into<SequenceNumber>,
+        operations: Vec<xdr::Operation>,
+    ) -> Result<xdr::Transaction, Error>;
+
     fn add_operation(self, operation: xdr::Operation) -> Result<xdr::Transaction, Error>;
 
     fn add_memo(self, memo: Memo) -> xdr::Transaction;
@@ -35,6 +45,26 @@ impl TxExt for xdr::Transaction {
         }
     }
 
+    fn from_operations(
+        source_account: xdr::MuxedAccount,
+        fee: u32,
+        seq_num: impl Into<SequenceNumber>,
+        ops: Vec<xdr::Operation>,
+    ) -> Result<Self, Error> {
+        if ops.is_empty() {
+            return Err(Error::NoOperations);
+        }
+        Ok(xdr::Transaction {
+            source_account,
+            fee,
+            seq_num: seq_num.into(),
+            cond: crate::xdr::Preconditions::None,
+            memo: Memo::None,
+            operations: ops.try_into().map_err(|_| Error::TooManyOperations)?,
+            ext: TransactionExt::V0,
+        })
+    }
+
     fn add_operation(mut self, operation: xdr::Operation) -> Result<Self, Error> {
         let mut ops = self.operations.to_vec();
         ops.push(operation);
@@ -51,3 +81,55 @@ impl TxExt for xdr::Transaction {
         xdr::Transaction { cond, ..self }
     }
 }
+
+pub trait FeeBumpExt {
+    fn new_fee_bump(
+        fee_source: xdr::MuxedAccount,
+        fee: i64,
+        inner: xdr::TransactionV1Envelope,
+    ) -> xdr::FeeBumpTransaction;
+}
+
+impl FeeBumpExt for xdr::FeeBumpTransaction {
+    fn new_fee_bump(
+        fee_source: xdr::MuxedAccount,
+        fee: i64,
+        inner: xdr::TransactionV1Envelope,
+    ) -> xdr::FeeBumpTransaction {
+        xdr::FeeBumpTransaction {
+            fee_source,
+            fee,
+            inner_tx: FeeBumpTransactionInnerTx::Tx(inner),
+            ext: FeeBumpTransactionExt::V0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_operations(count: usize) -> Result<xdr::Transaction, Error> {
+        let op = xdr::Operation {
+            source_account: None,
+            body: xdr::OperationBody::Inflation,
+        };
+        xdr::Transaction::from_operations(
+            xdr::MuxedAccount::Ed25519(xdr::Uint256([0; 32])),
+            100,
+            1,
+            vec![op; count],
+        )
+    }
+
+    #[test]
+    fn from_operations_checks_count() {
+        assert!(matches!(from_operations(0), Err(Error::NoOperations)));
+        assert_eq!(from_operations(1).unwrap().operations.len(), 1);
+        assert_eq!(from_operations(100).unwrap().operations.len(), 100);
+        assert!(matches!(
+            from_operations(101),
+            Err(Error::TooManyOperations)
+        ));
+    }
+}
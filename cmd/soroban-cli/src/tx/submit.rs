@@ -0,0 +1,212 @@
+use soroban_rpc::{Client, GetTransactionResponse};
+
+use crate::{
+    assembled::simulate_and_assemble_transaction,
+    commands::txn_result::TxnResult,
+    config::network::{self, Network},
+    fee,
+    signer::{self, Signer},
+    utils,
+    xdr::Transaction,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Rpc(#[from] soroban_rpc::Error),
+    #[error(transparent)]
+    Signer(#[from] signer::Error),
+    #[error(transparent)]
+    Polling(#[from] utils::rpc::Error),
+    #[error(transparent)]
+    Network(#[from] network::Error),
+}
+
+/// Simulates `tx`, applies the resource overrides of `fee_args` to the assembled transaction, then
+/// signs it with `signer` and sends it, waiting up to `--tx-timeout` for the result. Each RPC
+/// request gives up after `--rpc-timeout`.
+///
+/// With `--sim-only` the assembled transaction is returned without being signed or sent.
+pub async fn assemble_sign_submit(
+    client: &Client,
+    tx: &Transaction,
+    signer: &Signer,
+    fee_args: &fee::Args,
+    network: &Network,
+) -> Result<TxnResult<GetTransactionResponse>, Error> {
+    let txn = network
+        .timeout(simulate_and_assemble_transaction(client, tx))
+        .await??;
+    let txn = fee_args.apply_to_assembled_txn(txn).transaction().clone();
+    if fee_args.sim_only {
+        return Ok(TxnResult::Txn(Box::new(txn)));
+    }
+    Ok(TxnResult::Res(
+        sign_submit(client, txn, signer, fee_args, network).await?,
+    ))
+}
+
+/// Signs `tx` as is with `signer` and sends it, waiting up to `--tx-timeout` for the result. For
+/// transactions that are already assembled, or whose resources were all given manually.
+pub async fn sign_submit(
+    client: &Client,
+    tx: Transaction,
+    signer: &Signer,
+    fee_args: &fee::Args,
+    network: &Network,
+) -> Result<GetTransactionResponse, Error> {
+    let tx_env = signer.sign_tx(tx, network)?;
+    Ok(utils::rpc::send_transaction_polling(
+        client,
+        network,
+        &tx_env,
+        fee_args.tx_timeout(),
+        &signer.print,
+    )
+    .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use serde_json::{json, Value};
+    use soroban_rpc::{SimulateHostFunctionResultRaw, SimulateTransactionResponse};
+
+    use super::*;
+    use crate::{
+        config::network::passphrase,
+        print::Print,
+        signer::{LocalKey, SignerKind},
+        xdr::{
+            ExtensionPoint, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp,
+            LedgerFootprint, Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions,
+            ScAddress, ScSymbol, ScVal, SequenceNumber, SorobanResources, SorobanTransactionData,
+            TransactionExt, Uint256, VecM, WriteXdr,
+        },
+    };
+
+    async fn mock_rpc(server: &mut mockito::Server, method: &str, result: Value) -> mockito::Mock {
+        server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({ "method": method })))
+            .with_body_from_request(move |req| {
+                let body: Value = serde_json::from_slice(req.body().unwrap()).unwrap();
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"].clone(),
+                    "result": result.clone(),
+                })
+                .to_string()
+                .into()
+            })
+            .create_async()
+            .await
+    }
+
+    fn simulation_response() -> SimulateTransactionResponse {
+        let transaction_data = SorobanTransactionData {
+            resources: SorobanResources {
+                footprint: LedgerFootprint {
+                    read_only: VecM::default(),
+                    read_write: VecM::default(),
+                },
+                instructions: 0,
+                read_bytes: 5,
+                write_bytes: 0,
+            },
+            resource_fee: 0,
+            ext: ExtensionPoint::V0,
+        };
+        SimulateTransactionResponse {
+            min_resource_fee: 115,
+            latest_ledger: 1,
+            results: vec![SimulateHostFunctionResultRaw {
+                auth: vec![],
+                xdr: ScVal::Void.to_xdr_base64(Limits::none()).unwrap(),
+            }],
+            transaction_data: transaction_data.to_xdr_base64(Limits::none()).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn invoke_transaction(key: &ed25519_dalek::SigningKey) -> Transaction {
+        let op = Operation {
+            source_account: None,
+            body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                    contract_address: ScAddress::Contract(Hash([0; 32])),
+                    function_name: ScSymbol("hello".try_into().unwrap()),
+                    args: VecM::default(),
+                }),
+                auth: VecM::default(),
+            }),
+        };
+        Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(key.verifying_key().to_bytes())),
+            fee: 100,
+            seq_num: SequenceNumber(1),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![op].try_into().unwrap(),
+            ext: TransactionExt::V0,
+        }
+    }
+
+    #[tokio::test]
+    async fn simulates_signs_and_sends() {
+        let mut server = mockito::Server::new_async().await;
+        let sim = serde_json::to_value(simulation_response()).unwrap();
+        let simulate = mock_rpc(&mut server, "simulateTransaction", sim).await;
+        let sent = json!({
+            "hash": hex::encode([7; 32]),
+            "status": "PENDING",
+            "latestLedger": 1,
+            "latestLedgerCloseTime": "0",
+        });
+        let send = mock_rpc(&mut server, "sendTransaction", sent).await;
+        let polled = json!({ "status": "SUCCESS", "latestLedger": 1 });
+        let poll = mock_rpc(&mut server, "getTransaction", polled).await;
+
+        let network = Network {
+            rpc_url: server.url(),
+            rpc_headers: Vec::new(),
+            network_passphrase: passphrase::LOCAL.to_string(),
+            rpc_timeout: None,
+        };
+        let key = ed25519_dalek::SigningKey::from_bytes(&[1; 32]);
+        let tx = invoke_transaction(&key);
+        let signer = Signer {
+            kind: SignerKind::Local(LocalKey { key }),
+            print: Print::new(true),
+        };
+        let fee_args = fee::Args {
+            tx_timeout: 5,
+            ..fee::Args::default()
+        };
+        let client = network.rpc_client().unwrap();
+
+        let res = assemble_sign_submit(&client, &tx, &signer, &fee_args, &network)
+            .await
+            .unwrap();
+        let TxnResult::Res(res) = res else {
+            panic!("expected the transaction to be sent");
+        };
+        assert_eq!(res.status, "SUCCESS");
+        simulate.assert_async().await;
+        send.assert_async().await;
+        poll.assert_async().await;
+
+        let fee_args = fee::Args {
+            sim_only: true,
+            ..fee::Args::default()
+        };
+        let res = assemble_sign_submit(&client, &tx, &signer, &fee_args, &network)
+            .await
+            .unwrap();
+        let TxnResult::Txn(txn) = res else {
+            panic!("expected the assembled transaction");
+        };
+        assert_eq!(txn.fee, 215);
+        assert!(matches!(txn.ext, TransactionExt::V1(_)));
+    }
+}
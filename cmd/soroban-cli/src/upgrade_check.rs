@@ -1,6 +1,7 @@
 use crate::config::upgrade_check::UpgradeCheck;
 use crate::print::Print;
 use crate::utils::http;
+use chrono::{DateTime, Utc};
 use semver::Version;
 use serde::Deserialize;
 use std::error::Error;
@@ -29,25 +30,17 @@ struct Crate {
 async fn fetch_latest_crate_info() -> Result<Crate, Box<dyn Error>> {
     let crate_name = env!("CARGO_PKG_NAME");
     let url = format!("{CRATES_IO_API_URL}{crate_name}");
-    let resp = http::client()
-        .get(url)
-        .send()
-        .await?
-        .json::<CrateResponse>()
-        .await?;
+    let resp = http::get(&url).await?.json::<CrateResponse>().await?;
     Ok(resp.crate_)
 }
 
 /// Print a warning if a new version of the CLI is available
 pub async fn upgrade_check(quiet: bool) {
-    // We should skip the upgrade check if we're not in a tty environment.
-    if !std::io::stderr().is_terminal() {
-        return;
-    }
-
-    // We should skip the upgrade check if the user has disabled it by setting
-    // the environment variable (STELLAR_NO_UPDATE_CHECK)
-    if std::env::var(NO_UPDATE_CHECK_ENV_VAR).is_ok() {
+    // We should skip the upgrade check if we're not in a tty environment, or if the user has
+    // disabled it by setting the environment variable (STELLAR_NO_UPDATE_CHECK)
+    let is_tty = std::io::stderr().is_terminal();
+    let env_disabled = std::env::var(NO_UPDATE_CHECK_ENV_VAR).is_ok();
+    if !is_enabled(is_tty, env_disabled) {
         return;
     }
 
@@ -62,7 +55,7 @@ pub async fn upgrade_check(quiet: bool) {
 
     let now = chrono::Utc::now();
     // Skip fetch from crates.io if we've checked recently
-    if now - MINIMUM_CHECK_INTERVAL >= stats.latest_check_time {
+    if should_check(now, stats.latest_check_time, is_tty, env_disabled) {
         match fetch_latest_crate_info().await {
             Ok(c) => {
                 stats = UpgradeCheck {
@@ -85,9 +78,7 @@ pub async fn upgrade_check(quiet: bool) {
     }
 
     let current_version = Version::parse(current_version).unwrap();
-    let latest_version = get_latest_version(&current_version, &stats);
-
-    if *latest_version > current_version {
+    if let Some(latest_version) = decide_prompt(&current_version, &stats) {
         let printer = Print::new(quiet);
         printer.warnln(format!(
             "A new release of stellar-cli is available: {current_version} -> {latest_version}"
@@ -97,6 +88,26 @@ pub async fn upgrade_check(quiet: bool) {
     tracing::debug!("finished upgrade check");
 }
 
+fn is_enabled(is_tty: bool, env_disabled: bool) -> bool {
+    is_tty && !env_disabled
+}
+
+/// Whether to fetch the latest version from crates.io, given when it was last fetched.
+fn should_check(
+    now: DateTime<Utc>,
+    last_check: DateTime<Utc>,
+    is_tty: bool,
+    env_disabled: bool,
+) -> bool {
+    is_enabled(is_tty, env_disabled) && now - MINIMUM_CHECK_INTERVAL >= last_check
+}
+
+/// The version to prompt the user to upgrade to, if the cached versions have a newer one.
+fn decide_prompt(current: &Version, cached: &UpgradeCheck) -> Option<Version> {
+    let latest = get_latest_version(current, cached);
+    (latest > current).then(|| latest.clone())
+}
+
 fn get_latest_version<'a>(current_version: &Version, stats: &'a UpgradeCheck) -> &'a Version {
     if current_version.pre.is_empty() {
         // If we are currently using a non-preview version
@@ -145,6 +156,66 @@ mod tests {
         assert_eq!(*latest_version, Version::parse("1.1.0-rc.1").unwrap());
     }
 
+    #[test]
+    fn test_should_check_interval_boundary() {
+        let now = Utc::now();
+        let last_check = now - MINIMUM_CHECK_INTERVAL;
+        assert!(should_check(now, last_check, true, false));
+        assert!(should_check(
+            now,
+            last_check - Duration::from_secs(1),
+            true,
+            false
+        ));
+        assert!(!should_check(
+            now,
+            last_check + Duration::from_secs(1),
+            true,
+            false
+        ));
+        assert!(!should_check(now, now, true, false));
+    }
+
+    #[test]
+    fn test_should_check_disabled_env() {
+        let now = Utc::now();
+        let last_check = DateTime::<Utc>::UNIX_EPOCH;
+        assert!(!should_check(now, last_check, true, true));
+    }
+
+    #[test]
+    fn test_should_check_non_tty() {
+        let now = Utc::now();
+        let last_check = DateTime::<Utc>::UNIX_EPOCH;
+        assert!(!should_check(now, last_check, false, false));
+        assert!(!should_check(now, last_check, false, true));
+    }
+
+    #[test]
+    fn test_decide_prompt() {
+        let cached = UpgradeCheck {
+            latest_check_time: Utc::now(),
+            max_stable_version: Version::parse("1.0.0").unwrap(),
+            max_version: Version::parse("1.1.0-rc.1").unwrap(),
+        };
+        assert_eq!(
+            decide_prompt(&Version::parse("0.9.0").unwrap(), &cached),
+            Some(Version::parse("1.0.0").unwrap())
+        );
+        assert_eq!(
+            decide_prompt(&Version::parse("1.0.0").unwrap(), &cached),
+            None
+        );
+        assert_eq!(
+            decide_prompt(&Version::parse("1.1.0-beta.1").unwrap(), &cached),
+            Some(Version::parse("1.1.0-rc.1").unwrap())
+        );
+        assert_eq!(
+            decide_prompt(&Version::parse("1.1.0").unwrap(), &cached),
+            None
+        );
+    }
+
     #[test]
     fn test_semver_compare() {
         assert!(Version::parse("0.1.0").unwrap() < Version::parse("0.2.0").unwrap());
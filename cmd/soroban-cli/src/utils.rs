@@ -1,17 +1,59 @@
 use phf::phf_map;
 use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex, PoisonError},
+};
 use stellar_strkey::ed25519::PrivateKey;
 
 use crate::xdr::{
-    self, Asset, ContractIdPreimage, Hash, HashIdPreimage, HashIdPreimageContractId, Limits, ScMap,
-    ScMapEntry, ScVal, Transaction, TransactionSignaturePayload,
-    TransactionSignaturePayloadTaggedTransaction, WriteXdr,
+    self, Asset, ContractIdPreimage, FeeBumpTransaction, FeeBumpTransactionEnvelope, Hash,
+    HashIdPreimage, HashIdPreimageContractId, Limits, MuxedAccount, Preconditions, ScMap,
+    ScMapEntry, ScVal, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction, TransactionV0,
+    TransactionV0Envelope, TransactionV1Envelope, WriteXdr,
 };
 
 pub use soroban_spec_tools::contract as contract_spec;
 
 use crate::config::network::Network;
 
+/// Network id, i.e. the SHA-256 hash of a network passphrase, which is part of every signature
+/// payload and contract id preimage. Ids are memoized per passphrase, so that e.g. signing many
+/// auth entries doesn't hash the passphrase again for each of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkId(pub Hash);
+
+static NETWORK_IDS: LazyLock<Mutex<HashMap<String, Hash>>> = LazyLock::new(Mutex::default);
+
+impl NetworkId {
+    pub fn new(network_passphrase: &str) -> Self {
+        Self::memoized(&NETWORK_IDS, network_passphrase, |passphrase| {
+            Hash(Sha256::digest(passphrase).into())
+        })
+    }
+
+    fn memoized(
+        ids: &Mutex<HashMap<String, Hash>>,
+        network_passphrase: &str,
+        hash: impl FnOnce(&str) -> Hash,
+    ) -> Self {
+        let mut ids = ids.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(id) = ids.get(network_passphrase) {
+            return NetworkId(id.clone());
+        }
+        let id = hash(network_passphrase);
+        ids.insert(network_passphrase.to_string(), id.clone());
+        NetworkId(id)
+    }
+}
+
+impl From<NetworkId> for Hash {
+    fn from(NetworkId(id): NetworkId) -> Self {
+        id
+    }
+}
+
 /// # Errors
 ///
 /// Might return an error
@@ -27,12 +69,67 @@ pub fn transaction_hash(
     network_passphrase: &str,
 ) -> Result<[u8; 32], xdr::Error> {
     let signature_payload = TransactionSignaturePayload {
-        network_id: Hash(Sha256::digest(network_passphrase).into()),
+        network_id: NetworkId::new(network_passphrase).into(),
         tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
     };
     Ok(Sha256::digest(signature_payload.to_xdr(Limits::none())?).into())
 }
 
+/// # Errors
+///
+/// Might return an error
+pub fn fee_bump_transaction_hash(
+    tx: &FeeBumpTransaction,
+    network_passphrase: &str,
+) -> Result<[u8; 32], xdr::Error> {
+    let signature_payload = TransactionSignaturePayload {
+        network_id: NetworkId::new(network_passphrase).into(),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::TxFeeBump(tx.clone()),
+    };
+    Ok(Sha256::digest(signature_payload.to_xdr(Limits::none())?).into())
+}
+
+/// Hash of the transaction in an envelope, as signed and as reported by the network. Fee bump
+/// envelopes hash the fee bump transaction, and v0 envelopes hash the equivalent v1 transaction.
+///
+/// # Errors
+///
+/// Might return an error
+pub fn envelope_hash(
+    env: &TransactionEnvelope,
+    network_passphrase: &str,
+) -> Result<[u8; 32], xdr::Error> {
+    match env {
+        TransactionEnvelope::Tx(TransactionV1Envelope { tx, .. }) => {
+            transaction_hash(tx, network_passphrase)
+        }
+        TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope { tx, .. }) => {
+            fee_bump_transaction_hash(tx, network_passphrase)
+        }
+        TransactionEnvelope::TxV0(TransactionV0Envelope { tx, .. }) => {
+            let TransactionV0 {
+                source_account_ed25519,
+                fee,
+                seq_num,
+                time_bounds,
+                memo,
+                operations,
+                ..
+            } = tx.clone();
+            let tx = Transaction {
+                source_account: MuxedAccount::Ed25519(source_account_ed25519),
+                fee,
+                seq_num,
+                cond: time_bounds.map_or(Preconditions::None, Preconditions::Time),
+                memo,
+                operations,
+                ext: TransactionExt::V0,
+            };
+            transaction_hash(&tx, network_passphrase)
+        }
+    }
+}
+
 static EXPLORERS: phf::Map<&'static str, &'static str> = phf_map! {
     "Test SDF Network ; September 2015" => "https://stellar.expert/explorer/testnet",
     "Public Global Stellar Network ; September 2015" => "https://stellar.expert/explorer/public",
@@ -85,7 +182,7 @@ pub fn find_config_dir(mut pwd: std::path::PathBuf) -> std::io::Result<std::path
         let soroban_exists = soroban_dir.exists();
 
         if stellar_exists && soroban_exists {
-            tracing::warn!("the .stellar and .soroban config directories exist at path {pwd:?}, using the .stellar");
+            tracing::warn!("the .stellar and .soroban config directories exist at path {pwd:?}, using the .stellar, run `stellar config migrate` to merge them");
         }
 
         if stellar_exists {
@@ -124,11 +221,13 @@ pub fn is_hex_string(s: &str) -> bool {
     s.chars().all(|s| s.is_ascii_hexdigit())
 }
 
+/// Derive the id of the Stellar Asset Contract for `asset` on the network identified by
+/// `network_passphrase`.
 pub fn contract_id_hash_from_asset(
     asset: impl Into<Asset>,
     network_passphrase: &str,
 ) -> stellar_strkey::Contract {
-    let network_id = Hash(Sha256::digest(network_passphrase.as_bytes()).into());
+    let network_id = NetworkId::new(network_passphrase).into();
     let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
         network_id,
         contract_id_preimage: ContractIdPreimage::Asset(asset.into()),
@@ -139,34 +238,77 @@ pub fn contract_id_hash_from_asset(
     stellar_strkey::Contract(Sha256::digest(preimage_xdr).into())
 }
 
+/// Metadata a Stellar Asset Contract keeps in its instance storage under `METADATA`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StellarAssetContractMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+}
+
 pub fn get_name_from_stellar_asset_contract_storage(storage: &ScMap) -> Option<String> {
-    if let Some(ScMapEntry {
+    match stellar_asset_contract_metadata_field(storage, "name")? {
+        ScVal::String(name) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+pub fn get_metadata_from_stellar_asset_contract_storage(
+    storage: &ScMap,
+) -> Option<StellarAssetContractMetadata> {
+    let field = |name| stellar_asset_contract_metadata_field(storage, name);
+    let (ScVal::String(name), ScVal::String(symbol), ScVal::U32(decimals)) =
+        (field("name")?, field("symbol")?, field("decimal")?)
+    else {
+        return None;
+    };
+    Some(StellarAssetContractMetadata {
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        decimals: *decimals,
+    })
+}
+
+fn stellar_asset_contract_metadata_field<'a>(storage: &'a ScMap, field: &str) -> Option<&'a ScVal> {
+    let symbol = |s: &str| ScVal::Symbol(s.try_into().unwrap());
+    let Some(ScMapEntry {
         val: ScVal::Map(Some(map)),
         ..
     }) = storage
         .iter()
-        .find(|ScMapEntry { key, .. }| key == &ScVal::Symbol("METADATA".try_into().unwrap()))
-    {
-        if let Some(ScMapEntry {
-            val: ScVal::String(name),
-            ..
-        }) = map
-            .iter()
-            .find(|ScMapEntry { key, .. }| key == &ScVal::Symbol("name".try_into().unwrap()))
-        {
-            Some(name.to_string())
-        } else {
-            None
-        }
-    } else {
-        None
-    }
+        .find(|ScMapEntry { key, .. }| key == &symbol("METADATA"))
+    else {
+        return None;
+    };
+    map.iter()
+        .find(|ScMapEntry { key, .. }| key == &symbol(field))
+        .map(|ScMapEntry { val, .. }| val)
 }
 
 pub mod http {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
     use crate::commands::version;
-    fn user_agent() -> String {
-        format!("{}/{}", env!("CARGO_PKG_NAME"), version::pkg())
+
+    /// Environment variable that sets how many times an idempotent request is retried.
+    pub const RETRIES_ENV: &str = "STELLAR_HTTP_RETRIES";
+    /// Environment variable that sets the initial retry backoff, in milliseconds.
+    pub const RETRY_BACKOFF_MS_ENV: &str = "STELLAR_HTTP_RETRY_BACKOFF_MS";
+
+    const DEFAULT_RETRIES: u32 = 3;
+    const DEFAULT_BACKOFF: Duration = Duration::from_millis(250);
+
+    /// `name/version (os; arch)`, e.g. `soroban-cli/22.0.0 (linux; x86_64)`.
+    pub(crate) fn user_agent() -> String {
+        format!(
+            "{}/{} ({}; {})",
+            env!("CARGO_PKG_NAME"),
+            version::pkg(),
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
     }
 
     /// Creates and returns a configured `reqwest::Client`.
@@ -196,6 +338,190 @@ pub mod http {
             .build()
             .expect("Failed to build reqwest blocking client")
     }
+
+    /// Send a GET request with [`client`], retrying transient failures.
+    ///
+    /// See [`Retry`] for which failures are retried and how to configure it.
+    pub async fn get(url: &str) -> reqwest::Result<reqwest::Response> {
+        Retry::from_env().get(&client(), url).await
+    }
+
+    /// Send a GET request with [`blocking_client`], retrying transient failures.
+    ///
+    /// See [`Retry`] for which failures are retried and how to configure it.
+    pub fn blocking_get(url: &str) -> reqwest::Result<reqwest::blocking::Response> {
+        Retry::from_env().blocking_get(&blocking_client(), url)
+    }
+
+    /// Retry policy for idempotent requests.
+    ///
+    /// Connection errors, timeouts and `429` and `5xx` responses are retried up to
+    /// `retries` times, doubling the delay between attempts starting from `backoff`. The defaults
+    /// can be overridden with `STELLAR_HTTP_RETRIES` and `STELLAR_HTTP_RETRY_BACKOFF_MS`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Retry {
+        pub retries: u32,
+        pub backoff: Duration,
+    }
+
+    impl Default for Retry {
+        fn default() -> Self {
+            Self {
+                retries: DEFAULT_RETRIES,
+                backoff: DEFAULT_BACKOFF,
+            }
+        }
+    }
+
+    impl Retry {
+        pub fn from_env() -> Self {
+            let var = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<u64>().ok());
+            let default = Self::default();
+            Self {
+                retries: var(RETRIES_ENV)
+                    .and_then(|v| u32::try_from(v).ok())
+                    .unwrap_or(default.retries),
+                backoff: var(RETRY_BACKOFF_MS_ENV).map_or(default.backoff, Duration::from_millis),
+            }
+        }
+
+        fn delay(&self, attempt: u32) -> Duration {
+            self.backoff.saturating_mul(1 << attempt.min(16))
+        }
+
+        fn should_retry(&self, attempt: u32, res: Result<StatusCode, &reqwest::Error>) -> bool {
+            if attempt >= self.retries {
+                return false;
+            }
+            match res {
+                Ok(status) => status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error(),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            }
+        }
+
+        pub async fn get(
+            &self,
+            client: &reqwest::Client,
+            url: &str,
+        ) -> reqwest::Result<reqwest::Response> {
+            let mut attempt = 0;
+            loop {
+                let res = client.get(url).send().await;
+                let status = res.as_ref().map(reqwest::Response::status);
+                if !self.should_retry(attempt, status) {
+                    return res;
+                }
+                let delay = self.delay(attempt);
+                tracing::debug!("GET {url} failed ({status:?}), retrying in {delay:?}");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+
+        pub fn blocking_get(
+            &self,
+            client: &reqwest::blocking::Client,
+            url: &str,
+        ) -> reqwest::Result<reqwest::blocking::Response> {
+            let mut attempt = 0;
+            loop {
+                let res = client.get(url).send();
+                let status = res.as_ref().map(reqwest::blocking::Response::status);
+                if !self.should_retry(attempt, status) {
+                    return res;
+                }
+                let delay = self.delay(attempt);
+                tracing::debug!("GET {url} failed ({status:?}), retrying in {delay:?}");
+                std::thread::sleep(delay);
+                attempt += 1;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn user_agent_includes_platform() {
+            let agent = user_agent();
+            assert!(agent.starts_with(concat!(env!("CARGO_PKG_NAME"), "/")));
+            assert!(agent.contains(std::env::consts::OS));
+            assert!(agent.ends_with(&format!(
+                "({}; {})",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            )));
+        }
+
+        #[tokio::test]
+        async fn get_retries_service_unavailable() {
+            let mut server = mockito::Server::new_async().await;
+            let unavailable = server
+                .mock("GET", "/")
+                .with_status(503)
+                .expect(2)
+                .create_async()
+                .await;
+            let ok = server
+                .mock("GET", "/")
+                .with_status(200)
+                .with_body("ok")
+                .expect(1)
+                .create_async()
+                .await;
+            let retry = Retry {
+                retries: 3,
+                backoff: Duration::from_millis(1),
+            };
+            let res = retry.get(&client(), &server.url()).await.unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            assert_eq!(res.text().await.unwrap(), "ok");
+            unavailable.assert_async().await;
+            ok.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn get_does_not_retry_client_errors() {
+            let mut server = mockito::Server::new_async().await;
+            let not_found = server
+                .mock("GET", "/")
+                .with_status(404)
+                .expect(1)
+                .create_async()
+                .await;
+            let retry = Retry {
+                retries: 3,
+                backoff: Duration::from_millis(1),
+            };
+            let res = retry.get(&client(), &server.url()).await.unwrap();
+            assert_eq!(res.status(), StatusCode::NOT_FOUND);
+            not_found.assert_async().await;
+        }
+
+        #[test]
+        fn blocking_get_retries_server_errors() {
+            let mut server = mockito::Server::new();
+            let failing = server.mock("GET", "/").with_status(500).expect(2).create();
+            let ok = server
+                .mock("GET", "/")
+                .with_status(200)
+                .with_body("ok")
+                .expect(1)
+                .create();
+            let retry = Retry {
+                retries: 3,
+                backoff: Duration::from_millis(1),
+            };
+            let res = retry
+                .blocking_get(&blocking_client(), &server.url())
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+            assert_eq!(res.text().unwrap(), "ok");
+            failing.assert();
+            ok.assert();
+        }
+    }
 }
 
 pub mod args {
@@ -236,31 +562,483 @@ pub mod args {
 }
 
 pub mod rpc {
-    use crate::xdr;
-    use soroban_rpc::{Client, Error};
-    use stellar_xdr::curr::{Hash, LedgerEntryData, LedgerKey, Limits, ReadXdr};
+    use std::time::{Duration, Instant};
 
-    pub async fn get_remote_wasm_from_hash(client: &Client, hash: &Hash) -> Result<Vec<u8>, Error> {
-        let code_key = LedgerKey::ContractCode(xdr::LedgerKeyContractCode { hash: hash.clone() });
-        let contract_data = client.get_ledger_entries(&[code_key]).await?;
-        let entries = contract_data.entries.unwrap_or_default();
-        if entries.is_empty() {
-            return Err(Error::NotFound(
-                "Contract Code".to_string(),
+    use crate::{
+        config::network::{self, Network},
+        print::Print,
+        xdr,
+    };
+    use soroban_rpc::{Client, GetTransactionResponse, LedgerEntryResult};
+    use stellar_xdr::curr::{
+        Hash, LedgerEntryData, LedgerKey, Limits, ReadXdr, TransactionEnvelope,
+    };
+
+    #[derive(thiserror::Error, Debug)]
+    pub enum Error {
+        #[error(transparent)]
+        Rpc(#[from] soroban_rpc::Error),
+        #[error(transparent)]
+        Network(#[from] network::Error),
+        #[error(transparent)]
+        Xdr(#[from] xdr::Error),
+        #[error("expected exactly one contract code entry for wasm hash {hash}, but the rpc returned {count}")]
+        UnexpectedContractCodeCount { hash: String, count: usize },
+        #[error("unexpected contract code data type: {0:?}")]
+        UnexpectedContractCodeDataType(LedgerEntryData),
+        #[error("transaction {hash} failed: {result}")]
+        TransactionFailed { hash: String, result: String },
+        #[error("transaction {hash} was not included in a ledger within {secs} seconds")]
+        TransactionTimeout { hash: String, secs: u64 },
+        #[error("unexpected transaction status: {0}")]
+        UnexpectedTransactionStatus(String),
+    }
+
+    /// Submits `tx` and polls for its result until it succeeds, fails, or `timeout` elapses,
+    /// printing progress while it is pending. Each RPC request gives up after the network's
+    /// `--rpc-timeout`.
+    pub async fn send_transaction_polling(
+        client: &Client,
+        network: &Network,
+        tx: &TransactionEnvelope,
+        timeout: Duration,
+        print: &Print,
+    ) -> Result<GetTransactionResponse, Error> {
+        let hash = network.timeout(client.send_transaction(tx)).await??;
+        poll_transaction(
+            client,
+            network,
+            &hash,
+            timeout,
+            Duration::from_secs(1),
+            print,
+        )
+        .await
+    }
+
+    async fn poll_transaction(
+        client: &Client,
+        network: &Network,
+        hash: &Hash,
+        timeout: Duration,
+        interval: Duration,
+        print: &Print,
+    ) -> Result<GetTransactionResponse, Error> {
+        let start = Instant::now();
+        loop {
+            let response = network.timeout(client.get_transaction(hash)).await??;
+            match response.status.as_str() {
+                "SUCCESS" => return Ok(response.try_into()?),
+                "FAILED" => {
+                    return Err(Error::TransactionFailed {
+                        hash: hex::encode(hash),
+                        result: response.result_xdr.unwrap_or_default(),
+                    })
+                }
+                "NOT_FOUND" => {}
+                status => return Err(Error::UnexpectedTransactionStatus(status.to_string())),
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Err(Error::TransactionTimeout {
+                    hash: hex::encode(hash),
+                    secs: timeout.as_secs(),
+                });
+            }
+            print.infoln(format!(
+                "Waiting for transaction {} ({}s elapsed)…",
                 hex::encode(hash),
+                elapsed.as_secs()
             ));
+            tokio::time::sleep(interval.min(timeout - elapsed)).await;
         }
-        let contract_data_entry = &entries[0];
+    }
+
+    pub async fn get_remote_wasm_from_hash(client: &Client, hash: &Hash) -> Result<Vec<u8>, Error> {
+        let code_key = LedgerKey::ContractCode(xdr::LedgerKeyContractCode { hash: hash.clone() });
+        let contract_data = client.get_ledger_entries(&[code_key]).await?;
+        wasm_from_entries(hash, &contract_data.entries.unwrap_or_default())
+    }
+
+    fn wasm_from_entries(hash: &Hash, entries: &[LedgerEntryResult]) -> Result<Vec<u8>, Error> {
+        let contract_data_entry = match entries {
+            [] => {
+                return Err(soroban_rpc::Error::NotFound(
+                    "Contract Code".to_string(),
+                    hex::encode(hash),
+                )
+                .into())
+            }
+            [entry] => entry,
+            _ => {
+                return Err(Error::UnexpectedContractCodeCount {
+                    hash: hex::encode(hash),
+                    count: entries.len(),
+                })
+            }
+        };
         match LedgerEntryData::from_xdr_base64(&contract_data_entry.xdr, Limits::none())? {
             LedgerEntryData::ContractCode(xdr::ContractCodeEntry { code, .. }) => Ok(code.into()),
             scval => Err(Error::UnexpectedContractCodeDataType(scval)),
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use mockito::{Matcher, Mock};
+        use serde_json::{json, Value};
+
+        use super::*;
+        use crate::xdr::{ContractCodeEntry, ContractCodeEntryExt, WriteXdr};
+
+        fn entry(code: &[u8]) -> LedgerEntryResult {
+            let data = LedgerEntryData::ContractCode(ContractCodeEntry {
+                ext: ContractCodeEntryExt::V0,
+                hash: Hash([0; 32]),
+                code: code.to_vec().try_into().unwrap(),
+            });
+            serde_json::from_value(serde_json::json!({
+                "key": "",
+                "xdr": data.to_xdr_base64(Limits::none()).unwrap(),
+                "lastModifiedLedgerSeq": 1,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn wasm_from_zero_entries() {
+            assert!(matches!(
+                wasm_from_entries(&Hash([0; 32]), &[]),
+                Err(Error::Rpc(soroban_rpc::Error::NotFound(..)))
+            ));
+        }
+
+        #[test]
+        fn wasm_from_one_entry() {
+            let wasm = wasm_from_entries(&Hash([0; 32]), &[entry(b"\0asm")]).unwrap();
+            assert_eq!(wasm, b"\0asm");
+        }
+
+        #[test]
+        fn wasm_from_two_entries() {
+            assert!(matches!(
+                wasm_from_entries(&Hash([0; 32]), &[entry(b"\0asm"), entry(b"\0asm")]),
+                Err(Error::UnexpectedContractCodeCount { count: 2, .. })
+            ));
+        }
+
+        fn network(server: &mockito::Server, rpc_timeout: Option<u64>) -> Network {
+            Network {
+                rpc_url: server.url(),
+                rpc_headers: Vec::new(),
+                network_passphrase: network::passphrase::LOCAL.to_string(),
+                rpc_timeout,
+            }
+        }
+
+        fn mock_get_transaction(
+            server: &mut mockito::Server,
+            statuses: &'static [&'static str],
+        ) -> Mock {
+            let polls = AtomicUsize::new(0);
+            server
+                .mock("POST", "/")
+                .match_body(Matcher::PartialJson(json!({ "method": "getTransaction" })))
+                .with_body_from_request(move |req| {
+                    let body: Value = serde_json::from_slice(req.body().unwrap()).unwrap();
+                    let poll = polls.fetch_add(1, Ordering::SeqCst);
+                    let status = statuses[poll.min(statuses.len() - 1)];
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": body["id"].clone(),
+                        "result": { "status": status, "latestLedger": 1 },
+                    })
+                    .to_string()
+                    .into()
+                })
+        }
+
+        #[tokio::test]
+        async fn poll_transaction_until_success() {
+            let mut server = mockito::Server::new_async().await;
+            let mock = mock_get_transaction(&mut server, &["NOT_FOUND", "NOT_FOUND", "SUCCESS"])
+                .expect(3)
+                .create_async()
+                .await;
+            let client = Client::new(&server.url()).unwrap();
+            let response = poll_transaction(
+                &client,
+                &network(&server, None),
+                &Hash([1; 32]),
+                Duration::from_secs(5),
+                Duration::from_millis(10),
+                &Print::new(true),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.status, "SUCCESS");
+            mock.assert_async().await;
+        }
+
+        #[tokio::test]
+        async fn poll_transaction_times_out() {
+            let mut server = mockito::Server::new_async().await;
+            let _mock = mock_get_transaction(&mut server, &["NOT_FOUND"])
+                .create_async()
+                .await;
+            let client = Client::new(&server.url()).unwrap();
+            let result = poll_transaction(
+                &client,
+                &network(&server, None),
+                &Hash([1; 32]),
+                Duration::from_millis(50),
+                Duration::from_millis(10),
+                &Print::new(true),
+            )
+            .await;
+            assert!(matches!(result, Err(Error::TransactionTimeout { .. })));
+        }
+
+        #[tokio::test]
+        async fn rpc_timeout_bounds_each_poll_not_the_whole_wait() {
+            let mut server = mockito::Server::new_async().await;
+            let statuses = &["NOT_FOUND", "NOT_FOUND", "NOT_FOUND", "SUCCESS"];
+            let mock = mock_get_transaction(&mut server, statuses)
+                .expect(4)
+                .create_async()
+                .await;
+            let client = Client::new(&server.url()).unwrap();
+            let response = poll_transaction(
+                &client,
+                &network(&server, Some(1)),
+                &Hash([1; 32]),
+                Duration::from_secs(5),
+                Duration::from_millis(400),
+                &Print::new(true),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.status, "SUCCESS");
+            mock.assert_async().await;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        config::network::passphrase,
+        print::Print,
+        signer::{LocalKey, Signer, SignerKind},
+        tx::builder::FeeBumpExt,
+        xdr::{DecoratedSignature, ReadXdr, ScString, TransactionV0Ext, Uint256, VecM},
+    };
+
+    /// A transaction signed by its source account on the local network.
+    const SIGNED_TX: &str = include_str!("../../crates/soroban-test/tests/fixtures/signed_tx.txt");
+
+    fn signed_tx() -> TransactionV1Envelope {
+        let TransactionEnvelope::Tx(env) =
+            TransactionEnvelope::from_xdr_base64(SIGNED_TX, Limits::none()).unwrap()
+        else {
+            panic!("expected a v1 transaction envelope");
+        };
+        env
+    }
+
+    fn assert_signed_by(key: &[u8; 32], hash: &[u8; 32], sig: &DecoratedSignature) {
+        let key = ed25519_dalek::VerifyingKey::from_bytes(key).unwrap();
+        let sig = ed25519_dalek::Signature::from_slice(&sig.signature.0).unwrap();
+        key.verify_strict(hash, &sig).unwrap();
+    }
+
+    #[test]
+    fn network_id_is_hashed_once_per_passphrase() {
+        let ids = Mutex::default();
+        let hashed = std::cell::Cell::new(0);
+        let hash = |passphrase: &str| {
+            hashed.set(hashed.get() + 1);
+            Hash(Sha256::digest(passphrase).into())
+        };
+        let expected = Hash(Sha256::digest(passphrase::LOCAL).into());
+        for _ in 0..1000 {
+            let id = NetworkId::memoized(&ids, passphrase::LOCAL, hash);
+            assert_eq!(Hash::from(id), expected);
+        }
+        assert_eq!(hashed.get(), 1);
+        NetworkId::memoized(&ids, passphrase::TESTNET, hash);
+        assert_eq!(hashed.get(), 2);
+        assert_eq!(NetworkId::new(passphrase::LOCAL), NetworkId(expected));
+    }
+
+    #[test]
+    fn envelope_hash_of_signed_tx() {
+        let env = signed_tx();
+        let hash = envelope_hash(&TransactionEnvelope::Tx(env.clone()), passphrase::LOCAL);
+        let hash = hash.unwrap();
+        assert_eq!(
+            hex::encode(hash),
+            "bcc9fa60c8f6607c981d6e1c65d77ae07617720113f9080fe5883d8e4a331a68"
+        );
+        let MuxedAccount::Ed25519(Uint256(source)) = &env.tx.source_account else {
+            panic!("expected an ed25519 source account");
+        };
+        assert_signed_by(source, &hash, &env.signatures[0]);
+    }
+
+    #[test]
+    fn envelope_hash_of_fee_bump() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[1; 32]);
+        let fee_source = MuxedAccount::Ed25519(Uint256(key.verifying_key().to_bytes()));
+        let inner = signed_tx();
+        let inner_env = TransactionEnvelope::Tx(inner.clone());
+        let inner_hash = envelope_hash(&inner_env, passphrase::LOCAL).unwrap();
+        let env = TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope {
+            tx: FeeBumpTransaction::new_fee_bump(fee_source, 1_000_000, inner),
+            signatures: VecM::default(),
+        });
+        let network = Network {
+            rpc_url: String::new(),
+            rpc_headers: vec![],
+            network_passphrase: passphrase::LOCAL.to_string(),
+            rpc_timeout: None,
+        };
+        let signer = Signer {
+            kind: SignerKind::Local(LocalKey { key: key.clone() }),
+            print: Print::new(true),
+        };
+        let TransactionEnvelope::TxFeeBump(signed) = signer.sign_tx_env(&env, &network).unwrap()
+        else {
+            panic!("expected a fee bump envelope");
+        };
+
+        let hash = envelope_hash(&env, passphrase::LOCAL).unwrap();
+        assert_ne!(hash, inner_hash);
+        assert_signed_by(
+            &key.verifying_key().to_bytes(),
+            &hash,
+            &signed.signatures[0],
+        );
+    }
+
+    #[test]
+    fn envelope_hash_of_v0_matches_v1() {
+        let TransactionV1Envelope { tx, signatures } = signed_tx();
+        let MuxedAccount::Ed25519(source) = tx.source_account.clone() else {
+            panic!("expected an ed25519 source account");
+        };
+        let v0 = TransactionEnvelope::TxV0(TransactionV0Envelope {
+            tx: TransactionV0 {
+                source_account_ed25519: source,
+                fee: tx.fee,
+                seq_num: tx.seq_num.clone(),
+                time_bounds: None,
+                memo: tx.memo.clone(),
+                operations: tx.operations.clone(),
+                ext: TransactionV0Ext::V0,
+            },
+            signatures,
+        });
+        let v1 = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                cond: Preconditions::None,
+                ext: TransactionExt::V0,
+                ..tx
+            },
+            signatures: VecM::default(),
+        });
+        let hash = envelope_hash(&v0, passphrase::LOCAL).unwrap();
+        assert_eq!(
+            hex::encode(hash),
+            "6259b1ac0249e0826de5a8804f2b744aaae57f886d9604891fb931ce2ee58262"
+        );
+        assert_eq!(hash, envelope_hash(&v1, passphrase::LOCAL).unwrap());
+    }
+
+    fn sac_storage(metadata: Vec<(&str, ScVal)>) -> ScMap {
+        let symbol = |s: &str| ScVal::Symbol(s.try_into().unwrap());
+        let metadata = metadata
+            .into_iter()
+            .map(|(key, val)| ScMapEntry {
+                key: symbol(key),
+                val,
+            })
+            .collect::<Vec<_>>();
+        ScMap(
+            vec![ScMapEntry {
+                key: symbol("METADATA"),
+                val: ScVal::Map(Some(ScMap(metadata.try_into().unwrap()))),
+            }]
+            .try_into()
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn metadata_from_stellar_asset_contract_storage() {
+        let string = |s: &str| ScVal::String(ScString(s.try_into().unwrap()));
+        let storage = sac_storage(vec![
+            ("decimal", ScVal::U32(7)),
+            (
+                "name",
+                string("USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN"),
+            ),
+            ("symbol", string("USDC")),
+        ]);
+        assert_eq!(
+            get_metadata_from_stellar_asset_contract_storage(&storage),
+            Some(StellarAssetContractMetadata {
+                name: "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN".to_string(),
+                symbol: "USDC".to_string(),
+                decimals: 7,
+            })
+        );
+        assert_eq!(
+            get_name_from_stellar_asset_contract_storage(&storage).as_deref(),
+            Some("USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN")
+        );
+    }
+
+    #[test]
+    fn metadata_requires_every_field() {
+        let storage = sac_storage(vec![
+            ("decimal", ScVal::U32(7)),
+            (
+                "name",
+                ScVal::String(ScString("native".try_into().unwrap())),
+            ),
+        ]);
+        assert_eq!(
+            get_metadata_from_stellar_asset_contract_storage(&storage),
+            None
+        );
+        assert_eq!(
+            get_name_from_stellar_asset_contract_storage(&storage).as_deref(),
+            Some("native")
+        );
+        let storage = sac_storage(vec![
+            ("decimal", ScVal::I32(7)),
+            (
+                "name",
+                ScVal::String(ScString("native".try_into().unwrap())),
+            ),
+            (
+                "symbol",
+                ScVal::String(ScString("native".try_into().unwrap())),
+            ),
+        ]);
+        assert_eq!(
+            get_metadata_from_stellar_asset_contract_storage(&storage),
+            None
+        );
+        assert_eq!(
+            get_metadata_from_stellar_asset_contract_storage(&ScMap::default()),
+            None
+        );
+    }
 
     #[test]
     fn test_contract_id_from_str() {
@@ -277,4 +1055,36 @@ mod tests {
             Err(err) => panic!("Failed to parse contract id: {err}"),
         }
     }
+
+    fn asset_contract_id(asset: &str, network_passphrase: &str) -> String {
+        let asset: crate::tx::builder::Asset = asset.parse().unwrap();
+        let id = contract_id_hash_from_asset(&asset, network_passphrase);
+        assert_eq!(id, contract_id_hash_from_asset(asset.0, network_passphrase));
+        id.to_string()
+    }
+
+    #[test]
+    fn test_contract_id_hash_from_native_asset() {
+        assert_eq!(
+            asset_contract_id("native", passphrase::TESTNET),
+            "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+        );
+        assert_eq!(
+            asset_contract_id("native", passphrase::MAINNET),
+            "CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA"
+        );
+    }
+
+    #[test]
+    fn test_contract_id_hash_from_issued_asset() {
+        let usdc = "USDC:GA5ZSEJYB37JRC5AVCIA5MOP4RHTM335X2KGX3IHOJAPP5RE34K4KZVN";
+        assert_eq!(
+            asset_contract_id(usdc, passphrase::TESTNET),
+            "CA2E53VHFZ6YSWQIEIPBXJQGT6VW3VKWWZO555XKRQXYJ63GEBJJGHY7"
+        );
+        assert_eq!(
+            asset_contract_id(usdc, passphrase::MAINNET),
+            "CCW67TSZV3SSS2HXMBQ5JFGCKJNXKZM7UQUWUZPUTHXSTZLEO7SJMI75"
+        );
+    }
 }
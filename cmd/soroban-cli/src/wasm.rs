@@ -41,6 +41,8 @@ pub enum Error {
     Locator(#[from] locator::Error),
     #[error(transparent)]
     Rpc(#[from] soroban_rpc::Error),
+    #[error(transparent)]
+    RemoteWasm(#[from] utils::rpc::Error),
     #[error("unexpected contract data {0:?}")]
     UnexpectedContractToken(Box<ContractDataEntry>),
     #[error(
@@ -50,6 +52,8 @@ pub enum Error {
     ContractIsStellarAsset,
     #[error(transparent)]
     Network(#[from] NetworkError),
+    #[error("local wasm hash {local} does not match on-chain wasm hash {remote}")]
+    HashMismatch { local: String, remote: String },
 }
 
 #[derive(Debug, clap::Args, Clone)]
@@ -126,15 +130,53 @@ pub async fn fetch_from_contract(
 ) -> Result<Vec<u8>, Error> {
     tracing::trace!(?network);
     let client = network.rpc_client()?;
-    client
-        .verify_network_passphrase(Some(&network.network_passphrase))
-        .await?;
-    let data_entry = client.get_contract_data(contract_id).await?;
+    network
+        .timeout(client.verify_network_passphrase(Some(&network.network_passphrase)))
+        .await??;
+    let data_entry = network
+        .timeout(client.get_contract_data(contract_id))
+        .await??;
     if let ScVal::ContractInstance(contract) = &data_entry.val {
         return match &contract.executable {
-            ContractExecutable::Wasm(hash) => Ok(get_remote_wasm_from_hash(&client, hash).await?),
+            ContractExecutable::Wasm(hash) => Ok(network
+                .timeout(get_remote_wasm_from_hash(&client, hash))
+                .await??),
             ContractExecutable::StellarAsset => Err(ContractIsStellarAsset),
         };
     }
     Err(UnexpectedContractToken(Box::new(data_entry)))
 }
+
+/// # Errors
+/// Returns `HashMismatch` if the local and on-chain wasm differ
+pub fn verify(local: &[u8], remote: &[u8]) -> Result<Hash, Error> {
+    let local = Hash(Sha256::digest(local).into());
+    let remote = Hash(Sha256::digest(remote).into());
+    if local != remote {
+        return Err(Error::HashMismatch {
+            local: hex::encode(local.0),
+            remote: hex::encode(remote.0),
+        });
+    }
+    Ok(local)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_matching_wasm() {
+        let code = b"\0asm\x01\0\0\0";
+        let hash = verify(code, code).unwrap();
+        assert_eq!(hash, Hash(Sha256::digest(code).into()));
+    }
+
+    #[test]
+    fn verify_mismatched_wasm() {
+        assert!(matches!(
+            verify(b"\0asm\x01\0\0\0", b"\0asm\x01\0\0\x01"),
+            Err(Error::HashMismatch { .. })
+        ));
+    }
+}